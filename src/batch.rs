@@ -0,0 +1,300 @@
+//! # Batch module
+//!
+//! This module is used for sending many commands to Hyprland over a single connection,
+//! using the `[[BATCH]]` socket syntax
+//!
+//! ## Usage
+//!
+//! ```rust, no_run
+//! use hyprland::batch::{Batch, BatchResponse};
+//! use hyprland::data::{DataCommands, DataResponse};
+//! use hyprland::dispatch::DispatchType;
+//! fn main() -> hyprland::Result<()> {
+//!     let responses = Batch::new()
+//!         .dispatch(DispatchType::Exec("kitty"))
+//!         .keyword("general:gaps_in", 5)
+//!         .data(DataCommands::Monitors)
+//!         .call()?;
+//!
+//!     for response in responses {
+//!         if let BatchResponse::Data(Ok(DataResponse::Monitors(monitors))) = response {
+//!             println!("{monitors:#?}");
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::default_instance;
+use crate::error::hypr_err;
+use crate::instance::Instance;
+use crate::shared::*;
+
+#[cfg(feature = "data")]
+use crate::data::{DataCommands, DataResponse};
+#[cfg(feature = "dispatch")]
+use crate::dispatch::{gen_dispatch_str, DispatchType};
+#[cfg(any(feature = "dispatch", feature = "keyword"))]
+use crate::error::HyprError;
+#[cfg(feature = "keyword")]
+use crate::keyword::{Keyword, OptionValue};
+
+const BATCH_PREFIX: &str = "[[BATCH]]";
+const BATCH_SEPARATOR: char = ';';
+const REPLY_DELIMITER: &str = "\n\n\n";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BatchKind {
+    #[cfg(feature = "dispatch")]
+    Dispatch,
+    #[cfg(feature = "keyword")]
+    Keyword,
+    #[cfg(feature = "data")]
+    Data(DataCommands),
+}
+
+/// This enum holds the result of a single command in a [Batch]
+#[derive(Debug)]
+pub enum BatchResponse {
+    /// The result of a dispatcher, errors with [HyprError::NotOkDispatch] like [crate::dispatch::Dispatch::call]
+    #[cfg(feature = "dispatch")]
+    Dispatch(crate::Result<()>),
+    /// The result of setting a keyword
+    #[cfg(feature = "keyword")]
+    Keyword(crate::Result<()>),
+    /// The deserialized reply of a data query
+    #[cfg(feature = "data")]
+    Data(crate::Result<DataResponse>),
+}
+
+/// This struct accumulates commands and sends them to Hyprland in one request
+///
+/// The responses are returned in the same order the commands were added
+#[derive(Debug, Default)]
+pub struct Batch {
+    commands: Vec<(BatchKind, crate::Result<CommandContent>)>,
+}
+
+impl Batch {
+    /// This method creates a new empty batch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This method returns the amount of commands in the batch
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// This method returns true if no commands have been added
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// This method adds a dispatcher to the batch
+    #[cfg(feature = "dispatch")]
+    pub fn dispatch(mut self, dispatch_type: DispatchType) -> Self {
+        self.commands
+            .push((BatchKind::Dispatch, gen_dispatch_str(dispatch_type, true)));
+        self
+    }
+
+    /// This method adds setting a keyword to the batch, like [Keyword::set]
+    #[cfg(feature = "keyword")]
+    pub fn keyword<Str: ToString, Opt: Into<OptionValue>>(mut self, key: Str, value: Opt) -> Self {
+        self.commands
+            .push((BatchKind::Keyword, Ok(Keyword::gen_set_str(key, value))));
+        self
+    }
+
+    /// This method adds a data query to the batch
+    #[cfg(feature = "data")]
+    pub fn data(mut self, command: DataCommands) -> Self {
+        self.commands
+            .push((BatchKind::Data(command), Ok(command!(JSON, "{command}"))));
+        self
+    }
+
+    fn gen_batch_str(commands: Vec<crate::Result<CommandContent>>) -> crate::Result<String> {
+        let mut request = BATCH_PREFIX.to_string();
+        for (index, content) in commands.into_iter().enumerate() {
+            let content = content?.to_string();
+            if content.contains(BATCH_SEPARATOR) {
+                hypr_err!(other "Command `{content}` can not be batched as it contains a `;`");
+            }
+            if index > 0 {
+                request.push(BATCH_SEPARATOR);
+            }
+            request.push_str(&content);
+        }
+        Ok(request)
+    }
+
+    fn parse_replies(kinds: Vec<BatchKind>, response: &str) -> crate::Result<Vec<BatchResponse>> {
+        let replies: Vec<&str> = response.split(REPLY_DELIMITER).collect();
+        if replies.len() < kinds.len() {
+            hypr_err!(
+                "Expected {} replies to a batch request, but received {}!",
+                kinds.len(),
+                replies.len()
+            );
+        }
+        Ok(kinds
+            .into_iter()
+            .zip(replies)
+            .map(|(kind, reply)| match kind {
+                #[cfg(feature = "dispatch")]
+                BatchKind::Dispatch => BatchResponse::Dispatch(match reply.trim() {
+                    "ok" => Ok(()),
+                    msg => Err(HyprError::NotOkDispatch(msg.to_string())),
+                }),
+                #[cfg(feature = "keyword")]
+                BatchKind::Keyword => BatchResponse::Keyword(match reply.trim() {
+                    "ok" => Ok(()),
+                    msg => Err(HyprError::Internal(msg.to_string())),
+                }),
+                #[cfg(feature = "data")]
+                BatchKind::Data(command) => BatchResponse::Data(command.parse_reply(reply)),
+            })
+            .collect())
+    }
+
+    /// This method sends the batch to Hyprland (blocking)
+    pub fn call(self) -> crate::Result<Vec<BatchResponse>> {
+        self.instance_call(default_instance()?)
+    }
+
+    /// This method sends the batch to Hyprland (blocking)
+    pub fn instance_call(self, instance: &Instance) -> crate::Result<Vec<BatchResponse>> {
+        if self.is_empty() {
+            return Ok(vec![]);
+        }
+        let (kinds, commands): (Vec<_>, Vec<_>) = self.commands.into_iter().unzip();
+        let request = Self::gen_batch_str(commands)?;
+        let response = instance.write_raw_to_socket(request.as_bytes())?;
+        Self::parse_replies(kinds, &response)
+    }
+
+    /// This method sends the batch to Hyprland (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn call_async(self) -> crate::Result<Vec<BatchResponse>> {
+        self.instance_call_async(default_instance()?).await
+    }
+
+    /// This method sends the batch to Hyprland (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_call_async(
+        self,
+        instance: &Instance,
+    ) -> crate::Result<Vec<BatchResponse>> {
+        if self.is_empty() {
+            return Ok(vec![]);
+        }
+        let (kinds, commands): (Vec<_>, Vec<_>) = self.commands.into_iter().unzip();
+        let request = Self::gen_batch_str(commands)?;
+        let response = instance
+            .write_raw_to_socket_async(request.as_bytes())
+            .await?;
+        Self::parse_replies(kinds, &response)
+    }
+}
+
+impl BatchResponse {
+    /// This method returns the error of the command, if it failed
+    pub fn err(self) -> Option<crate::error::HyprError> {
+        match self {
+            #[cfg(feature = "dispatch")]
+            BatchResponse::Dispatch(result) => result.err(),
+            #[cfg(feature = "keyword")]
+            BatchResponse::Keyword(result) => result.err(),
+            #[cfg(feature = "data")]
+            BatchResponse::Data(result) => result.err(),
+        }
+    }
+}
+
+/// Returns the first error of the commands in a batch
+#[cfg(any(
+    feature = "config",
+    feature = "keyword",
+    all(feature = "data", feature = "dispatch")
+))]
+pub(crate) fn check_batch(responses: Vec<BatchResponse>) -> crate::Result<()> {
+    match responses.into_iter().find_map(BatchResponse::err) {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+#[cfg(all(test, feature = "dispatch", feature = "keyword", feature = "data"))]
+mod tests {
+    use super::*;
+    use crate::dispatch::WorkspaceIdentifierWithSpecial;
+    use crate::error::HyprError;
+
+    fn split(batch: Batch) -> (Vec<BatchKind>, Vec<crate::Result<CommandContent>>) {
+        batch.commands.into_iter().unzip()
+    }
+
+    #[test]
+    fn test_batch_string() {
+        let batch = Batch::new()
+            .dispatch(DispatchType::Exec("kitty"))
+            .keyword("general:gaps_in", 5)
+            .dispatch(DispatchType::Workspace(WorkspaceIdentifierWithSpecial::Id(
+                2,
+            )))
+            .data(DataCommands::Monitors);
+        let (_, commands) = split(batch);
+        let request = match Batch::gen_batch_str(commands) {
+            Ok(v) => v,
+            Err(e) => panic!("Error occured: {e}"),
+        };
+        assert_eq!(
+            request,
+            "[[BATCH]]j/dispatch exec kitty;/keyword general:gaps_in 5;j/dispatch workspace 2;j/monitors all"
+        );
+    }
+
+    #[test]
+    fn test_batch_rejects_separator() {
+        let batch = Batch::new().dispatch(DispatchType::Exec("sleep 1; kitty"));
+        let (_, commands) = split(batch);
+        assert!(Batch::gen_batch_str(commands).is_err());
+    }
+
+    #[test]
+    fn test_batch_replies() {
+        let batch = Batch::new()
+            .dispatch(DispatchType::Exec("kitty"))
+            .dispatch(DispatchType::KillActiveWindow)
+            .keyword("general:gaps_in", 5)
+            .data(DataCommands::CursorPosition);
+        let (kinds, _) = split(batch);
+        let response = "ok\n\n\nNo such window\n\n\nok\n\n\n{\"x\": 10, \"y\": 20}\n\n\n";
+        let replies = match Batch::parse_replies(kinds, response) {
+            Ok(v) => v,
+            Err(e) => panic!("Error occured: {e}"),
+        };
+        assert!(matches!(replies[0], BatchResponse::Dispatch(Ok(()))));
+        assert!(
+            matches!(&replies[1], BatchResponse::Dispatch(Err(HyprError::NotOkDispatch(msg))) if msg == "No such window")
+        );
+        assert!(matches!(replies[2], BatchResponse::Keyword(Ok(()))));
+        assert!(matches!(
+            replies[3],
+            BatchResponse::Data(Ok(DataResponse::CursorPosition(
+                crate::data::CursorPosition { x: 10, y: 20 }
+            )))
+        ));
+    }
+
+    #[test]
+    fn test_batch_missing_replies() {
+        let batch = Batch::new()
+            .dispatch(DispatchType::Exec("kitty"))
+            .dispatch(DispatchType::Exec("foot"));
+        let (kinds, _) = split(batch);
+        assert!(Batch::parse_replies(kinds, "ok").is_err());
+    }
+}
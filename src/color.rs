@@ -0,0 +1,332 @@
+//! # Color module
+//!
+//! This module provides colors and gradients, which can be parsed from and formatted to
+//! every syntax Hyprland accepts in its config and commands
+//!
+//! ```rust
+//! use hyprland::color::{Color, Gradient};
+//! fn main() -> hyprland::Result<()> {
+//!     let teal: Color = "rgba(33ccffee)".parse()?;
+//!     assert_eq!(teal, Color::new(0x33, 0xcc, 0xff, 0xee));
+//!     assert_eq!("0xee33ccff".parse::<Color>()?, teal);
+//!     assert_eq!("rgb(51, 204, 255)".parse::<Color>()?, teal.with_alpha(0xff));
+//!     assert_eq!(Color::from_hex("#33ccff")?, teal.with_alpha(0xff));
+//!
+//!     let border: Gradient = "rgba(33ccffee) rgba(00ff99ee) 45deg".parse()?;
+//!     assert_eq!(border.colors, [teal, Color::new(0x00, 0xff, 0x99, 0xee)]);
+//!     assert_eq!(border.to_string(), "rgba(33ccffee) rgba(00ff99ee) 45deg");
+//!     Ok(())
+//! }
+//! ```
+
+use crate::error::HyprError;
+use std::str::FromStr;
+
+fn invalid(message: impl Into<String>) -> HyprError {
+    HyprError::InvalidColor(message.into())
+}
+
+/// This struct holds an 8-bit color with an alpha channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Color {
+    /// The red channel
+    pub red: u8,
+    /// The green channel
+    pub green: u8,
+    /// The blue channel
+    pub blue: u8,
+    /// The alpha channel, 0 is transparent
+    pub alpha: u8,
+}
+
+impl Color {
+    /// Creates a color from its channels
+    pub const fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Creates an opaque color from its channels
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::new(red, green, blue, 0xff)
+    }
+
+    /// Creates a color from `0xAARRGGBB`, which is how Hyprland stores colors
+    pub const fn from_argb(argb: u32) -> Self {
+        let [alpha, red, green, blue] = argb.to_be_bytes();
+        Self::new(red, green, blue, alpha)
+    }
+
+    /// Returns the color as `0xAARRGGBB`, which is how Hyprland stores colors
+    pub const fn to_argb(&self) -> u32 {
+        u32::from_be_bytes([self.alpha, self.red, self.green, self.blue])
+    }
+
+    /// Returns the color with another alpha channel
+    pub const fn with_alpha(mut self, alpha: u8) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Creates a color from a `RRGGBB` or `RRGGBBAA` hex string, optionally starting with `#`
+    pub fn from_hex(hex: &str) -> crate::Result<Self> {
+        let digits = hex.trim().trim_start_matches('#');
+        let error = || invalid(format!("`{hex}` is not a RRGGBB or RRGGBBAA hex color"));
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+        let value = u32::from_str_radix(digits, 16).map_err(|_| error())?;
+        match digits.len() {
+            6 => Ok(Self::from_argb(0xff000000 | value)),
+            8 => Ok(Self::from_argb(value.rotate_right(8))),
+            _ => Err(error()),
+        }
+    }
+
+    /// Returns the color as a `#RRGGBBAA` hex string
+    pub fn to_hex(&self) -> String {
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            self.red, self.green, self.blue, self.alpha
+        )
+    }
+
+    /// Parses the arguments of `rgb()` or `rgba()`, either hex digits or decimal channels
+    fn from_function(s: &str, args: &str, alpha: bool) -> crate::Result<Self> {
+        let args: Vec<&str> = args.split(',').map(str::trim).collect();
+        let channel = |arg: &str| {
+            arg.parse::<u8>()
+                .map_err(|_| invalid(format!("`{arg}` in `{s}` is not a channel from 0 to 255")))
+        };
+        match (args.as_slice(), alpha) {
+            ([hex], false) if hex.len() == 6 => Self::from_hex(hex),
+            ([hex], true) if hex.len() == 8 => Self::from_hex(hex),
+            ([r, g, b], false) => Ok(Self::rgb(channel(r)?, channel(g)?, channel(b)?)),
+            ([r, g, b, a], true) => {
+                let a = a
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|a| (0.0..=1.0).contains(a))
+                    .ok_or_else(|| {
+                        invalid(format!("`{a}` in `{s}` is not an alpha from 0 to 1"))
+                    })?;
+                Ok(Self::new(
+                    channel(r)?,
+                    channel(g)?,
+                    channel(b)?,
+                    (a * 255.0).round() as u8,
+                ))
+            }
+            _ => Err(invalid(format!("`{s}` has the wrong number of arguments"))),
+        }
+    }
+}
+
+impl std::fmt::Display for Color {
+    /// Formats the color as `rgba(RRGGBBAA)`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "rgba({:02x}{:02x}{:02x}{:02x})",
+            self.red, self.green, self.blue, self.alpha
+        )
+    }
+}
+
+impl FromStr for Color {
+    type Err = HyprError;
+
+    /// Parses `rgba(RRGGBBAA)`, `rgba(r, g, b, alpha)`, `rgb(RRGGBB)`, `rgb(r, g, b)`,
+    /// `0xAARRGGBB`, `#RRGGBB`, `#RRGGBBAA` or `AARRGGBB` as a decimal number
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if let Some(args) = trimmed
+            .strip_prefix("rgba(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            Self::from_function(s, args, true)
+        } else if let Some(args) = trimmed
+            .strip_prefix("rgb(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            Self::from_function(s, args, false)
+        } else if let Some(hex) = trimmed.strip_prefix("0x") {
+            u32::from_str_radix(hex, 16)
+                .map(Self::from_argb)
+                .map_err(|_| invalid(format!("`{s}` is not a 0xAARRGGBB color")))
+        } else if trimmed.starts_with('#') {
+            Self::from_hex(trimmed)
+        } else {
+            trimmed
+                .parse::<u32>()
+                .map(Self::from_argb)
+                .map_err(|e| invalid(format!("`{s}` is not a color: {e}")))
+        }
+    }
+}
+
+impl From<u32> for Color {
+    /// Creates a color from `0xAARRGGBB`
+    fn from(argb: u32) -> Self {
+        Self::from_argb(argb)
+    }
+}
+
+impl From<Color> for u32 {
+    /// Returns the color as `0xAARRGGBB`
+    fn from(color: Color) -> Self {
+        color.to_argb()
+    }
+}
+
+/// This struct holds a gradient of colors, used for borders
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Gradient {
+    /// The colors of the gradient
+    pub colors: Vec<Color>,
+    /// The angle of the gradient in degrees
+    pub angle: f64,
+}
+
+impl Gradient {
+    /// Creates a gradient from the colors, at the angle in degrees
+    pub fn new<I: IntoIterator<Item = Color>>(colors: I, angle: f64) -> Self {
+        Self {
+            colors: colors.into_iter().collect(),
+            angle,
+        }
+    }
+}
+
+impl std::fmt::Display for Gradient {
+    /// Formats the gradient as the colors followed by the angle, like `rgba(33ccffee) rgba(00ff99ee) 45deg`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for color in &self.colors {
+            write!(f, "{color} ")?;
+        }
+        write!(f, "{}deg", self.angle)
+    }
+}
+
+impl FromStr for Gradient {
+    type Err = HyprError;
+
+    /// Parses colors separated by spaces, optionally followed by an angle like `45deg`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut gradient = Self::default();
+        let mut parts = split_outside_parens(s).peekable();
+        while let Some(part) = parts.next() {
+            match part.strip_suffix("deg") {
+                Some(angle) if parts.peek().is_none() && !gradient.colors.is_empty() => {
+                    gradient.angle = angle
+                        .parse()
+                        .map_err(|_| invalid(format!("`{part}` in `{s}` is not an angle")))?;
+                }
+                _ => gradient.colors.push(part.parse()?),
+            }
+        }
+        if gradient.colors.is_empty() {
+            return Err(invalid(format!("`{s}` has no colors")));
+        }
+        Ok(gradient)
+    }
+}
+
+impl From<Color> for Gradient {
+    fn from(color: Color) -> Self {
+        Self::new([color], 0.0)
+    }
+}
+
+/// Splits on whitespace, except inside parentheses like in `rgba(0, 0, 0, 0.5)`
+fn split_outside_parens(s: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0usize;
+    s.split(move |c: char| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        depth == 0 && c.is_whitespace()
+    })
+    .filter(|part| !part.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colors() -> crate::Result<()> {
+        let color = Color::new(0x33, 0xcc, 0xff, 0xee);
+        for syntax in [
+            "rgba(33ccffee)",
+            "rgba(33CCFFEE)",
+            " rgba(51, 204, 255, 0.933) ",
+            "0xee33ccff",
+            "#33ccffee",
+            "3996372223",
+        ] {
+            assert_eq!(syntax.parse::<Color>()?, color, "{syntax}");
+        }
+        let opaque = color.with_alpha(0xff);
+        for syntax in ["rgb(33ccff)", "rgb(51,204,255)", "#33ccff"] {
+            assert_eq!(syntax.parse::<Color>()?, opaque, "{syntax}");
+        }
+        assert_eq!(color.to_string(), "rgba(33ccffee)");
+        assert_eq!(color.to_hex(), "#33ccffee");
+        assert_eq!(color.to_argb(), 0xee33ccff);
+        assert_eq!(Color::from(0xee33ccff), color);
+
+        for invalid in [
+            "rgba(33ccff)",
+            "rgb(256, 0, 0)",
+            "rgba(0, 0, 0, 2)",
+            "0xnope",
+            "#12345",
+            "#+1234567",
+            "red",
+            "4294967296",
+            "-1",
+        ] {
+            assert!(
+                matches!(invalid.parse::<Color>(), Err(HyprError::InvalidColor(_))),
+                "{invalid}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_gradients() -> crate::Result<()> {
+        let gradient: Gradient =
+            "rgba(33ccffee)  rgba(0, 255, 153, 0.5) 0xff000000 45deg".parse()?;
+        assert_eq!(
+            gradient,
+            Gradient::new(
+                [
+                    Color::new(0x33, 0xcc, 0xff, 0xee),
+                    Color::new(0x00, 0xff, 0x99, 0x80),
+                    Color::rgb(0, 0, 0),
+                ],
+                45.0
+            )
+        );
+        assert_eq!(
+            gradient.to_string(),
+            "rgba(33ccffee) rgba(00ff9980) rgba(000000ff) 45deg"
+        );
+        assert_eq!(
+            "rgb(ff0000)".parse::<Gradient>()?,
+            Color::rgb(0xff, 0, 0).into()
+        );
+        for invalid in ["", "45deg", "rgb(ff0000) 45deg rgb(00ff00)"] {
+            assert!(invalid.parse::<Gradient>().is_err(), "{invalid}");
+        }
+        Ok(())
+    }
+}
@@ -2,8 +2,43 @@
 //!
 
 use crate::dispatch::{gen_dispatch_str, DispatchType};
+use crate::error::HyprError;
 use crate::keyword::Keyword;
 
+/// Creates the error for a rule that can't be sent to Hyprland
+fn invalid(message: impl Into<String>) -> HyprError {
+    HyprError::InvalidRule(message.into())
+}
+
+/// Checks that a text argument can be sent as part of a rule
+fn validate_text(what: &str, text: &str) -> crate::Result<()> {
+    if text.trim().is_empty() {
+        Err(invalid(format!("{what} can not be empty")))
+    } else if text.contains(['\n', ',']) {
+        Err(invalid(format!(
+            "{what} `{text}` can not contain newlines or commas"
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Module for reading and writing hyprlang config files
+pub mod hyprlang;
+
+/// Module for building window rules
+pub mod window_rules;
+
+/// Module for building layer rules
+pub mod layer_rules;
+
+/// Module for building workspace rules
+pub mod workspace_rules;
+
+/// Module for configuring monitors
+#[cfg(feature = "data")]
+pub mod monitors;
+
 /// Module providing stuff for adding an removing keybinds
 pub mod binds {
     use super::*;
@@ -0,0 +1,904 @@
+//! # Hyprlang module
+//!
+//! This module parses and writes config files in the hyprlang format used by `hyprland.conf`
+//!
+//! ## Usage
+//!
+//! ```rust, no_run
+//! use hyprland::config::hyprlang::{Config, Document};
+//!
+//! fn main() -> hyprland::Result<()> {
+//!     // read the config with everything it sources
+//!     let config = Config::load("/home/user/.config/hypr/hyprland.conf")?;
+//!     for bind in config.get_all("bind") {
+//!         println!("{}", bind.value);
+//!     }
+//!
+//!     // edit a single file, keeping its comments
+//!     let mut document = Document::parse("general {\n    gaps_in = 5 # inner gaps\n}\n")?;
+//!     document.set("general:gaps_in", "10");
+//!     assert_eq!(document.to_string(), "general {\n    gaps_in = 10 # inner gaps\n}\n");
+//!     Ok(())
+//! }
+//! ```
+
+use crate::error::HyprError;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// The maximum depth of nested `source =` includes
+const MAX_SOURCE_DEPTH: usize = 32;
+
+/// Categories that are identified by one of their values instead of their name,
+/// like `device { name = ... }`, together with the key holding the identifier
+const KEYED_CATEGORIES: [(&str, &str); 2] = [("device", "name"), ("monitorv2", "output")];
+
+/// This struct holds the location of a node in its file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    /// The line the node starts on, starting at 1
+    pub line: usize,
+    /// The byte offset the node starts at
+    pub start: usize,
+    /// The byte offset after the node ends
+    pub end: usize,
+}
+
+/// This struct holds an error found while parsing a config file
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+#[display("{}line {}: {message}", path.as_ref().map(|p| format!("{}, ", p.display())).unwrap_or_default(), span.line)]
+pub struct ParseError {
+    /// The file, if it was read from disk
+    pub path: Option<PathBuf>,
+    /// Where the error occurred
+    pub span: Span,
+    /// What went wrong
+    pub message: String,
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_error(span: Span, message: impl Into<String>) -> HyprError {
+    HyprError::ConfigParse(ParseError {
+        path: None,
+        span,
+        message: message.into(),
+    })
+}
+
+/// This struct holds a `key = value` line, which is also used for variables and sources
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    /// The key, without the `$` for variables
+    pub key: String,
+    /// The value, with `##` unescaped to `#`
+    pub value: String,
+    /// The comment after the value, without the `#`
+    pub comment: Option<String>,
+    /// Where the line is
+    pub span: Span,
+}
+
+impl Assignment {
+    /// Creates an assignment without a comment or location
+    pub fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+            comment: None,
+            span: Span::default(),
+        }
+    }
+}
+
+/// This struct holds a `name { ... }` block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Category {
+    /// The name of the category
+    pub name: String,
+    /// The nodes inside the category
+    pub nodes: Vec<Node>,
+    /// The comment after the opening brace, without the `#`
+    pub comment: Option<String>,
+    /// Where the category is, from its name to the closing brace
+    pub span: Span,
+}
+
+impl Category {
+    /// Creates an empty category
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            nodes: vec![],
+            comment: None,
+            span: Span::default(),
+        }
+    }
+
+    /// Returns the identifier of keyed categories,
+    /// like the `name` of a `device` block, or the part after `:` in `device:name { ... }`
+    pub fn key(&self) -> Option<&str> {
+        let (name, key) = match self.name.split_once(':') {
+            Some((name, key)) => (name, Some(key)),
+            None => (self.name.as_str(), None),
+        };
+        let (_, key_field) = KEYED_CATEGORIES.iter().find(|(n, _)| *n == name)?;
+        key.or_else(|| {
+            self.nodes.iter().find_map(|node| match node {
+                Node::Assignment(a) if a.key == *key_field => Some(a.value.as_str()),
+                _ => None,
+            })
+        })
+    }
+
+    /// Creates a category from the name used in full keys,
+    /// adding the identifier of keyed categories like `device[my-mouse]`
+    fn from_path_name(path_name: &str) -> Self {
+        let keyed = path_name
+            .strip_suffix(']')
+            .and_then(|rest| rest.split_once('['))
+            .and_then(|(name, key)| {
+                let (_, key_field) = KEYED_CATEGORIES.iter().find(|(n, _)| *n == name)?;
+                Some((name, key_field, key))
+            });
+        match keyed {
+            Some((name, key_field, key)) => Self {
+                nodes: vec![Node::Assignment(Assignment::new(*key_field, key))],
+                ..Self::new(name)
+            },
+            None => Self::new(path_name),
+        }
+    }
+
+    /// Returns the name used in full keys, like `device[my-mouse]` for keyed categories
+    fn path_name(&self) -> String {
+        match self.key() {
+            Some(key) => format!("{}[{key}]", self.name.split(':').next().unwrap_or_default()),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// This enum holds a node of a config file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    /// An empty line
+    Blank(Span),
+    /// A line only holding a comment, without the `#`
+    Comment(String, Span),
+    /// A `key = value` line
+    Assignment(Assignment),
+    /// A `$name = value` line
+    Variable(Assignment),
+    /// A `source = path` line
+    Source(Assignment),
+    /// A `name { ... }` block
+    Category(Category),
+}
+
+impl Node {
+    /// Returns where the node is
+    pub fn span(&self) -> Span {
+        match self {
+            Node::Blank(span) | Node::Comment(_, span) => *span,
+            Node::Assignment(a) | Node::Variable(a) | Node::Source(a) => a.span,
+            Node::Category(c) => c.span,
+        }
+    }
+}
+
+/// Splits off a comment, unescaping `##` to `#`
+fn split_comment(text: &str) -> (String, Option<String>) {
+    let mut value = String::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c != '#' {
+            value.push(c);
+        } else if chars.peek().map(|(_, c)| *c) == Some('#') {
+            chars.next();
+            value.push('#');
+        } else {
+            return (value, Some(text[i + 1..].to_string()));
+        }
+    }
+    (value, None)
+}
+
+/// This struct holds the nodes of a single config file
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Document {
+    /// The top level nodes
+    pub nodes: Vec<Node>,
+}
+
+impl Document {
+    /// This function parses a config file
+    pub fn parse(source: &str) -> crate::Result<Self> {
+        // the categories being parsed, with the top level at the bottom
+        let mut stack: Vec<Category> = vec![];
+        let mut nodes = vec![];
+        let mut offset = 0;
+        for (index, raw_line) in source.split_inclusive('\n').enumerate() {
+            let span = Span {
+                line: index + 1,
+                start: offset,
+                end: offset + raw_line.trim_end_matches(['\n', '\r']).len(),
+            };
+            offset += raw_line.len();
+            let line = raw_line.trim();
+
+            let node = if line.is_empty() {
+                Node::Blank(span)
+            } else if let Some(comment) = line.strip_prefix('#').filter(|c| !c.starts_with('#')) {
+                Node::Comment(comment.to_string(), span)
+            } else {
+                let (content, comment) = split_comment(line);
+                let content = content.trim();
+                if content == "}" {
+                    let Some(mut category) = stack.pop() else {
+                        return Err(parse_error(span, "unexpected `}`"));
+                    };
+                    category.span.end = span.end;
+                    let node = Node::Category(category);
+                    match stack.last_mut() {
+                        Some(parent) => parent.nodes.push(node),
+                        None => nodes.push(node),
+                    }
+                    continue;
+                } else if let Some(name) = content.strip_suffix('{') {
+                    let name = name.trim();
+                    if name.is_empty() || name.contains('=') {
+                        return Err(parse_error(span, "invalid category name"));
+                    }
+                    stack.push(Category {
+                        name: name.to_string(),
+                        nodes: vec![],
+                        comment,
+                        span,
+                    });
+                    continue;
+                } else if let Some((key, value)) = content.split_once('=') {
+                    let key = key.trim();
+                    let assignment = |key: &str| Assignment {
+                        key: key.to_string(),
+                        value: value.trim().to_string(),
+                        comment: comment.clone(),
+                        span,
+                    };
+                    if key.is_empty() || key == "$" {
+                        return Err(parse_error(span, "missing key"));
+                    } else if let Some(name) = key.strip_prefix('$') {
+                        Node::Variable(assignment(name))
+                    } else if key == "source" {
+                        Node::Source(assignment(key))
+                    } else {
+                        Node::Assignment(assignment(key))
+                    }
+                } else {
+                    return Err(parse_error(span, format!("expected `=` in `{content}`")));
+                }
+            };
+            match stack.last_mut() {
+                Some(category) => category.nodes.push(node),
+                None => nodes.push(node),
+            }
+        }
+        if let Some(category) = stack.pop() {
+            return Err(parse_error(
+                category.span,
+                format!("category `{}` is never closed", category.name),
+            ));
+        }
+        Ok(Self { nodes })
+    }
+
+    /// This function reads and parses a config file, without following `source =` lines
+    pub fn read(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        Self::parse(&std::fs::read_to_string(path)?).map_err(|e| with_path(e, path))
+    }
+
+    /// This method returns the last assignment to the key, which is the one Hyprland uses
+    ///
+    /// Keys of nested categories are joined with `:`, like `decoration:blur:enabled`
+    pub fn get(&self, key: &str) -> Option<&Assignment> {
+        self.get_all(key).pop()
+    }
+
+    /// This method returns every assignment to the key, for keywords that can be repeated like `bind`
+    pub fn get_all(&self, key: &str) -> Vec<&Assignment> {
+        let mut found = vec![];
+        visit(&self.nodes, "", &mut |path, node| {
+            if let Node::Assignment(a) = node {
+                if join_key(path, &a.key) == key {
+                    found.push(a);
+                }
+            }
+        });
+        found
+    }
+
+    /// This method changes the value of the last assignment to the key,
+    /// or adds one, creating the categories it is in when needed
+    pub fn set(&mut self, key: &str, value: impl Into<String>) {
+        let value = value.into();
+        if let Some(assignment) = last_assignment_mut(&mut self.nodes, "", key) {
+            assignment.value = value;
+            return;
+        }
+        let mut parts: Vec<&str> = key.split(':').collect();
+        let last = parts.pop().unwrap_or_default();
+        let mut nodes = &mut self.nodes;
+        for part in parts {
+            let index = nodes
+                .iter()
+                .position(|node| matches!(node, Node::Category(c) if c.path_name() == part));
+            let index = match index {
+                Some(index) => index,
+                None => {
+                    nodes.push(Node::Category(Category::from_path_name(part)));
+                    nodes.len() - 1
+                }
+            };
+            let Some(Node::Category(category)) = nodes.get_mut(index) else {
+                unreachable!("the index points at a category");
+            };
+            nodes = &mut category.nodes;
+        }
+        nodes.push(Node::Assignment(Assignment::new(last, value)));
+    }
+
+    /// This method adds an assignment to the end of the file, for keywords that can be repeated like `bind`
+    pub fn push(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.nodes
+            .push(Node::Assignment(Assignment::new(key, value)));
+    }
+
+    /// This method removes every assignment to the key, and returns how many were removed
+    pub fn remove(&mut self, key: &str) -> usize {
+        remove_assignments(&mut self.nodes, "", key)
+    }
+}
+
+fn join_key(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}:{key}")
+    }
+}
+
+/// Calls the function for every node, with the full key of the category it is in
+fn visit<'a>(nodes: &'a [Node], path: &str, f: &mut impl FnMut(&str, &'a Node)) {
+    for node in nodes {
+        f(path, node);
+        if let Node::Category(category) = node {
+            visit(&category.nodes, &join_key(path, &category.path_name()), f);
+        }
+    }
+}
+
+fn last_assignment_mut<'a>(
+    nodes: &'a mut [Node],
+    path: &str,
+    key: &str,
+) -> Option<&'a mut Assignment> {
+    for node in nodes.iter_mut().rev() {
+        match node {
+            Node::Assignment(a) if join_key(path, &a.key) == key => return Some(a),
+            Node::Category(category) => {
+                let path = join_key(path, &category.path_name());
+                if let Some(a) = last_assignment_mut(&mut category.nodes, &path, key) {
+                    return Some(a);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn remove_assignments(nodes: &mut Vec<Node>, path: &str, key: &str) -> usize {
+    let before = nodes.len();
+    nodes.retain(|node| !matches!(node, Node::Assignment(a) if join_key(path, &a.key) == key));
+    let mut removed = before - nodes.len();
+    for node in nodes {
+        if let Node::Category(category) = node {
+            let path = join_key(path, &category.path_name());
+            removed += remove_assignments(&mut category.nodes, &path, key);
+        }
+    }
+    removed
+}
+
+fn write_comment(f: &mut std::fmt::Formatter<'_>, comment: &Option<String>) -> std::fmt::Result {
+    match comment {
+        Some(comment) => write!(f, " #{comment}"),
+        None => Ok(()),
+    }
+}
+
+fn write_nodes(f: &mut std::fmt::Formatter<'_>, nodes: &[Node], depth: usize) -> std::fmt::Result {
+    let indent = "    ".repeat(depth);
+    for node in nodes {
+        match node {
+            Node::Blank(_) => writeln!(f)?,
+            Node::Comment(comment, _) => writeln!(f, "{indent}#{comment}")?,
+            Node::Assignment(a) | Node::Variable(a) | Node::Source(a) => {
+                let prefix = if matches!(node, Node::Variable(_)) {
+                    "$"
+                } else {
+                    ""
+                };
+                let value = a.value.replace('#', "##");
+                if value.is_empty() {
+                    write!(f, "{indent}{prefix}{} =", a.key)?;
+                } else {
+                    write!(f, "{indent}{prefix}{} = {value}", a.key)?;
+                }
+                write_comment(f, &a.comment)?;
+                writeln!(f)?;
+            }
+            Node::Category(category) => {
+                write!(f, "{indent}{} {{", category.name)?;
+                write_comment(f, &category.comment)?;
+                writeln!(f)?;
+                write_nodes(f, &category.nodes, depth + 1)?;
+                writeln!(f, "{indent}}}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes the document in the canonical format, keeping comments and blank lines
+impl std::fmt::Display for Document {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_nodes(f, &self.nodes, 0)
+    }
+}
+
+fn with_path(error: HyprError, path: &Path) -> HyprError {
+    match error {
+        // errors from sourced files already hold their path
+        HyprError::ConfigParse(e) => HyprError::ConfigParse(ParseError {
+            path: e.path.or_else(|| Some(path.to_path_buf())),
+            ..e
+        }),
+        e => e,
+    }
+}
+
+/// Matches a file name against a pattern with `*` and `?` wildcards
+fn wildcard_match(pattern: &[char], name: &[char]) -> bool {
+    match (pattern.first(), name.first()) {
+        (None, None) => true,
+        (Some('*'), _) => {
+            wildcard_match(&pattern[1..], name)
+                || (!name.is_empty() && wildcard_match(pattern, &name[1..]))
+        }
+        (Some('?'), Some(_)) => wildcard_match(&pattern[1..], &name[1..]),
+        (Some(p), Some(n)) if p == n => wildcard_match(&pattern[1..], &name[1..]),
+        _ => false,
+    }
+}
+
+/// Expands `~` and the wildcards of a `source =` path, like glob(3) does
+///
+/// Returns `None` if the path starts with `~` but `$HOME` is not set
+fn glob(pattern: &str, relative_to: &Path) -> Option<Vec<PathBuf>> {
+    let path = match pattern.strip_prefix('~') {
+        Some(rest) => PathBuf::from(std::env::var_os("HOME")?).join(rest.trim_start_matches('/')),
+        None => relative_to.join(pattern),
+    };
+    let mut paths = vec![PathBuf::new()];
+    for component in path.components() {
+        let part = component.as_os_str().to_string_lossy();
+        if !part.contains(['*', '?']) {
+            paths.iter_mut().for_each(|path| path.push(component));
+            continue;
+        }
+        let pattern: Vec<char> = part.chars().collect();
+        let mut matched = vec![];
+        for dir in paths {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                // like glob(3), wildcards don't match hidden files
+                if name.starts_with('.') && !part.starts_with('.') {
+                    continue;
+                }
+                if wildcard_match(&pattern, &name.chars().collect::<Vec<_>>()) {
+                    matched.push(dir.join(name));
+                }
+            }
+        }
+        matched.sort();
+        paths = matched;
+    }
+    paths.retain(|path| path.exists());
+    Some(paths)
+}
+
+/// This struct holds a config file read from disk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceFile {
+    /// The path of the file
+    pub path: PathBuf,
+    /// The parsed file
+    pub document: Document,
+}
+
+/// This struct holds an assignment after following sources and expanding variables
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// The full key, with categories joined by `:`, like `decoration:blur:enabled`
+    /// or `device[my-mouse]:sensitivity`
+    pub key: String,
+    /// The value with variables expanded
+    pub value: String,
+    /// The index of the file in [Config::files]
+    pub file: usize,
+    /// Where the assignment is in the file
+    pub span: Span,
+}
+
+/// This struct holds a config file and every file it sources, in the order Hyprland reads them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    files: Vec<SourceFile>,
+    entries: Vec<Entry>,
+    variables: HashMap<String, String>,
+}
+
+impl Config {
+    /// This function reads a config file, following its `source =` lines
+    ///
+    /// Relative sources are resolved from the directory of the file sourcing them,
+    /// and `~` and the `*`/`?` wildcards are expanded
+    pub fn load(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let mut config = Self {
+            files: vec![],
+            entries: vec![],
+            variables: HashMap::new(),
+        };
+        config.load_file(path.as_ref(), &mut vec![])?;
+        Ok(config)
+    }
+
+    fn load_file(&mut self, path: &Path, stack: &mut Vec<PathBuf>) -> crate::Result<()> {
+        let canonical = path.canonicalize()?;
+        let document = Document::read(path)?;
+        let file = self.files.len();
+        self.files.push(SourceFile {
+            path: path.to_path_buf(),
+            document: document.clone(),
+        });
+        stack.push(canonical);
+        let dir = path.parent().unwrap_or(Path::new(""));
+        self.load_nodes(&document.nodes, "", file, dir, stack)
+            .map_err(|e| with_path(e, path))?;
+        stack.pop();
+        Ok(())
+    }
+
+    fn load_nodes(
+        &mut self,
+        nodes: &[Node],
+        path: &str,
+        file: usize,
+        dir: &Path,
+        stack: &mut Vec<PathBuf>,
+    ) -> crate::Result<()> {
+        for node in nodes {
+            match node {
+                Node::Variable(a) => {
+                    let value = self.expand(&a.value);
+                    self.variables.insert(a.key.clone(), value);
+                }
+                Node::Source(a) => {
+                    let pattern = self.expand(&a.value);
+                    let Some(paths) = glob(&pattern, dir) else {
+                        return Err(parse_error(
+                            a.span,
+                            format!("`{pattern}` uses `~`, but $HOME is not set"),
+                        ));
+                    };
+                    if paths.is_empty() {
+                        return Err(parse_error(a.span, format!("no file matches `{pattern}`")));
+                    }
+                    for source in paths {
+                        if stack.contains(&source.canonicalize()?)
+                            || stack.len() >= MAX_SOURCE_DEPTH
+                        {
+                            return Err(parse_error(
+                                a.span,
+                                format!("`{}` sources itself", source.display()),
+                            ));
+                        }
+                        self.load_file(&source, stack)?;
+                    }
+                }
+                Node::Assignment(a) => {
+                    let value = self.expand(&a.value);
+                    self.entries.push(Entry {
+                        key: join_key(path, &a.key),
+                        value,
+                        file,
+                        span: a.span,
+                    });
+                }
+                Node::Category(category) => {
+                    let path = join_key(path, &category.path_name());
+                    self.load_nodes(&category.nodes, &path, file, dir, stack)?;
+                }
+                Node::Blank(_) | Node::Comment(..) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// This method replaces `$name` with the value of the variable, if it is defined
+    pub fn expand(&self, value: &str) -> String {
+        let mut out = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(index) = rest.find('$') {
+            out.push_str(&rest[..index]);
+            let after = &rest[index + 1..];
+            let len = after
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .unwrap_or(after.len());
+            match self.variables.get(&after[..len]) {
+                Some(variable) if len > 0 => out.push_str(variable),
+                _ => out.push_str(&rest[index..index + 1 + len]),
+            }
+            rest = &after[len..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// This method returns the files, starting with the loaded one
+    pub fn files(&self) -> &[SourceFile] {
+        &self.files
+    }
+
+    /// This method returns every assignment in the order Hyprland applies them
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// This method returns the variables defined in the files
+    pub fn variables(&self) -> &HashMap<String, String> {
+        &self.variables
+    }
+
+    /// This method returns the last assignment to the key, which is the one Hyprland uses
+    pub fn get(&self, key: &str) -> Option<&Entry> {
+        self.entries.iter().rev().find(|entry| entry.key == key)
+    }
+
+    /// This method returns every assignment to the key, for keywords that can be repeated like `bind`
+    pub fn get_all(&self, key: &str) -> Vec<&Entry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.key == key)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = "\
+# hyprland.conf
+$mod = SUPER
+$terminal = kitty ## not a comment
+
+monitor = DP-1, 3840x2160@60, 0x0, 1.5
+
+general {
+    gaps_in = 5 # inner
+    border_size = 2
+
+    snap {
+        enabled = true
+    }
+}
+
+device {
+    name = my-mouse
+    sensitivity = -0.5
+}
+
+bind = $mod, Q, exec, $terminal
+bind = $mod, C, killactive,
+decoration:rounding = 10
+";
+
+    #[test]
+    fn test_parse() -> crate::Result<()> {
+        let document = Document::parse(CONFIG)?;
+        assert_eq!(
+            document.nodes[0],
+            Node::Comment(
+                " hyprland.conf".into(),
+                Span {
+                    line: 1,
+                    start: 0,
+                    end: 15,
+                }
+            )
+        );
+        let Node::Variable(terminal) = &document.nodes[2] else {
+            panic!("expected a variable, got {:?}", document.nodes[2]);
+        };
+        assert_eq!(terminal.key, "terminal");
+        assert_eq!(terminal.value, "kitty # not a comment");
+
+        let gaps = document.get("general:gaps_in");
+        assert_eq!(
+            gaps.map(|a| (a.value.as_str(), a.comment.as_deref())),
+            Some(("5", Some(" inner")))
+        );
+        assert_eq!(gaps.map(|a| a.span.line), Some(8));
+        assert_eq!(
+            document
+                .get("general:snap:enabled")
+                .map(|a| a.value.as_str()),
+            Some("true")
+        );
+        assert_eq!(
+            document
+                .get("device[my-mouse]:sensitivity")
+                .map(|a| a.value.as_str()),
+            Some("-0.5")
+        );
+        assert_eq!(document.get_all("bind").len(), 2);
+        assert_eq!(
+            document
+                .get("decoration:rounding")
+                .map(|a| a.value.as_str()),
+            Some("10")
+        );
+
+        let Some(Node::Category(general)) = document.nodes.get(6) else {
+            panic!("expected a category, got {:?}", document.nodes.get(6));
+        };
+        assert_eq!(general.span.line, 7);
+        assert_eq!(
+            &CONFIG[general.span.start..general.span.end].lines().last(),
+            &Some("}")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() {
+        for (source, line) in [
+            ("general {\n    gaps_in = 5\n", 1),
+            ("}\n", 1),
+            ("general {\n    gaps_in\n}\n", 2),
+            ("= 5\n", 1),
+        ] {
+            match Document::parse(source) {
+                Err(HyprError::ConfigParse(e)) => assert_eq!(e.span.line, line, "{source}"),
+                result => panic!("expected a parse error for {source:?}, got {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_write() -> crate::Result<()> {
+        let document = Document::parse(CONFIG)?;
+        assert_eq!(document.to_string(), CONFIG);
+
+        let messy = "general{ # comment\n  gaps_in=5\n\t}\n$a=b\n";
+        let document = Document::parse(messy)?;
+        let written = document.to_string();
+        assert_eq!(written, "general { # comment\n    gaps_in = 5\n}\n$a = b\n");
+        assert_eq!(Document::parse(&written)?.to_string(), written);
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit() -> crate::Result<()> {
+        let mut document = Document::parse(CONFIG)?;
+        document.set("general:border_size", "3");
+        document.set("general:snap:window_gap", "10");
+        document.set("input:touchpad:natural_scroll", "true");
+        document.push("bind", "$mod, F, fullscreen, 0");
+        assert_eq!(document.remove("decoration:rounding"), 1);
+
+        let written = document.to_string();
+        assert!(written.contains("    border_size = 3\n"));
+        assert!(written.contains("        enabled = true\n        window_gap = 10\n"));
+        assert!(written.ends_with(
+            "input {\n    touchpad {\n        natural_scroll = true\n    }\n}\nbind = $mod, F, fullscreen, 0\n"
+        ));
+        assert!(!written.contains("rounding"));
+        assert!(written.contains("gaps_in = 5 # inner\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_keyed() -> crate::Result<()> {
+        let mut document = Document::parse(CONFIG)?;
+        document.set("device[my-mouse]:accel_profile", "flat");
+        document.set("device[my-keyboard]:kb_layout", "de");
+
+        let written = document.to_string();
+        assert!(written.contains(
+            "device {\n    name = my-mouse\n    sensitivity = -0.5\n    accel_profile = flat\n}\n"
+        ));
+        assert!(written.contains("device {\n    name = my-keyboard\n    kb_layout = de\n}\n"));
+        assert!(!written.contains('['));
+
+        let document = Document::parse(&written)?;
+        let value = |key| document.get(key).map(|a| a.value.as_str());
+        assert_eq!(value("device[my-mouse]:accel_profile"), Some("flat"));
+        assert_eq!(value("device[my-mouse]:sensitivity"), Some("-0.5"));
+        assert_eq!(value("device[my-keyboard]:kb_layout"), Some("de"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_load() -> crate::Result<()> {
+        let dir = std::env::temp_dir().join(format!("hyprland-rs-hyprlang-{}", std::process::id()));
+        let conf = dir.join("conf.d");
+        std::fs::create_dir_all(&conf)?;
+        std::fs::write(
+            dir.join("hyprland.conf"),
+            "$mod = SUPER\nsource = conf.d/*.conf\nbind = $mod, Q, exec, $term\n",
+        )?;
+        std::fs::write(
+            conf.join("a.conf"),
+            "$term = kitty\ngeneral {\n    gaps_in = 5\n}\n",
+        )?;
+        std::fs::write(conf.join("b.conf"), "general:gaps_in = 10\n")?;
+        std::fs::write(conf.join(".hidden.conf"), "general:gaps_in = 20\n")?;
+        std::fs::write(conf.join("c.txt"), "broken {\n")?;
+
+        let result = Config::load(dir.join("hyprland.conf"));
+        std::fs::write(conf.join("loop.conf"), "source = ../hyprland.conf\n")?;
+        let looped = Config::load(dir.join("hyprland.conf"));
+        std::fs::write(dir.join("missing.conf"), "\n\nsource = nope/*.conf\n")?;
+        let missing = Config::load(dir.join("missing.conf"));
+        std::fs::remove_dir_all(&dir)?;
+
+        let config = result?;
+        assert_eq!(config.files().len(), 3);
+        assert_eq!(config.files()[1].path, conf.join("a.conf"));
+        assert_eq!(
+            config.get("general:gaps_in").map(|e| e.value.as_str()),
+            Some("10")
+        );
+        assert_eq!(config.get_all("general:gaps_in").len(), 2);
+        let bind = config.get("bind");
+        assert_eq!(
+            bind.map(|e| e.value.as_str()),
+            Some("SUPER, Q, exec, kitty")
+        );
+        assert_eq!(bind.map(|e| (e.file, e.span.line)), Some((0, 3)));
+        assert_eq!(config.expand("$mod $unknown $"), "SUPER $unknown $");
+
+        match looped {
+            Err(HyprError::ConfigParse(e)) => {
+                assert_eq!(e.span.line, 1);
+                assert_eq!(e.path, Some(conf.join("loop.conf")));
+            }
+            result => panic!("expected a parse error, got {result:?}"),
+        }
+        match missing {
+            Err(HyprError::ConfigParse(e)) => {
+                assert_eq!(e.span.line, 3);
+                assert_eq!(e.path, Some(dir.join("missing.conf")));
+            }
+            result => panic!("expected a parse error, got {result:?}"),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,327 @@
+//! # Layer rules module
+//!
+//! This module is used for building `layerrule` rules for layer-shell surfaces
+//! and applying them with [Keyword](crate::keyword::Keyword)
+//!
+//! ## Usage
+//!
+//! ```rust, no_run
+//! use hyprland::config::layer_rules::{LayerRule, LayerTarget, Rule};
+//! use hyprland::data::Layers;
+//! use hyprland::shared::HyprData;
+//! fn main() -> hyprland::Result<()> {
+//!     LayerRule::new()
+//!         .rule(Rule::Blur)
+//!         .rule(Rule::IgnoreZero)
+//!         .target(LayerTarget::Namespace("^(waybar)$".to_string()))
+//!         .apply()?;
+//!
+//!     // blur every layer that is currently open
+//!     let layers = Layers::get()?;
+//!     LayerRule::new()
+//!         .rule(Rule::Blur)
+//!         .targets(layers.namespaces().into_iter().map(LayerTarget::exact))
+//!         .apply()?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use super::{invalid, validate_text};
+use crate::batch::{check_batch, Batch};
+use crate::default_instance;
+use crate::instance::Instance;
+use crate::shared::Address;
+use derive_more::Display;
+
+#[cfg(feature = "data")]
+use crate::data::{LayerClient, Layers};
+
+/// The keyword layer rules are set with
+const KEYWORD: &str = "layerrule";
+
+/// This enum holds what a layer rule does to the layers it targets
+#[derive(Debug, Clone, PartialEq, Display)]
+pub enum Rule {
+    /// Removes the rules set by other rules for the same target
+    #[display("unset")]
+    Unset,
+    /// Disables animations
+    #[display("noanim")]
+    NoAnim,
+    /// Blurs the layer
+    #[display("blur")]
+    Blur,
+    /// Blurs the popups of the layer
+    #[display("blurpopups")]
+    BlurPopups,
+    /// Ignores pixels with an opacity at or below the value, from 0 to 1, when blurring
+    #[display("ignorealpha {_0}")]
+    IgnoreAlpha(f64),
+    /// Ignores fully transparent pixels when blurring
+    #[display("ignorezero")]
+    IgnoreZero,
+    /// Dims everything behind the layer
+    #[display("dimaround")]
+    DimAround,
+    /// Sets xray blur for the layer
+    #[display("xray {}", *_0 as u8)]
+    Xray(bool),
+    /// Sets the animation style
+    #[display("animation {_0}")]
+    Animation(String),
+    /// Sets the order among layers of the same level, higher is above
+    #[display("order {_0}")]
+    Order(i32),
+    /// Renders the layer above the lockscreen, and lets it take input if true
+    #[display("abovelock {}", *_0 as u8)]
+    AboveLock(bool),
+    /// Hides the layer from screen sharing
+    #[display("noscreenshare")]
+    NoScreenShare,
+}
+
+impl Rule {
+    /// This method checks that the rule can be sent to Hyprland
+    pub fn validate(&self) -> crate::Result<()> {
+        match self {
+            Rule::IgnoreAlpha(alpha) if !(0.0..=1.0).contains(alpha) => {
+                Err(invalid(format!("ignorealpha {alpha} is not in 0..=1")))
+            }
+            Rule::Animation(style) => validate_text("animation", style),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// This enum holds the layers a rule applies to
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display)]
+pub enum LayerTarget {
+    /// Layers with a namespace matching the regular expression
+    #[display("{_0}")]
+    Namespace(String),
+    /// The layer with the address
+    #[display("address:{_0}")]
+    Address(Address),
+}
+
+impl LayerTarget {
+    /// Creates a target matching exactly the namespace, by escaping it into a regular expression
+    pub fn exact<Str: AsRef<str>>(namespace: Str) -> Self {
+        let mut pattern = String::from("^(");
+        for c in namespace.as_ref().chars() {
+            if "\\.+*?()|[]{}^$-".contains(c) {
+                pattern.push('\\');
+            }
+            pattern.push(c);
+        }
+        pattern.push_str(")$");
+        Self::Namespace(pattern)
+    }
+
+    /// This method checks that the target can be sent to Hyprland
+    pub fn validate(&self) -> crate::Result<()> {
+        match self {
+            LayerTarget::Namespace(namespace) => validate_text("namespace", namespace),
+            LayerTarget::Address(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "data")]
+impl From<&LayerClient> for LayerTarget {
+    /// Targets the namespace of the layer exactly
+    fn from(layer: &LayerClient) -> Self {
+        Self::exact(&layer.namespace)
+    }
+}
+
+#[cfg(feature = "data")]
+impl Layers {
+    /// This method returns the namespaces of every layer, sorted and without duplicates
+    pub fn namespaces(&self) -> Vec<&str> {
+        let mut namespaces: Vec<&str> = self
+            .iter()
+            .flat_map(|(_, display)| display.iter())
+            .flat_map(|(_, layers)| layers.iter().map(|layer| layer.namespace.as_str()))
+            .collect();
+        namespaces.sort_unstable();
+        namespaces.dedup();
+        namespaces
+    }
+}
+
+/// This struct holds rules and the layers they apply to
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LayerRule {
+    /// The rules
+    pub rules: Vec<Rule>,
+    /// The layers the rules apply to
+    pub targets: Vec<LayerTarget>,
+}
+
+impl LayerRule {
+    /// This method creates a layer rule without rules or targets
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This method adds a rule
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// This method adds a target
+    pub fn target(mut self, target: LayerTarget) -> Self {
+        self.targets.push(target);
+        self
+    }
+
+    /// This method adds many targets
+    pub fn targets<T: IntoIterator<Item = LayerTarget>>(mut self, targets: T) -> Self {
+        self.targets.extend(targets);
+        self
+    }
+
+    /// This method checks that the layer rule can be sent to Hyprland
+    pub fn validate(&self) -> crate::Result<()> {
+        if self.rules.is_empty() {
+            return Err(invalid("a layer rule needs at least one rule"));
+        }
+        if self.targets.is_empty() {
+            return Err(invalid("a layer rule needs at least one target"));
+        }
+        self.rules.iter().try_for_each(Rule::validate)?;
+        self.targets.iter().try_for_each(LayerTarget::validate)
+    }
+
+    /// This method validates the layer rule and returns the value of every `layerrule` keyword,
+    /// one for each rule and target
+    pub fn values(&self) -> crate::Result<Vec<String>> {
+        self.validate()?;
+        Ok(self
+            .targets
+            .iter()
+            .flat_map(|target| {
+                self.rules
+                    .iter()
+                    .map(move |rule| format!("{rule}, {target}"))
+            })
+            .collect())
+    }
+
+    fn batch(&self) -> crate::Result<Batch> {
+        Ok(self
+            .values()?
+            .into_iter()
+            .fold(Batch::new(), |batch, value| batch.keyword(KEYWORD, value)))
+    }
+
+    /// This method validates and adds the layer rule
+    pub fn apply(&self) -> crate::Result<()> {
+        self.instance_apply(default_instance()?)
+    }
+
+    /// This method validates and adds the layer rule
+    pub fn instance_apply(&self, instance: &Instance) -> crate::Result<()> {
+        check_batch(self.batch()?.instance_call(instance)?)
+    }
+
+    /// This method validates and adds the layer rule (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn apply_async(&self) -> crate::Result<()> {
+        self.instance_apply_async(default_instance()?).await
+    }
+
+    /// This method validates and adds the layer rule (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_apply_async(&self, instance: &Instance) -> crate::Result<()> {
+        check_batch(self.batch()?.instance_call_async(instance).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::HyprError;
+    use crate::testing::MockHyprland;
+
+    #[test]
+    fn test_values() -> crate::Result<()> {
+        let rule = LayerRule::new()
+            .rule(Rule::Blur)
+            .rule(Rule::IgnoreAlpha(0.5))
+            .rule(Rule::Animation("slide top".into()))
+            .rule(Rule::Xray(true))
+            .rule(Rule::AboveLock(false))
+            .target(LayerTarget::Namespace("^(waybar)$".into()))
+            .target(LayerTarget::Address(Address::new("55d1c8b1a2c0")));
+        assert_eq!(
+            rule.values()?,
+            [
+                "blur, ^(waybar)$",
+                "ignorealpha 0.5, ^(waybar)$",
+                "animation slide top, ^(waybar)$",
+                "xray 1, ^(waybar)$",
+                "abovelock 0, ^(waybar)$",
+                "blur, address:0x55d1c8b1a2c0",
+                "ignorealpha 0.5, address:0x55d1c8b1a2c0",
+                "animation slide top, address:0x55d1c8b1a2c0",
+                "xray 1, address:0x55d1c8b1a2c0",
+                "abovelock 0, address:0x55d1c8b1a2c0",
+            ]
+        );
+        assert_eq!(
+            LayerTarget::exact("gtk-layer-shell.1").to_string(),
+            r"^(gtk\-layer\-shell\.1)$"
+        );
+
+        for invalid in [
+            LayerRule::new().target(LayerTarget::exact("waybar")),
+            LayerRule::new().rule(Rule::Blur),
+            LayerRule::new()
+                .rule(Rule::IgnoreAlpha(1.5))
+                .target(LayerTarget::exact("waybar")),
+            LayerRule::new()
+                .rule(Rule::Blur)
+                .target(LayerTarget::Namespace("a, b".into())),
+            LayerRule::new()
+                .rule(Rule::Blur)
+                .target(LayerTarget::Namespace(" ".into())),
+            LayerRule::new()
+                .rule(Rule::Animation("slide,top".into()))
+                .target(LayerTarget::exact("waybar")),
+        ] {
+            assert!(
+                matches!(invalid.validate(), Err(HyprError::InvalidRule(_))),
+                "{invalid:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "data")]
+    fn test_apply_to_layers() -> crate::Result<()> {
+        use crate::shared::HyprData;
+
+        let mock = MockHyprland::new()?;
+        let layers = Layers::instance_get(mock.instance())?;
+        assert_eq!(layers.namespaces(), ["wallpaper", "waybar"]);
+        mock.clear_received();
+
+        LayerRule::new()
+            .rule(Rule::NoAnim)
+            .targets(layers.namespaces().into_iter().map(LayerTarget::exact))
+            .instance_apply(mock.instance())?;
+        assert_eq!(
+            mock.received(),
+            [
+                "keyword layerrule noanim, ^(wallpaper)$",
+                "keyword layerrule noanim, ^(waybar)$",
+            ]
+        );
+        Ok(())
+    }
+}
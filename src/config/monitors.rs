@@ -0,0 +1,482 @@
+//! # Monitors module
+//!
+//! This module is used for building `monitor` rules, which configure the mode, position, scale
+//! and more of a monitor, and applying them with [Keyword](crate::keyword::Keyword)
+//!
+//! ## Usage
+//!
+//! ```rust, no_run
+//! use hyprland::config::monitors::{MonitorConfig, MonitorPosition, MonitorScale, Resolution};
+//! use hyprland::data::{Monitor, MonitorMode, Transforms};
+//! use hyprland::dispatch::Direction;
+//! use hyprland::shared::HyprDataActive;
+//! fn main() -> hyprland::Result<()> {
+//!     MonitorConfig::description("Dell Inc. DELL U2720Q")
+//!         .resolution(Resolution::Mode(MonitorMode::with_refresh_rate(2560, 1440, 144.0)))
+//!         .position(MonitorPosition::Auto(Some(Direction::Right)))
+//!         .scale(MonitorScale::Exact(1.25))
+//!         .apply()?;
+//!
+//!     // rotate the focused monitor, keeping everything else as it is
+//!     MonitorConfig::from(&Monitor::get_active()?)
+//!         .transform(Transforms::Normal90)
+//!         .apply()?;
+//!
+//!     MonitorConfig::name("HDMI-A-1").disable().apply()?;
+//!     Ok(())
+//! }
+//! ```
+
+use super::invalid;
+use crate::batch::{check_batch, Batch};
+use crate::data::{Monitor, MonitorMode, Transforms};
+use crate::default_instance;
+use crate::dispatch::Direction;
+use crate::instance::Instance;
+use crate::keyword::options::{OptionType, Vrr};
+use derive_more::Display;
+
+/// The keyword monitors are configured with
+const KEYWORD: &str = "monitor";
+
+/// This enum holds the monitors a config applies to
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display)]
+pub enum MonitorTarget {
+    /// The monitor with the connector name, like `DP-1`
+    #[display("{_0}")]
+    Name(String),
+    /// The monitor with a description starting with the string
+    #[display("desc:{_0}")]
+    Description(String),
+    /// Monitors no other config applies to
+    #[display("")]
+    Fallback,
+}
+
+impl MonitorTarget {
+    /// Targets the monitor by its make, model and serial, so the config follows it
+    /// whatever connector it is plugged into
+    pub fn identity(monitor: &Monitor) -> Self {
+        Self::Description(monitor.identity())
+    }
+}
+
+/// This enum holds the mode a monitor is set to
+#[derive(Debug, Clone, Copy, PartialEq, Display)]
+pub enum Resolution {
+    /// The preferred mode of the monitor
+    #[display("preferred")]
+    Preferred,
+    /// The mode with the highest resolution
+    #[display("highres")]
+    HighRes,
+    /// The mode with the highest refresh rate
+    #[display("highrr")]
+    HighRefreshRate,
+    /// The mode with the widest resolution
+    #[display("maxwidth")]
+    MaxWidth,
+    /// An exact mode
+    #[display("{_0}")]
+    Mode(MonitorMode),
+}
+
+/// This enum holds where a monitor is placed in the layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitorPosition {
+    /// Placed automatically, to the right of the others or in the direction
+    Auto(Option<Direction>),
+    /// Placed at the coordinates, in logical pixels
+    Exact(i32, i32),
+}
+
+impl std::fmt::Display for MonitorPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MonitorPosition::Auto(None) => write!(f, "auto"),
+            MonitorPosition::Auto(Some(direction)) => write!(
+                f,
+                "auto-{}",
+                match direction {
+                    Direction::Up => "up",
+                    Direction::Down => "down",
+                    Direction::Right => "right",
+                    Direction::Left => "left",
+                }
+            ),
+            MonitorPosition::Exact(x, y) => write!(f, "{x}x{y}"),
+        }
+    }
+}
+
+/// This enum holds the scale of a monitor
+#[derive(Debug, Clone, Copy, PartialEq, Display)]
+pub enum MonitorScale {
+    /// Picked by Hyprland from the size and resolution of the monitor
+    #[display("auto")]
+    Auto,
+    /// An exact scale
+    #[display("{_0}")]
+    Exact(f64),
+}
+
+/// This struct holds the configuration of a monitor
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorConfig {
+    /// The monitors the config applies to
+    pub target: MonitorTarget,
+    /// The mode
+    pub resolution: Resolution,
+    /// The position
+    pub position: MonitorPosition,
+    /// The scale
+    pub scale: MonitorScale,
+    /// The rotation and flip, if set
+    pub transform: Option<Transforms>,
+    /// The name of the monitor this one mirrors, if set
+    pub mirror: Option<String>,
+    /// The bit depth, 8 or 10, if set
+    pub bitdepth: Option<u8>,
+    /// The variable refresh rate mode, if set
+    pub vrr: Option<Vrr>,
+    /// If the monitor is disabled, everything else is then ignored
+    pub disabled: bool,
+}
+
+impl MonitorConfig {
+    /// This method creates a config for the monitors, with the preferred mode
+    /// and automatic position and scale
+    pub fn new(target: MonitorTarget) -> Self {
+        Self {
+            target,
+            resolution: Resolution::Preferred,
+            position: MonitorPosition::Auto(None),
+            scale: MonitorScale::Auto,
+            transform: None,
+            mirror: None,
+            bitdepth: None,
+            vrr: None,
+            disabled: false,
+        }
+    }
+
+    /// This method creates a config for the monitor with the connector name
+    pub fn name<Str: ToString>(name: Str) -> Self {
+        Self::new(MonitorTarget::Name(name.to_string()))
+    }
+
+    /// This method creates a config for the monitor with a description starting with the string
+    pub fn description<Str: ToString>(description: Str) -> Self {
+        Self::new(MonitorTarget::Description(description.to_string()))
+    }
+
+    /// This method sets the mode
+    pub fn resolution(mut self, resolution: Resolution) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// This method sets the position
+    pub fn position(mut self, position: MonitorPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// This method sets the scale
+    pub fn scale(mut self, scale: MonitorScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// This method sets the rotation and flip
+    pub fn transform(mut self, transform: Transforms) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
+    /// This method makes the monitor mirror another one
+    pub fn mirror<Str: ToString>(mut self, name: Str) -> Self {
+        self.mirror = Some(name.to_string());
+        self
+    }
+
+    /// This method sets the bit depth
+    pub fn bitdepth(mut self, bitdepth: u8) -> Self {
+        self.bitdepth = Some(bitdepth);
+        self
+    }
+
+    /// This method sets the variable refresh rate mode
+    pub fn vrr(mut self, vrr: Vrr) -> Self {
+        self.vrr = Some(vrr);
+        self
+    }
+
+    /// This method disables the monitor
+    pub fn disable(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    /// This method enables the monitor
+    pub fn enable(mut self) -> Self {
+        self.disabled = false;
+        self
+    }
+
+    /// This method checks that the config can be sent to Hyprland
+    pub fn validate(&self) -> crate::Result<()> {
+        match &self.target {
+            MonitorTarget::Name(s) | MonitorTarget::Description(s)
+                if s.trim().is_empty() || s.contains([',', '\n']) =>
+            {
+                return Err(invalid(format!(
+                    "monitor `{s}` can not be empty or contain commas or newlines"
+                )));
+            }
+            _ => {}
+        }
+        if self.disabled {
+            return Ok(());
+        }
+        if let Resolution::Mode(mode) = self.resolution {
+            if mode.width == 0 || mode.height == 0 {
+                return Err(invalid(format!("mode {mode} has no size")));
+            }
+            if mode
+                .refresh_rate
+                .is_some_and(|rate| rate.is_nan() || rate <= 0.0)
+            {
+                return Err(invalid(format!("mode {mode} has no refresh rate")));
+            }
+        }
+        if let MonitorScale::Exact(scale) = self.scale {
+            if !(scale.is_finite() && scale > 0.0) {
+                return Err(invalid(format!("scale {scale} is not positive")));
+            }
+            if let Resolution::Mode(mode) = self.resolution {
+                // Hyprland rejects scales that don't give a whole logical size
+                let whole = |size: u16| {
+                    let logical = f64::from(size) / scale;
+                    (logical - logical.round()).abs() < 0.01
+                };
+                if !whole(mode.width) || !whole(mode.height) {
+                    return Err(invalid(format!(
+                        "scale {scale} doesn't divide mode {mode} into whole pixels"
+                    )));
+                }
+            }
+        }
+        if let Some(mirror) = &self.mirror {
+            if mirror.trim().is_empty() || mirror.contains([',', '\n']) {
+                return Err(invalid(format!(
+                    "mirrored monitor `{mirror}` can not be empty or contain commas or newlines"
+                )));
+            }
+        }
+        if let Some(bitdepth) = self.bitdepth.filter(|depth| ![8, 10].contains(depth)) {
+            return Err(invalid(format!("bitdepth {bitdepth} is not 8 or 10")));
+        }
+        Ok(())
+    }
+
+    /// This method validates the config, and checks that its mode is one of the modes
+    pub fn validate_modes(&self, modes: &[MonitorMode]) -> crate::Result<()> {
+        self.validate()?;
+        match self.resolution {
+            Resolution::Mode(mode) if !self.disabled && !modes.iter().any(|m| m.matches(&mode)) => {
+                Err(invalid(format!("mode {mode} is not available")))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// This method validates the config, and checks that its mode is available on the monitor
+    pub fn validate_for(&self, monitor: &Monitor) -> crate::Result<()> {
+        self.validate_modes(&monitor.available_modes)
+    }
+
+    fn batch(&self) -> crate::Result<Batch> {
+        self.validate()?;
+        Ok(Batch::new().keyword(KEYWORD, self.to_string()))
+    }
+
+    /// This method validates and applies the config
+    pub fn apply(&self) -> crate::Result<()> {
+        self.instance_apply(default_instance()?)
+    }
+
+    /// This method validates and applies the config
+    pub fn instance_apply(&self, instance: &Instance) -> crate::Result<()> {
+        check_batch(self.batch()?.instance_call(instance)?)
+    }
+
+    /// This method validates and applies the config (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn apply_async(&self) -> crate::Result<()> {
+        self.instance_apply_async(default_instance()?).await
+    }
+
+    /// This method validates and applies the config (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_apply_async(&self, instance: &Instance) -> crate::Result<()> {
+        check_batch(self.batch()?.instance_call_async(instance).await?)
+    }
+}
+
+impl std::fmt::Display for MonitorConfig {
+    /// Formats the config as the value of a `monitor` keyword
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.disabled {
+            return write!(f, "{}, disable", self.target);
+        }
+        write!(
+            f,
+            "{}, {}, {}, {}",
+            self.target, self.resolution, self.position, self.scale
+        )?;
+        if let Some(transform) = self.transform {
+            write!(f, ", transform, {}", transform as u8)?;
+        }
+        if let Some(mirror) = &self.mirror {
+            write!(f, ", mirror, {mirror}")?;
+        }
+        if let Some(bitdepth) = self.bitdepth {
+            write!(f, ", bitdepth, {bitdepth}")?;
+        }
+        if let Some(vrr) = self.vrr {
+            write!(f, ", vrr, {}", vrr.to_option())?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&Monitor> for MonitorConfig {
+    /// Creates a config that keeps the monitor as it currently is,
+    /// except for the vrr mode, which keeps its configured value
+    fn from(monitor: &Monitor) -> Self {
+        Self {
+            target: MonitorTarget::Name(monitor.name.clone()),
+            resolution: Resolution::Mode(MonitorMode::with_refresh_rate(
+                monitor.width,
+                monitor.height,
+                f64::from(monitor.refresh_rate),
+            )),
+            position: MonitorPosition::Exact(monitor.x, monitor.y),
+            scale: MonitorScale::Exact(f64::from(monitor.scale)),
+            transform: Some(monitor.transform),
+            mirror: monitor.mirror_of.clone(),
+            bitdepth: None,
+            // the monitor only reports if vrr is active right now, not the configured mode
+            vrr: None,
+            disabled: monitor.disabled,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::HyprError;
+    use crate::shared::HyprData;
+    use crate::testing::MockHyprland;
+
+    #[test]
+    fn test_values() -> crate::Result<()> {
+        let config = MonitorConfig::description("Dell Inc. DELL U2720Q")
+            .resolution(Resolution::Mode(MonitorMode::with_refresh_rate(
+                2560, 1440, 143.99899,
+            )))
+            .position(MonitorPosition::Exact(-2560, 0))
+            .scale(MonitorScale::Exact(1.25))
+            .transform(Transforms::Flipped90)
+            .mirror("DP-2")
+            .bitdepth(10)
+            .vrr(Vrr::Fullscreen);
+        assert_eq!(
+            config.to_string(),
+            "desc:Dell Inc. DELL U2720Q, 2560x1440@143.999, -2560x0, 1.25, \
+             transform, 5, mirror, DP-2, bitdepth, 10, vrr, 2"
+        );
+        assert_eq!(
+            MonitorConfig::new(MonitorTarget::Fallback)
+                .position(MonitorPosition::Auto(Some(Direction::Left)))
+                .to_string(),
+            ", preferred, auto-left, auto"
+        );
+        assert_eq!(
+            MonitorConfig::name("HDMI-A-1").disable().to_string(),
+            "HDMI-A-1, disable"
+        );
+        assert_eq!(
+            "1920x1080@60.00Hz".parse::<MonitorMode>()?,
+            MonitorMode::with_refresh_rate(1920, 1080, 60.0)
+        );
+
+        let modes = [
+            MonitorMode::with_refresh_rate(2560, 1440, 144.0),
+            MonitorMode::with_refresh_rate(1920, 1080, 60.0),
+        ];
+        config.validate_modes(&modes)?;
+        for invalid in [
+            config.clone().scale(MonitorScale::Exact(1.3)),
+            config.clone().bitdepth(12),
+            config.clone().mirror(""),
+            MonitorConfig::name("DP-1, DP-2"),
+            MonitorConfig::name("DP-1").resolution(Resolution::Mode(MonitorMode::new(1280, 720))),
+        ] {
+            assert!(
+                matches!(
+                    invalid.validate_modes(&modes),
+                    Err(HyprError::InvalidRule(_))
+                ),
+                "{invalid:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_from_monitor() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let monitors = crate::data::Monitors::instance_get(mock.instance())?;
+        mock.clear_received();
+        for monitor in monitors.iter() {
+            let config = MonitorConfig::from(monitor).scale(MonitorScale::Exact(2.0));
+            config.validate_for(monitor)?;
+            config.instance_apply(mock.instance())?;
+
+            let mode = monitor.find_mode(1920, 1080).copied();
+            MonitorConfig::new(MonitorTarget::identity(monitor))
+                .resolution(mode.map_or(Resolution::Preferred, Resolution::Mode))
+                .instance_apply(mock.instance())?;
+        }
+        assert_eq!(
+            mock.received(),
+            [
+                "keyword monitor DP-1, 2560x1440@144, 0x0, 2, transform, 0",
+                "keyword monitor desc:Dell Inc. DELL U2720Q 6KFDN13, 1920x1080@60, auto, auto",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_vrr_mode() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let monitors = crate::data::Monitors::instance_get(mock.instance())?;
+        let Some(mut monitor) = monitors.into_iter().next() else {
+            panic!("the fixture has monitors");
+        };
+        // in fullscreen only mode, vrr is only active while a window is fullscreen
+        monitor.vrr = true;
+        let config = MonitorConfig::from(&monitor);
+        assert_eq!(config.vrr, None);
+        assert!(!config.to_string().contains("vrr"));
+
+        let config = config.vrr(Vrr::Fullscreen);
+        config.validate_for(&monitor)?;
+        assert!(config.to_string().ends_with(", vrr, 2"));
+        Ok(())
+    }
+}
@@ -0,0 +1,693 @@
+//! # Window rules module
+//!
+//! This module is used for building `windowrulev2` rules and applying them with [crate::keyword::Keyword]
+//!
+//! ## Usage
+//!
+//! ```rust, no_run
+//! use hyprland::config::window_rules::{Dimension, Matcher, Rule, WindowRule};
+//! fn main() -> hyprland::Result<()> {
+//!     WindowRule::new()
+//!         .rule(Rule::Float)
+//!         .rule(Rule::Size(Dimension::Percent(50.0), Dimension::Percent(50.0)))
+//!         .rule(Rule::Center(false))
+//!         .matching(Matcher::Class("^(pavucontrol)$".to_string()))
+//!         .apply()?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use super::{invalid, validate_text};
+use crate::batch::{check_batch, Batch};
+use crate::color::Gradient;
+use crate::default_instance;
+use crate::dispatch::OwnedWorkspaceIdentifierWithSpecial;
+use crate::instance::Instance;
+use crate::shared::WorkspaceId;
+use derive_more::Display;
+use std::fmt;
+
+/// The keyword window rules are set with
+const KEYWORD: &str = "windowrulev2";
+
+/// This enum holds a size or coordinate of a window rule
+#[derive(Debug, Clone, PartialEq, Display)]
+pub enum Dimension {
+    /// An amount of pixels
+    #[display("{_0}")]
+    Pixels(i32),
+    /// A percentage of the monitor size
+    #[display("{_0}%")]
+    Percent(f64),
+    /// An expression like `100%-w-10`, where `w` and `h` are the size of the window
+    /// and `monitor_w` and `monitor_h` the size of the monitor
+    #[display("{_0}")]
+    Expression(String),
+}
+
+impl From<i32> for Dimension {
+    fn from(pixels: i32) -> Self {
+        Self::Pixels(pixels)
+    }
+}
+
+impl Dimension {
+    fn validate(&self) -> crate::Result<()> {
+        match self {
+            Dimension::Percent(p) if !p.is_finite() => Err(invalid(format!("{p}% is not a size"))),
+            Dimension::Expression(e) if e.is_empty() || e.contains([' ', ',']) => Err(invalid(
+                format!("`{e}` can not be empty or contain spaces or commas"),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The type of content a window shows
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum ContentType {
+    #[display("none")]
+    None,
+    #[display("photo")]
+    Photo,
+    #[display("video")]
+    Video,
+    #[display("game")]
+    Game,
+}
+
+/// When a window inhibits idling
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum IdleInhibitMode {
+    /// Never
+    #[display("none")]
+    None,
+    /// Always
+    #[display("always")]
+    Always,
+    /// While the window is focused
+    #[display("focus")]
+    Focus,
+    /// While the window is fullscreen
+    #[display("fullscreen")]
+    Fullscreen,
+}
+
+/// Requests of the window that are ignored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum SuppressEvent {
+    /// Requests to become fullscreen
+    #[display("fullscreen")]
+    Fullscreen,
+    /// Requests to maximize
+    #[display("maximize")]
+    Maximize,
+    /// Requests to be activated
+    #[display("activate")]
+    Activate,
+    /// Requests to be activated and focused
+    #[display("activatefocus")]
+    ActivateFocus,
+    /// Requests to become fullscreen on a specific monitor
+    #[display("fullscreenoutput")]
+    FullscreenOutput,
+}
+
+/// This enum holds what a window rule does to the windows it matches
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    /// Floats the window
+    Float,
+    /// Tiles the window
+    Tile,
+    /// Makes the window fullscreen
+    Fullscreen,
+    /// Maximizes the window
+    Maximize,
+    /// Remembers the size of floating windows between launches
+    PersistentSize,
+    /// Sets the fullscreen state, internal to Hyprland and sent to the client, from 0 to 3
+    FullscreenState(u8, u8),
+    /// Moves a floating window
+    Move(Dimension, Dimension),
+    /// Resizes a floating window
+    Size(Dimension, Dimension),
+    /// Centers a floating window, respecting reserved areas if true
+    Center(bool),
+    /// Pseudotiles the window
+    Pseudo,
+    /// Opens the window on a monitor, by name or id
+    Monitor(String),
+    /// Opens the window on a workspace, without switching to it if silent
+    Workspace(OwnedWorkspaceIdentifierWithSpecial, bool),
+    /// Doesn't focus the window when it opens
+    NoInitialFocus,
+    /// Pins the window to all workspaces
+    Pin,
+    /// Removes the rules set by other rules for the same matchers
+    Unset,
+    /// Removes the maximum size of the window
+    NoMaxSize,
+    /// Keeps the window focused while it is visible
+    StayFocused,
+    /// Sets group options, like `set always` or `deny`
+    Group(String),
+    /// Ignores requests of the window
+    SuppressEvent(Vec<SuppressEvent>),
+    /// Sets the content type of the window
+    Content(ContentType),
+    /// Ignores closing the window with `killactive` for a time in milliseconds
+    NoCloseFor(u32),
+    /// Sets the opacity when active, and optionally when inactive and fullscreen.
+    /// Without an inactive opacity, the active one is also used for inactive windows
+    Opacity(f64, Option<f64>, Option<f64>),
+    /// Sets the animation style
+    Animation(String),
+    /// Sets the border color when active, and optionally when inactive
+    BorderColor(Gradient, Option<Gradient>),
+    /// Inhibits idling
+    IdleInhibit(IdleInhibitMode),
+    /// Dims everything around the window
+    DimAround,
+    /// Makes the window opaque
+    Opaque,
+    /// Ignores the alpha channel of the window
+    ForceRgbx,
+    /// Syncs the fullscreen state to the client
+    SyncFullscreen,
+    /// Allows tearing for the window
+    Immediate,
+    /// Sets xray blur for the window
+    Xray(bool),
+    /// Keeps rendering the window while it isn't visible
+    RenderUnfocused,
+    /// Disables blur
+    NoBlur,
+    /// Disables borders
+    NoBorder,
+    /// Disables dimming
+    NoDim,
+    /// Disables focus
+    NoFocus,
+    /// Doesn't focus the window when the cursor moves over it
+    NoFollowMouse,
+    /// Disables shadows
+    NoShadow,
+    /// Disables animations
+    NoAnim,
+    /// Disables rounding
+    NoRounding,
+    /// Hides the window from screen sharing
+    NoScreenShare,
+    /// Disables variable refresh rate
+    NoVrr,
+    /// Sets the rounding
+    Rounding(u32),
+    /// Sets the rounding power
+    RoundingPower(f64),
+    /// Sets the border size
+    BorderSize(u32),
+    /// Keeps the aspect ratio when resizing
+    KeepAspectRatio,
+    /// Adds a tag, or toggles, adds or removes it with a `+` or `-` prefix
+    Tag(String),
+    /// Sets the maximum size
+    MaxSize(u32, u32),
+    /// Sets the minimum size
+    MinSize(u32, u32),
+    /// Forces the window to allow input
+    AllowsInput,
+    /// Focuses the window when it requests to be activated
+    FocusOnActivate,
+    /// Sets the scroll multiplier of mice
+    ScrollMouse(f64),
+    /// Sets the scroll multiplier of touchpads
+    ScrollTouchpad(f64),
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::Float => write!(f, "float"),
+            Rule::Tile => write!(f, "tile"),
+            Rule::Fullscreen => write!(f, "fullscreen"),
+            Rule::Maximize => write!(f, "maximize"),
+            Rule::PersistentSize => write!(f, "persistentsize"),
+            Rule::FullscreenState(internal, client) => {
+                write!(f, "fullscreenstate {internal} {client}")
+            }
+            Rule::Move(x, y) => write!(f, "move {x} {y}"),
+            Rule::Size(w, h) => write!(f, "size {w} {h}"),
+            Rule::Center(false) => write!(f, "center"),
+            Rule::Center(true) => write!(f, "center 1"),
+            Rule::Pseudo => write!(f, "pseudo"),
+            Rule::Monitor(monitor) => write!(f, "monitor {monitor}"),
+            Rule::Workspace(workspace, false) => write!(f, "workspace {workspace}"),
+            Rule::Workspace(workspace, true) => write!(f, "workspace {workspace} silent"),
+            Rule::NoInitialFocus => write!(f, "noinitialfocus"),
+            Rule::Pin => write!(f, "pin"),
+            Rule::Unset => write!(f, "unset"),
+            Rule::NoMaxSize => write!(f, "nomaxsize"),
+            Rule::StayFocused => write!(f, "stayfocused"),
+            Rule::Group(options) => write!(f, "group {options}"),
+            Rule::SuppressEvent(events) => {
+                write!(f, "suppressevent")?;
+                events.iter().try_for_each(|event| write!(f, " {event}"))
+            }
+            Rule::Content(content) => write!(f, "content {content}"),
+            Rule::NoCloseFor(ms) => write!(f, "noclosefor {ms}"),
+            Rule::Opacity(active, inactive, fullscreen) => {
+                write!(f, "opacity {active}")?;
+                // the values are positional, so the fullscreen one needs an inactive one before it
+                let inactive = inactive.or(fullscreen.map(|_| *active));
+                [inactive, *fullscreen]
+                    .into_iter()
+                    .flatten()
+                    .try_for_each(|opacity| write!(f, " {opacity}"))
+            }
+            Rule::Animation(style) => write!(f, "animation {style}"),
+            Rule::BorderColor(active, None) => write!(f, "bordercolor {active}"),
+            Rule::BorderColor(active, Some(inactive)) => {
+                write!(f, "bordercolor {active} {inactive}")
+            }
+            Rule::IdleInhibit(mode) => write!(f, "idleinhibit {mode}"),
+            Rule::DimAround => write!(f, "dimaround"),
+            Rule::Opaque => write!(f, "opaque"),
+            Rule::ForceRgbx => write!(f, "forcergbx"),
+            Rule::SyncFullscreen => write!(f, "syncfullscreen"),
+            Rule::Immediate => write!(f, "immediate"),
+            Rule::Xray(xray) => write!(f, "xray {}", *xray as u8),
+            Rule::RenderUnfocused => write!(f, "renderunfocused"),
+            Rule::NoBlur => write!(f, "noblur"),
+            Rule::NoBorder => write!(f, "noborder"),
+            Rule::NoDim => write!(f, "nodim"),
+            Rule::NoFocus => write!(f, "nofocus"),
+            Rule::NoFollowMouse => write!(f, "nofollowmouse"),
+            Rule::NoShadow => write!(f, "noshadow"),
+            Rule::NoAnim => write!(f, "noanim"),
+            Rule::NoRounding => write!(f, "norounding"),
+            Rule::NoScreenShare => write!(f, "noscreenshare"),
+            Rule::NoVrr => write!(f, "novrr"),
+            Rule::Rounding(rounding) => write!(f, "rounding {rounding}"),
+            Rule::RoundingPower(power) => write!(f, "roundingpower {power}"),
+            Rule::BorderSize(size) => write!(f, "bordersize {size}"),
+            Rule::KeepAspectRatio => write!(f, "keepaspectratio"),
+            Rule::Tag(tag) => write!(f, "tag {tag}"),
+            Rule::MaxSize(w, h) => write!(f, "maxsize {w} {h}"),
+            Rule::MinSize(w, h) => write!(f, "minsize {w} {h}"),
+            Rule::AllowsInput => write!(f, "allowsinput"),
+            Rule::FocusOnActivate => write!(f, "focusonactivate"),
+            Rule::ScrollMouse(factor) => write!(f, "scrollmouse {factor}"),
+            Rule::ScrollTouchpad(factor) => write!(f, "scrolltouchpad {factor}"),
+        }
+    }
+}
+
+impl Rule {
+    /// This method checks that the rule can be sent to Hyprland
+    pub fn validate(&self) -> crate::Result<()> {
+        match self {
+            Rule::FullscreenState(internal, client) if *internal > 3 || *client > 3 => {
+                Err(invalid(format!(
+                    "fullscreen state {internal} {client} is not in 0..=3"
+                )))
+            }
+            Rule::Move(x, y) | Rule::Size(x, y) => x.validate().and_then(|_| y.validate()),
+            Rule::Monitor(text) => validate_text("monitor", text),
+            Rule::Group(text) => validate_text("group options", text),
+            Rule::Animation(text) => validate_text("animation", text),
+            Rule::Tag(text) => validate_text("tag", text),
+            Rule::Workspace(OwnedWorkspaceIdentifierWithSpecial::Name(text), _) => {
+                validate_text("workspace", text)
+            }
+            Rule::SuppressEvent(events) if events.is_empty() => {
+                Err(invalid("suppressevent needs at least one event"))
+            }
+            Rule::Opacity(active, inactive, fullscreen) => {
+                let opacities = [Some(active), inactive.as_ref(), fullscreen.as_ref()];
+                match opacities
+                    .into_iter()
+                    .flatten()
+                    .find(|o| !o.is_finite() || **o < 0.0)
+                {
+                    Some(opacity) => Err(invalid(format!("{opacity} is not an opacity"))),
+                    None => Ok(()),
+                }
+            }
+            Rule::BorderColor(active, _) if active.colors.is_empty() => {
+                Err(invalid("bordercolor needs at least one color"))
+            }
+            Rule::RoundingPower(n) | Rule::ScrollMouse(n) | Rule::ScrollTouchpad(n)
+                if !n.is_finite() || *n < 0.0 =>
+            {
+                Err(invalid(format!("{n} can not be negative")))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// This enum holds a workspace a [Matcher::Workspace] matches
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum WorkspaceMatch {
+    /// The workspace with the id
+    #[display("{_0}")]
+    Id(WorkspaceId),
+    /// The workspace with the name
+    #[display("name:{_0}")]
+    Name(String),
+}
+
+/// This enum holds a property the windows a rule applies to must have
+///
+/// The text of class, title and tag matchers are regular expressions
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum Matcher {
+    /// Matches the class
+    #[display("class:{_0}")]
+    Class(String),
+    /// Matches the title
+    #[display("title:{_0}")]
+    Title(String),
+    /// Matches the class the window had when it opened
+    #[display("initialClass:{_0}")]
+    InitialClass(String),
+    /// Matches the title the window had when it opened
+    #[display("initialTitle:{_0}")]
+    InitialTitle(String),
+    /// Matches a tag of the window
+    #[display("tag:{_0}")]
+    Tag(String),
+    /// Matches the xdg tag of the window
+    #[display("xdgTag:{_0}")]
+    XdgTag(String),
+    /// Matches XWayland windows
+    #[display("xwayland:{}", *_0 as u8)]
+    Xwayland(bool),
+    /// Matches floating windows
+    #[display("floating:{}", *_0 as u8)]
+    Floating(bool),
+    /// Matches fullscreen windows
+    #[display("fullscreen:{}", *_0 as u8)]
+    Fullscreen(bool),
+    /// Matches pinned windows
+    #[display("pinned:{}", *_0 as u8)]
+    Pinned(bool),
+    /// Matches the focused window
+    #[display("focus:{}", *_0 as u8)]
+    Focus(bool),
+    /// Matches grouped windows
+    #[display("group:{}", *_0 as u8)]
+    Group(bool),
+    /// Matches modal windows
+    #[display("modal:{}", *_0 as u8)]
+    Modal(bool),
+    /// Matches the internal and client fullscreen state, from 0 to 3, `None` matching any state
+    #[display("fullscreenstate:{} {}", state(_0), state(_1))]
+    FullscreenState(Option<u8>, Option<u8>),
+    /// Matches windows on the workspace
+    #[display("workspace:{_0}")]
+    Workspace(WorkspaceMatch),
+    /// Matches windows on workspaces matching a selector, like `w[t1]` or `s[true]`
+    #[display("onworkspace:{_0}")]
+    OnWorkspace(String),
+    /// Matches the content type
+    #[display("content:{_0}")]
+    Content(ContentType),
+}
+
+fn state(state: &Option<u8>) -> String {
+    state.map_or("*".to_string(), |s| s.to_string())
+}
+
+impl Matcher {
+    /// This method checks that the matcher can be sent to Hyprland
+    pub fn validate(&self) -> crate::Result<()> {
+        match self {
+            Matcher::Class(text)
+            | Matcher::Title(text)
+            | Matcher::InitialClass(text)
+            | Matcher::InitialTitle(text)
+            | Matcher::Tag(text)
+            | Matcher::XdgTag(text)
+            | Matcher::OnWorkspace(text)
+            | Matcher::Workspace(WorkspaceMatch::Name(text)) => {
+                if text.is_empty() || text.contains('\n') {
+                    Err(invalid(format!(
+                        "`{self}` can not be empty or contain newlines"
+                    )))
+                } else {
+                    Ok(())
+                }
+            }
+            Matcher::FullscreenState(internal, client)
+                if internal.is_some_and(|s| s > 3) || client.is_some_and(|s| s > 3) =>
+            {
+                Err(invalid(format!(
+                    "`{self}` is not a fullscreen state in 0..=3"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// This struct holds rules and the matchers of the windows they apply to
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WindowRule {
+    /// The rules, each sent as its own `windowrulev2`
+    pub rules: Vec<Rule>,
+    /// The properties windows must have for the rules to apply
+    pub matchers: Vec<Matcher>,
+}
+
+impl WindowRule {
+    /// This method creates a window rule without rules or matchers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This method adds a rule
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// This method adds a matcher
+    pub fn matching(mut self, matcher: Matcher) -> Self {
+        self.matchers.push(matcher);
+        self
+    }
+
+    /// This method checks that the window rule can be sent to Hyprland
+    pub fn validate(&self) -> crate::Result<()> {
+        if self.rules.is_empty() {
+            return Err(invalid("a window rule needs at least one rule"));
+        }
+        if self.matchers.is_empty() {
+            return Err(invalid("a window rule needs at least one matcher"));
+        }
+        self.rules.iter().try_for_each(Rule::validate)?;
+        self.matchers.iter().try_for_each(Matcher::validate)
+    }
+
+    /// This method validates the window rule and returns the value of every `windowrulev2` keyword
+    pub fn values(&self) -> crate::Result<Vec<String>> {
+        self.validate()?;
+        let matchers = self
+            .matchers
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        Ok(self
+            .rules
+            .iter()
+            .map(|rule| format!("{rule}, {matchers}"))
+            .collect())
+    }
+
+    fn batch(&self) -> crate::Result<Batch> {
+        Ok(self
+            .values()?
+            .into_iter()
+            .fold(Batch::new(), |batch, value| batch.keyword(KEYWORD, value)))
+    }
+
+    /// This method validates and adds the window rule
+    pub fn apply(&self) -> crate::Result<()> {
+        self.instance_apply(default_instance()?)
+    }
+
+    /// This method validates and adds the window rule
+    pub fn instance_apply(&self, instance: &Instance) -> crate::Result<()> {
+        check_batch(self.batch()?.instance_call(instance)?)
+    }
+
+    /// This method validates and adds the window rule (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn apply_async(&self) -> crate::Result<()> {
+        self.instance_apply_async(default_instance()?).await
+    }
+
+    /// This method validates and adds the window rule (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_apply_async(&self, instance: &Instance) -> crate::Result<()> {
+        check_batch(self.batch()?.instance_call_async(instance).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+    use crate::error::HyprError;
+    use crate::testing::MockHyprland;
+
+    #[test]
+    fn test_rules() {
+        use OwnedWorkspaceIdentifierWithSpecial as Workspace;
+        let border = Gradient {
+            colors: vec![Color::from_argb(0xffff0000), Color::from_argb(0xff880808)],
+            angle: 45.0,
+        };
+        for (rule, expected) in [
+            (Rule::Float, "float"),
+            (Rule::FullscreenState(0, 2), "fullscreenstate 0 2"),
+            (
+                Rule::Move(
+                    Dimension::Expression("100%-w-10".into()),
+                    Dimension::Pixels(10),
+                ),
+                "move 100%-w-10 10",
+            ),
+            (
+                Rule::Size(Dimension::Percent(50.0), Dimension::Percent(62.5)),
+                "size 50% 62.5%",
+            ),
+            (Rule::Center(true), "center 1"),
+            (Rule::Monitor("DP-1".into()), "monitor DP-1"),
+            (
+                Rule::Workspace(Workspace::Id(2), true),
+                "workspace 2 silent",
+            ),
+            (
+                Rule::Workspace(Workspace::Special(Some("term".into())), false),
+                "workspace special:term",
+            ),
+            (
+                Rule::Workspace(Workspace::Name("web".into()), false),
+                "workspace name:web",
+            ),
+            (
+                Rule::SuppressEvent(vec![SuppressEvent::Maximize, SuppressEvent::Fullscreen]),
+                "suppressevent maximize fullscreen",
+            ),
+            (Rule::Content(ContentType::Game), "content game"),
+            (Rule::Opacity(0.9, None, None), "opacity 0.9"),
+            (Rule::Opacity(1.0, Some(0.8), Some(1.0)), "opacity 1 0.8 1"),
+            (Rule::Opacity(0.9, None, Some(1.0)), "opacity 0.9 0.9 1"),
+            (
+                Rule::BorderColor(border, None),
+                "bordercolor rgba(ff0000ff) rgba(880808ff) 45deg",
+            ),
+            (
+                Rule::IdleInhibit(IdleInhibitMode::Fullscreen),
+                "idleinhibit fullscreen",
+            ),
+            (Rule::Xray(false), "xray 0"),
+            (Rule::NoBlur, "noblur"),
+            (Rule::Tag("+term".into()), "tag +term"),
+            (Rule::MaxSize(1280, 720), "maxsize 1280 720"),
+            (Rule::Group("set always".into()), "group set always"),
+        ] {
+            assert_eq!(rule.to_string(), expected);
+            assert!(rule.validate().is_ok(), "{expected}");
+        }
+    }
+
+    #[test]
+    fn test_matchers() {
+        for (matcher, expected) in [
+            (Matcher::Class("^(kitty)$".into()), "class:^(kitty)$"),
+            (
+                Matcher::InitialTitle("^(Firefox)$".into()),
+                "initialTitle:^(Firefox)$",
+            ),
+            (Matcher::Xwayland(true), "xwayland:1"),
+            (Matcher::Floating(false), "floating:0"),
+            (
+                Matcher::FullscreenState(None, Some(2)),
+                "fullscreenstate:* 2",
+            ),
+            (Matcher::Workspace(WorkspaceMatch::Id(3)), "workspace:3"),
+            (
+                Matcher::Workspace(WorkspaceMatch::Name("web".into())),
+                "workspace:name:web",
+            ),
+            (Matcher::OnWorkspace("w[t1]".into()), "onworkspace:w[t1]"),
+            (Matcher::Content(ContentType::Video), "content:video"),
+        ] {
+            assert_eq!(matcher.to_string(), expected);
+            assert!(matcher.validate().is_ok(), "{expected}");
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let valid = WindowRule::new()
+            .rule(Rule::Float)
+            .matching(Matcher::Class("kitty".into()));
+        for invalid in [
+            WindowRule::new().matching(Matcher::Class("kitty".into())),
+            WindowRule::new().rule(Rule::Float),
+            valid.clone().rule(Rule::FullscreenState(4, 0)),
+            valid.clone().rule(Rule::Size(
+                Dimension::Expression("50% 50%".into()),
+                10.into(),
+            )),
+            valid.clone().rule(Rule::Opacity(0.5, Some(-1.0), None)),
+            valid.clone().rule(Rule::SuppressEvent(vec![])),
+            valid.clone().rule(Rule::Monitor("DP-1, DP-2".into())),
+            valid.clone().matching(Matcher::Title(String::new())),
+            valid
+                .clone()
+                .matching(Matcher::FullscreenState(Some(5), None)),
+        ] {
+            assert!(
+                matches!(invalid.validate(), Err(HyprError::InvalidRule(_))),
+                "{invalid:?}"
+            );
+        }
+        let fullscreen_only = valid.rule(Rule::Opacity(0.9, None, Some(1.0)));
+        assert!(fullscreen_only.validate().is_ok());
+    }
+
+    #[test]
+    fn test_apply() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        WindowRule::new()
+            .rule(Rule::Float)
+            .rule(Rule::Size(800.into(), 600.into()))
+            .matching(Matcher::Class("^(pavucontrol)$".into()))
+            .matching(Matcher::Title("^(Volume Control)$".into()))
+            .instance_apply(mock.instance())?;
+        assert_eq!(
+            mock.received(),
+            [
+                "keyword windowrulev2 float, class:^(pavucontrol)$, title:^(Volume Control)$",
+                "keyword windowrulev2 size 800 600, class:^(pavucontrol)$, title:^(Volume Control)$",
+            ]
+        );
+
+        mock.respond_with("keyword", "invalid rule");
+        let result = WindowRule::new()
+            .rule(Rule::Pin)
+            .matching(Matcher::Floating(true))
+            .instance_apply(mock.instance());
+        assert!(matches!(result, Err(HyprError::Internal(e)) if e == "invalid rule"));
+        Ok(())
+    }
+}
@@ -0,0 +1,481 @@
+//! # Workspace rules module
+//!
+//! This module is used for building `workspace` rules and applying them with
+//! [Keyword](crate::keyword::Keyword), the write side of
+//! [WorkspaceRules](crate::data::WorkspaceRules)
+//!
+//! ## Usage
+//!
+//! ```rust, no_run
+//! use hyprland::config::workspace_rules::{Gaps, WorkspaceRule};
+//! use hyprland::data::WorkspaceRules;
+//! use hyprland::shared::HyprData;
+//! fn main() -> hyprland::Result<()> {
+//!     WorkspaceRule::id(1)
+//!         .monitor("DP-1")
+//!         .default(true)
+//!         .persistent(true)
+//!         .gaps_out(Gaps::all(10))
+//!         .apply()?;
+//!
+//!     // remove the gaps of every workspace with rules
+//!     for ruleset in WorkspaceRules::get()? {
+//!         WorkspaceRule::from(&ruleset)
+//!             .gaps_in(Gaps::all(0))
+//!             .gaps_out(Gaps::all(0))
+//!             .apply()?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use super::{invalid, validate_text};
+use crate::batch::{check_batch, Batch};
+use crate::default_instance;
+use crate::instance::Instance;
+use crate::shared::WorkspaceId;
+use std::fmt;
+
+#[cfg(feature = "data")]
+use crate::data::WorkspaceRuleset;
+
+/// The keyword workspace rules are set with
+const KEYWORD: &str = "workspace";
+
+/// This struct holds gaps for each side, in the order CSS uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Gaps {
+    /// The gap at the top
+    pub top: i64,
+    /// The gap on the right
+    pub right: i64,
+    /// The gap at the bottom
+    pub bottom: i64,
+    /// The gap on the left
+    pub left: i64,
+}
+
+impl Gaps {
+    /// Creates gaps that are the same on every side
+    pub fn all(gap: i64) -> Self {
+        Self {
+            top: gap,
+            right: gap,
+            bottom: gap,
+            left: gap,
+        }
+    }
+
+    /// Creates gaps from 1 to 4 values, which are interpreted like CSS margins
+    pub fn from_css(values: &[i64]) -> Option<Self> {
+        let (top, right, bottom, left) = match *values {
+            [all] => (all, all, all, all),
+            [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+            [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+            [top, right, bottom, left] => (top, right, bottom, left),
+            _ => return None,
+        };
+        Some(Self {
+            top,
+            right,
+            bottom,
+            left,
+        })
+    }
+}
+
+impl fmt::Display for Gaps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Self::all(self.top) {
+            write!(f, "{}", self.top)
+        } else {
+            write!(
+                f,
+                "{} {} {} {}",
+                self.top, self.right, self.bottom, self.left
+            )
+        }
+    }
+}
+
+/// This struct holds the rules of a workspace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceRule {
+    /// The workspaces the rules apply to, like `1`, `name:web`, `special:term` or a selector like `r[1-5]`
+    pub workspace: String,
+    /// The monitor the workspace is bound to
+    pub monitor: Option<String>,
+    /// Makes the workspace the default of its monitor
+    pub default: Option<bool>,
+    /// Keeps the workspace alive while it is empty
+    pub persistent: Option<bool>,
+    /// The gaps between windows
+    pub gaps_in: Option<Gaps>,
+    /// The gaps between windows and monitor edges
+    pub gaps_out: Option<Gaps>,
+    /// The size of window borders
+    pub border_size: Option<i64>,
+    /// Whether windows have borders
+    pub border: Option<bool>,
+    /// Whether windows have shadows
+    pub shadow: Option<bool>,
+    /// Whether windows are rounded
+    pub rounding: Option<bool>,
+    /// Whether windows have decorations
+    pub decorate: Option<bool>,
+    /// The command run when the workspace is created empty
+    pub on_created_empty: Option<String>,
+    /// The name the workspace gets when it is created
+    pub default_name: Option<String>,
+    /// Options passed to the layout, like `orientation` for master
+    pub layout_options: Vec<(String, String)>,
+}
+
+impl WorkspaceRule {
+    /// This method creates a workspace rule without rules, for a workspace string like `name:web` or `r[1-5]`
+    pub fn new<Str: ToString>(workspace: Str) -> Self {
+        Self {
+            workspace: workspace.to_string(),
+            monitor: None,
+            default: None,
+            persistent: None,
+            gaps_in: None,
+            gaps_out: None,
+            border_size: None,
+            border: None,
+            shadow: None,
+            rounding: None,
+            decorate: None,
+            on_created_empty: None,
+            default_name: None,
+            layout_options: vec![],
+        }
+    }
+
+    /// This method creates a workspace rule without rules, for the workspace with the id
+    pub fn id(id: WorkspaceId) -> Self {
+        Self::new(id)
+    }
+
+    /// This method binds the workspace to a monitor
+    pub fn monitor<Str: ToString>(mut self, monitor: Str) -> Self {
+        self.monitor = Some(monitor.to_string());
+        self
+    }
+
+    /// This method sets whether the workspace is the default of its monitor
+    pub fn default(mut self, default: bool) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// This method sets whether the workspace is kept alive while it is empty
+    pub fn persistent(mut self, persistent: bool) -> Self {
+        self.persistent = Some(persistent);
+        self
+    }
+
+    /// This method sets the gaps between windows
+    pub fn gaps_in(mut self, gaps: Gaps) -> Self {
+        self.gaps_in = Some(gaps);
+        self
+    }
+
+    /// This method sets the gaps between windows and monitor edges
+    pub fn gaps_out(mut self, gaps: Gaps) -> Self {
+        self.gaps_out = Some(gaps);
+        self
+    }
+
+    /// This method sets the size of window borders
+    pub fn border_size(mut self, size: i64) -> Self {
+        self.border_size = Some(size);
+        self
+    }
+
+    /// This method sets whether windows have borders
+    pub fn border(mut self, border: bool) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    /// This method sets whether windows have shadows
+    pub fn shadow(mut self, shadow: bool) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// This method sets whether windows are rounded
+    pub fn rounding(mut self, rounding: bool) -> Self {
+        self.rounding = Some(rounding);
+        self
+    }
+
+    /// This method sets whether windows have decorations
+    pub fn decorate(mut self, decorate: bool) -> Self {
+        self.decorate = Some(decorate);
+        self
+    }
+
+    /// This method sets the command run when the workspace is created empty
+    pub fn on_created_empty<Str: ToString>(mut self, command: Str) -> Self {
+        self.on_created_empty = Some(command.to_string());
+        self
+    }
+
+    /// This method sets the name the workspace gets when it is created
+    pub fn default_name<Str: ToString>(mut self, name: Str) -> Self {
+        self.default_name = Some(name.to_string());
+        self
+    }
+
+    /// This method adds an option passed to the layout
+    pub fn layout_option<K: ToString, V: ToString>(mut self, key: K, value: V) -> Self {
+        self.layout_options
+            .push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// This method checks that the workspace rule can be sent to Hyprland
+    pub fn validate(&self) -> crate::Result<()> {
+        validate_text("workspace", &self.workspace)?;
+        if let Some(monitor) = &self.monitor {
+            validate_text("monitor", monitor)?;
+        }
+        if let Some(command) = &self.on_created_empty {
+            validate_text("on-created-empty", command)?;
+        }
+        if let Some(name) = &self.default_name {
+            validate_text("defaultName", name)?;
+        }
+        for (key, value) in &self.layout_options {
+            validate_text("layout option", key)?;
+            validate_text("layout option", value)?;
+            if key.contains([':', ' ']) {
+                return Err(invalid(format!(
+                    "layout option `{key}` can not contain colons or spaces"
+                )));
+            }
+        }
+        for gaps in self.gaps_in.iter().chain(&self.gaps_out) {
+            if [gaps.top, gaps.right, gaps.bottom, gaps.left]
+                .iter()
+                .any(|gap| *gap < 0)
+            {
+                return Err(invalid(format!("gaps `{gaps}` can not be negative")));
+            }
+        }
+        if self.border_size.is_some_and(|size| size < 0) {
+            return Err(invalid("the border size can not be negative"));
+        }
+        if self.to_string() == self.workspace {
+            return Err(invalid(format!(
+                "the workspace rule for `{}` has no rules",
+                self.workspace
+            )));
+        }
+        Ok(())
+    }
+
+    fn batch(&self) -> crate::Result<Batch> {
+        self.validate()?;
+        Ok(Batch::new().keyword(KEYWORD, self.to_string()))
+    }
+
+    /// This method validates and adds the workspace rule
+    pub fn apply(&self) -> crate::Result<()> {
+        self.instance_apply(default_instance()?)
+    }
+
+    /// This method validates and adds the workspace rule
+    pub fn instance_apply(&self, instance: &Instance) -> crate::Result<()> {
+        check_batch(self.batch()?.instance_call(instance)?)
+    }
+
+    /// This method validates and adds the workspace rule (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn apply_async(&self) -> crate::Result<()> {
+        self.instance_apply_async(default_instance()?).await
+    }
+
+    /// This method validates and adds the workspace rule (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_apply_async(&self, instance: &Instance) -> crate::Result<()> {
+        check_batch(self.batch()?.instance_call_async(instance).await?)
+    }
+}
+
+/// Formats the rule as the value of a `workspace` keyword
+impl fmt::Display for WorkspaceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.workspace)?;
+        if let Some(monitor) = &self.monitor {
+            write!(f, ", monitor:{monitor}")?;
+        }
+        for (name, value) in [("default", self.default), ("persistent", self.persistent)] {
+            if let Some(value) = value {
+                write!(f, ", {name}:{value}")?;
+            }
+        }
+        if let Some(gaps) = &self.gaps_in {
+            write!(f, ", gapsin:{gaps}")?;
+        }
+        if let Some(gaps) = &self.gaps_out {
+            write!(f, ", gapsout:{gaps}")?;
+        }
+        if let Some(size) = &self.border_size {
+            write!(f, ", bordersize:{size}")?;
+        }
+        for (name, value) in [
+            ("border", self.border),
+            ("shadow", self.shadow),
+            ("rounding", self.rounding),
+            ("decorate", self.decorate),
+        ] {
+            if let Some(value) = value {
+                write!(f, ", {name}:{value}")?;
+            }
+        }
+        if let Some(command) = &self.on_created_empty {
+            write!(f, ", on-created-empty:{command}")?;
+        }
+        if let Some(name) = &self.default_name {
+            write!(f, ", defaultName:{name}")?;
+        }
+        for (key, value) in &self.layout_options {
+            write!(f, ", layoutopt:{key}:{value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "data")]
+impl From<&WorkspaceRuleset> for WorkspaceRule {
+    fn from(ruleset: &WorkspaceRuleset) -> Self {
+        Self {
+            monitor: ruleset.monitor.clone(),
+            default: ruleset.default,
+            persistent: ruleset.persistent,
+            gaps_in: ruleset.gaps_in.as_deref().and_then(Gaps::from_css),
+            gaps_out: ruleset.gaps_out.as_deref().and_then(Gaps::from_css),
+            border_size: ruleset.border_size,
+            border: ruleset.border,
+            shadow: ruleset.shadow,
+            rounding: ruleset.rounding,
+            decorate: ruleset.decorate,
+            default_name: ruleset.default_name.clone(),
+            ..Self::new(&ruleset.workspace_string)
+        }
+    }
+}
+
+#[cfg(feature = "data")]
+impl From<WorkspaceRuleset> for WorkspaceRule {
+    fn from(ruleset: WorkspaceRuleset) -> Self {
+        Self::from(&ruleset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::HyprError;
+
+    #[test]
+    fn test_format() -> crate::Result<()> {
+        let rule = WorkspaceRule::new("name:web")
+            .monitor("DP-1")
+            .default(true)
+            .persistent(false)
+            .gaps_in(Gaps::all(5))
+            .gaps_out(Gaps {
+                top: 10,
+                right: 20,
+                bottom: 10,
+                left: 20,
+            })
+            .border_size(2)
+            .border(false)
+            .shadow(false)
+            .rounding(true)
+            .decorate(false)
+            .on_created_empty("[float] firefox")
+            .default_name("web")
+            .layout_option("orientation", "left");
+        rule.validate()?;
+        assert_eq!(
+            rule.to_string(),
+            "name:web, monitor:DP-1, default:true, persistent:false, gapsin:5, \
+             gapsout:10 20 10 20, bordersize:2, border:false, shadow:false, rounding:true, \
+             decorate:false, on-created-empty:[float] firefox, defaultName:web, \
+             layoutopt:orientation:left"
+        );
+
+        for invalid in [
+            WorkspaceRule::id(1),
+            WorkspaceRule::new("").persistent(true),
+            WorkspaceRule::id(1).monitor("DP-1, DP-2"),
+            WorkspaceRule::id(1).gaps_in(Gaps::all(-1)),
+            WorkspaceRule::id(1).border_size(-2),
+            WorkspaceRule::id(1).layout_option("a:b", "c"),
+        ] {
+            assert!(
+                matches!(invalid.validate(), Err(HyprError::InvalidRule(_))),
+                "{invalid:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_gaps() {
+        assert_eq!(Gaps::from_css(&[5]), Some(Gaps::all(5)));
+        assert_eq!(
+            Gaps::from_css(&[1, 2]).map(|g| g.to_string()),
+            Some("1 2 1 2".to_string())
+        );
+        assert_eq!(
+            Gaps::from_css(&[1, 2, 3]).map(|g| g.to_string()),
+            Some("1 2 3 2".to_string())
+        );
+        assert_eq!(Gaps::from_css(&[]), None);
+    }
+
+    #[test]
+    #[cfg(feature = "data")]
+    fn test_from_ruleset() -> crate::Result<()> {
+        use crate::data::{DataCommands, WorkspaceRules};
+        use crate::shared::HyprData;
+        use crate::testing::MockHyprland;
+
+        let mock = MockHyprland::new()?;
+        mock.set_data(
+            DataCommands::WorkspaceRules,
+            r#"[{"workspaceString": "1", "monitor": "DP-1", "default": true,
+                 "gapsIn": [5, 5, 5, 5], "gapsOut": [20, 10, 20, 10], "border": false},
+                {"workspaceString": "special:term", "defaultName": "term"}]"#,
+        );
+        let rules: Vec<WorkspaceRule> = WorkspaceRules::instance_get(mock.instance())?
+            .into_iter()
+            .map(WorkspaceRule::from)
+            .collect();
+        assert_eq!(
+            rules[0].to_string(),
+            "1, monitor:DP-1, default:true, gapsin:5, gapsout:20 10 20 10, border:false"
+        );
+        assert_eq!(rules[1].to_string(), "special:term, defaultName:term");
+        mock.clear_received();
+
+        rules[0]
+            .clone()
+            .persistent(true)
+            .gaps_out(Gaps::all(0))
+            .instance_apply(mock.instance())?;
+        assert_eq!(
+            mock.received(),
+            ["keyword workspace 1, monitor:DP-1, default:true, persistent:true, gapsin:5, gapsout:0, border:false"]
+        );
+        Ok(())
+    }
+}
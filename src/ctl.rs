@@ -1,4 +1,4 @@
-use derive_more::{Constructor, Display as MDisplay};
+use derive_more::Display as MDisplay;
 use std::fmt::Display as FDisplay;
 
 use crate::default_instance;
@@ -420,10 +420,7 @@ pub mod dismissnotify {
     }
 }
 
-/// A 8-bit color with a alpha channel
-#[derive(Debug, Copy, Clone, MDisplay, Constructor, PartialEq, Eq)]
-#[display("rgba({_0:02x}{_1:02x}{_2:02x}{_3:02x})")]
-pub struct Color(u8, u8, u8, u8);
+pub use crate::color::{Color, Gradient};
 
 /// Provides things to setting props
 pub mod set_prop {
@@ -548,17 +545,17 @@ pub mod set_prop {
             /// locked
             bool,
         ),
-        /// The active border color
-        #[display("alphabordercolor {_0} {}", l(*_1))]
+        /// The active border color, a [Color] can be used with `.into()`
+        #[display("activebordercolor {_0} {}", l(*_1))]
         ActiveBorderColor(
-            Color,
+            Gradient,
             /// locked
             bool,
         ),
-        /// The inactive border color
-        #[display("inalphabordercolor {_0} {}", l(*_1))]
+        /// The inactive border color, a [Color] can be used with `.into()`
+        #[display("inactivebordercolor {_0} {}", l(*_1))]
         InactiveBorderColor(
-            Color,
+            Gradient,
             /// locked
             bool,
         ),
@@ -796,3 +793,34 @@ pub mod instance {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockHyprland;
+
+    #[test]
+    fn test_colors() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let border = Gradient::new([Color::rgb(0xff, 0, 0), "#00ff0080".parse()?], 90.0);
+        set_prop::instance_call(
+            mock.instance(),
+            "address:0x55d1c8a0e6f0".into(),
+            set_prop::PropType::ActiveBorderColor(border, false),
+            false,
+        )?;
+        set_error::instance_call(
+            mock.instance(),
+            Color::new(0xff, 0x44, 0x44, 0xcc),
+            "oops".into(),
+        )?;
+        assert_eq!(
+            mock.received(),
+            [
+                "setprop address:0x55d1c8a0e6f0 activebordercolor rgba(ff0000ff) rgba(00ff0080) 90deg",
+                "seterror rgba(ff4444cc) oops",
+            ]
+        );
+        Ok(())
+    }
+}
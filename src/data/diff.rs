@@ -0,0 +1,492 @@
+use super::*;
+
+#[cfg(feature = "listener")]
+use crate::event_listener::*;
+
+/// This enum holds a single difference between two [Snapshot]s, see [Snapshot::diff]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotChange {
+    /// A client was opened
+    ClientAdded(Client),
+    /// A client was closed
+    ClientRemoved(Client),
+    /// A client was moved to another workspace
+    ClientMoved {
+        /// The client's address
+        address: Address,
+        /// The workspace it was on
+        from: WorkspaceBasic,
+        /// The workspace it is on now
+        to: WorkspaceBasic,
+    },
+    /// The title of a client was changed
+    ClientTitleChanged {
+        /// The client's address
+        address: Address,
+        /// The old title
+        old: String,
+        /// The new title
+        new: String,
+    },
+    /// The class of a client was changed
+    ClientClassChanged {
+        /// The client's address
+        address: Address,
+        /// The old class
+        old: String,
+        /// The new class
+        new: String,
+    },
+    /// A client was made floating or tiled
+    ClientFloatingChanged {
+        /// The client's address
+        address: Address,
+        /// Is the client floating now?
+        floating: bool,
+    },
+    /// A client was pinned or unpinned
+    ClientPinnedChanged {
+        /// The client's address
+        address: Address,
+        /// Is the client pinned now?
+        pinned: bool,
+    },
+    /// The fullscreen mode of a client was changed
+    ClientFullscreenChanged {
+        /// The client's address
+        address: Address,
+        /// The old fullscreen mode
+        old: FullscreenMode,
+        /// The new fullscreen mode
+        new: FullscreenMode,
+    },
+    /// Another client was focused, or none
+    ActiveClientChanged(Option<Client>),
+    /// A monitor was connected
+    MonitorAdded(Monitor),
+    /// A monitor was disconnected
+    MonitorRemoved(Monitor),
+    /// The mode, position, scale, transform or state of a monitor was changed
+    MonitorReconfigured {
+        /// The monitor before
+        old: Box<Monitor>,
+        /// The monitor now
+        new: Box<Monitor>,
+    },
+    /// Another monitor was focused
+    MonitorFocused(Monitor),
+    /// Another workspace is shown on a monitor
+    ActiveWorkspaceChanged {
+        /// The monitor's name
+        monitor: String,
+        /// The workspace shown now
+        workspace: WorkspaceBasic,
+    },
+    /// A workspace was created
+    WorkspaceCreated(Workspace),
+    /// A workspace was deleted
+    WorkspaceDeleted(Workspace),
+    /// A workspace was renamed
+    WorkspaceRenamed {
+        /// The workspace's id
+        id: WorkspaceId,
+        /// The old name
+        old: String,
+        /// The new name
+        new: String,
+    },
+    /// A workspace was moved to another monitor
+    WorkspaceMoved {
+        /// The workspace's id
+        id: WorkspaceId,
+        /// The workspace's name
+        name: String,
+        /// The monitor it was on
+        from: String,
+        /// The monitor it is on now
+        to: String,
+    },
+}
+
+/// Returns true if anything but the focus and the shown workspaces differ
+fn reconfigured(old: &Monitor, new: &Monitor) -> bool {
+    let mut old = old.clone();
+    old.focused = new.focused;
+    old.active_workspace.clone_from(&new.active_workspace);
+    old.special_workspace.clone_from(&new.special_workspace);
+    // these change with the windows shown, not with the configuration
+    old.solitary.clone_from(&new.solitary);
+    old.actively_tearing = new.actively_tearing;
+    old.direct_scanout_to.clone_from(&new.direct_scanout_to);
+    old != *new
+}
+
+fn diff_client(old: &Client, new: &Client, changes: &mut Vec<SnapshotChange>) {
+    let address = &new.address;
+    if old.workspace.id != new.workspace.id {
+        changes.push(SnapshotChange::ClientMoved {
+            address: address.clone(),
+            from: old.workspace.clone(),
+            to: new.workspace.clone(),
+        });
+    }
+    if old.title != new.title {
+        changes.push(SnapshotChange::ClientTitleChanged {
+            address: address.clone(),
+            old: old.title.clone(),
+            new: new.title.clone(),
+        });
+    }
+    if old.class != new.class {
+        changes.push(SnapshotChange::ClientClassChanged {
+            address: address.clone(),
+            old: old.class.clone(),
+            new: new.class.clone(),
+        });
+    }
+    if old.floating != new.floating {
+        changes.push(SnapshotChange::ClientFloatingChanged {
+            address: address.clone(),
+            floating: new.floating,
+        });
+    }
+    if old.pinned != new.pinned {
+        changes.push(SnapshotChange::ClientPinnedChanged {
+            address: address.clone(),
+            pinned: new.pinned,
+        });
+    }
+    if old.fullscreen != new.fullscreen {
+        changes.push(SnapshotChange::ClientFullscreenChanged {
+            address: address.clone(),
+            old: old.fullscreen,
+            new: new.fullscreen,
+        });
+    }
+}
+
+impl Snapshot {
+    /// This method compares the snapshot to a newer one, and returns every difference
+    ///
+    /// Additions come first, followed by changes and then removals,
+    /// so the changes can be applied in order
+    pub fn diff(&self, newer: &Snapshot) -> Vec<SnapshotChange> {
+        let mut changes = vec![];
+
+        for monitor in newer.monitors() {
+            match self.monitor_by_name(&monitor.name) {
+                None => changes.push(SnapshotChange::MonitorAdded(monitor.clone())),
+                Some(old) if reconfigured(old, monitor) => {
+                    changes.push(SnapshotChange::MonitorReconfigured {
+                        old: Box::new(old.clone()),
+                        new: Box::new(monitor.clone()),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        for workspace in newer.workspaces() {
+            let Some(old) = self.workspace(workspace.id) else {
+                changes.push(SnapshotChange::WorkspaceCreated(workspace.clone()));
+                continue;
+            };
+            if old.name != workspace.name {
+                changes.push(SnapshotChange::WorkspaceRenamed {
+                    id: workspace.id,
+                    old: old.name.clone(),
+                    new: workspace.name.clone(),
+                });
+            }
+            if old.monitor != workspace.monitor {
+                changes.push(SnapshotChange::WorkspaceMoved {
+                    id: workspace.id,
+                    name: workspace.name.clone(),
+                    from: old.monitor.clone(),
+                    to: workspace.monitor.clone(),
+                });
+            }
+        }
+        for client in newer.clients() {
+            match self.client(&client.address) {
+                Some(old) => diff_client(old, client, &mut changes),
+                None => changes.push(SnapshotChange::ClientAdded(client.clone())),
+            }
+        }
+
+        for monitor in newer.monitors() {
+            let old = self.monitor_by_name(&monitor.name);
+            if monitor.focused && !old.is_some_and(|old| old.focused) {
+                changes.push(SnapshotChange::MonitorFocused(monitor.clone()));
+            }
+            if old.is_some_and(|old| old.active_workspace != monitor.active_workspace) {
+                changes.push(SnapshotChange::ActiveWorkspaceChanged {
+                    monitor: monitor.name.clone(),
+                    workspace: monitor.active_workspace.clone(),
+                });
+            }
+        }
+        let active = newer.active_client();
+        if self.active_client().map(|c| &c.address) != active.map(|c| &c.address) {
+            changes.push(SnapshotChange::ActiveClientChanged(active.cloned()));
+        }
+
+        for client in self.clients() {
+            if newer.client(&client.address).is_none() {
+                changes.push(SnapshotChange::ClientRemoved(client.clone()));
+            }
+        }
+        for workspace in self.workspaces() {
+            if newer.workspace(workspace.id).is_none() {
+                changes.push(SnapshotChange::WorkspaceDeleted(workspace.clone()));
+            }
+        }
+        for monitor in self.monitors() {
+            if newer.monitor_by_name(&monitor.name).is_none() {
+                changes.push(SnapshotChange::MonitorRemoved(monitor.clone()));
+            }
+        }
+        changes
+    }
+}
+
+#[cfg(feature = "listener")]
+impl SnapshotChange {
+    /// This method converts the change into the event the event listener emits for it,
+    /// changes without an event, like a class change, return [None]
+    pub fn to_event(&self) -> Option<Event> {
+        Some(match self {
+            Self::ClientAdded(client) => Event::WindowOpened(WindowOpenEvent {
+                window_address: client.address.clone(),
+                workspace_name: client.workspace.name.clone(),
+                window_class: client.class.clone(),
+                window_title: client.title.clone(),
+            }),
+            Self::ClientRemoved(client) => Event::WindowClosed(client.address.clone()),
+            Self::ClientMoved { address, to, .. } => Event::WindowMoved(WindowMoveEvent {
+                window_address: address.clone(),
+                workspace_id: to.id,
+                workspace_name: parse_string_as_work(to.name.clone()),
+            }),
+            Self::ClientTitleChanged { address, new, .. } => {
+                Event::WindowTitleChanged(WindowTitleEventData {
+                    address: address.clone(),
+                    title: new.clone(),
+                })
+            }
+            Self::ClientClassChanged { .. } => return None,
+            Self::ClientFloatingChanged { address, floating } => {
+                Event::FloatStateChanged(WindowFloatEventData {
+                    address: address.clone(),
+                    floating: *floating,
+                })
+            }
+            Self::ClientPinnedChanged { address, pinned } => {
+                Event::WindowPinned(WindowPinEventData {
+                    address: address.clone(),
+                    pinned: *pinned,
+                })
+            }
+            Self::ClientFullscreenChanged { new, .. } => {
+                Event::FullscreenStateChanged(*new != FullscreenMode::None)
+            }
+            Self::ActiveClientChanged(client) => {
+                Event::ActiveWindowChanged(client.as_ref().map(|client| WindowEventData {
+                    class: client.class.clone(),
+                    title: client.title.clone(),
+                    address: client.address.clone(),
+                }))
+            }
+            Self::MonitorAdded(monitor) => Event::MonitorAdded(MonitorAddedEventData {
+                id: monitor.id,
+                name: monitor.name.clone(),
+                description: monitor.description.clone(),
+            }),
+            Self::MonitorRemoved(monitor) => Event::MonitorRemoved(monitor.name.clone()),
+            Self::MonitorReconfigured { .. } => return None,
+            Self::MonitorFocused(monitor) => Event::ActiveMonitorChanged(MonitorEventData {
+                monitor_name: monitor.name.clone(),
+                workspace_name: Some(parse_string_as_work(monitor.active_workspace.name.clone())),
+            }),
+            Self::ActiveWorkspaceChanged { workspace, .. } => {
+                Event::WorkspaceChanged(WorkspaceEventData {
+                    name: parse_string_as_work(workspace.name.clone()),
+                    id: workspace.id,
+                })
+            }
+            Self::WorkspaceCreated(workspace) => Event::WorkspaceAdded(WorkspaceEventData {
+                name: parse_string_as_work(workspace.name.clone()),
+                id: workspace.id,
+            }),
+            Self::WorkspaceDeleted(workspace) => Event::WorkspaceDeleted(WorkspaceEventData {
+                name: parse_string_as_work(workspace.name.clone()),
+                id: workspace.id,
+            }),
+            Self::WorkspaceRenamed { id, new, .. } => {
+                Event::WorkspaceRenamed(NonSpecialWorkspaceEventData {
+                    name: new.clone(),
+                    id: *id,
+                })
+            }
+            Self::WorkspaceMoved { id, name, to, .. } => {
+                Event::WorkspaceMoved(WorkspaceMovedEventData {
+                    name: parse_string_as_work(name.clone()),
+                    id: *id,
+                    monitor: to.clone(),
+                })
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockHyprland};
+
+    fn snapshot(mock: &MockHyprland) -> Snapshot {
+        match Snapshot::instance_get(mock.instance()) {
+            Ok(snapshot) => snapshot,
+            Err(e) => panic!("Error occured: {e}"),
+        }
+    }
+
+    #[test]
+    fn test_no_changes() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        assert!(snapshot(&mock).diff(&snapshot(&mock)).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let old = snapshot(&mock);
+
+        let mut monitors: Vec<Monitor> = serde_json::from_str(fixtures::MONITORS)?;
+        let mut workspaces: Vec<Workspace> = serde_json::from_str(fixtures::WORKSPACES)?;
+        let mut clients: Vec<Client> = serde_json::from_str(fixtures::CLIENTS)?;
+        monitors[0].scale = 1.5;
+        monitors[0].active_workspace = WorkspaceBasic {
+            id: 2,
+            name: "2".into(),
+        };
+        let mut workspace = workspaces[0].clone();
+        workspace.id = 2;
+        workspace.name = "2".into();
+        workspaces[0].name = "web".into();
+        workspaces.push(workspace);
+        clients[0].title = "vim".into();
+        clients[0].floating = true;
+        clients[0].workspace = WorkspaceBasic {
+            id: 2,
+            name: "2".into(),
+        };
+        let mut foot = clients[0].clone();
+        foot.address = Address::new("0x1");
+        foot.focus_history_id = 1;
+        clients.push(foot.clone());
+        let new = Snapshot::new(monitors.clone(), workspaces.clone(), clients);
+
+        let kitty = Address::new("0x55d1c8a0e6f0");
+        let changes = old.diff(&new);
+        assert_eq!(
+            changes,
+            vec![
+                SnapshotChange::MonitorReconfigured {
+                    old: Box::new(old.monitors()[0].clone()),
+                    new: Box::new(monitors[0].clone()),
+                },
+                SnapshotChange::WorkspaceRenamed {
+                    id: 1,
+                    old: "1".into(),
+                    new: "web".into(),
+                },
+                SnapshotChange::WorkspaceCreated(workspaces[1].clone()),
+                SnapshotChange::ClientMoved {
+                    address: kitty.clone(),
+                    from: WorkspaceBasic {
+                        id: 1,
+                        name: "1".into()
+                    },
+                    to: WorkspaceBasic {
+                        id: 2,
+                        name: "2".into()
+                    },
+                },
+                SnapshotChange::ClientTitleChanged {
+                    address: kitty.clone(),
+                    old: "kitty".into(),
+                    new: "vim".into(),
+                },
+                SnapshotChange::ClientFloatingChanged {
+                    address: kitty.clone(),
+                    floating: true,
+                },
+                SnapshotChange::ClientAdded(foot),
+                SnapshotChange::ActiveWorkspaceChanged {
+                    monitor: "DP-1".into(),
+                    workspace: WorkspaceBasic {
+                        id: 2,
+                        name: "2".into()
+                    },
+                },
+            ]
+        );
+
+        // going back removes what was added
+        let changes = new.diff(&old);
+        assert!(changes.contains(&SnapshotChange::WorkspaceDeleted(workspaces[1].clone())));
+        assert!(matches!(
+            changes.last(),
+            Some(SnapshotChange::WorkspaceDeleted(_))
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "listener")]
+    #[test]
+    fn test_to_event() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let clients: Vec<Client> = serde_json::from_str(fixtures::CLIENTS)?;
+        let kitty = &clients[0];
+
+        let event = SnapshotChange::ClientAdded(kitty.clone()).to_event();
+        assert!(
+            matches!(event, Some(Event::WindowOpened(WindowOpenEvent { window_class, .. })) if window_class == "kitty")
+        );
+
+        let event = SnapshotChange::ClientMoved {
+            address: kitty.address.clone(),
+            from: kitty.workspace.clone(),
+            to: WorkspaceBasic {
+                id: -98,
+                name: "special:scratch".into(),
+            },
+        }
+        .to_event();
+        assert_eq!(
+            event.and_then(|event| match event {
+                Event::WindowMoved(data) => Some(data.workspace_name),
+                _ => None,
+            }),
+            Some(WorkspaceType::Special(Some("scratch".into())))
+        );
+
+        let event = SnapshotChange::ClientClassChanged {
+            address: kitty.address.clone(),
+            old: "kitty".into(),
+            new: "foot".into(),
+        }
+        .to_event();
+        assert!(event.is_none());
+
+        let old = snapshot(&mock);
+        let new = Snapshot::new(old.monitors().to_vec(), old.workspaces().to_vec(), vec![]);
+        let events: Vec<Event> = old.diff(&new).iter().filter_map(|c| c.to_event()).collect();
+        assert!(matches!(
+            events.as_slice(),
+            [Event::ActiveWindowChanged(None), Event::WindowClosed(_)]
+        ));
+        Ok(())
+    }
+}
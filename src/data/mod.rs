@@ -52,12 +52,26 @@ use std::collections::HashMap;
 
 mod regular;
 
+/// A snapshot of monitors, workspaces and clients with indexes relating them
+mod snapshot;
+
+/// Differences between two snapshots
+mod diff;
+
+/// Queries finding clients by the properties window rules match on
+#[cfg(feature = "query")]
+mod query;
+
 /// Helpers data commands, these use other hyprctl commands to create new ones!
 mod helpers;
 
 pub use crate::data::helpers::*;
 
 pub use crate::data::regular::*;
+pub use crate::data::snapshot::*;
+pub use crate::data::diff::*;
+#[cfg(feature = "query")]
+pub use crate::data::query::*;
 
 //// This module provides async function calls
 //pub mod asynchronous;
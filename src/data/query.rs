@@ -0,0 +1,400 @@
+use super::*;
+use crate::default_instance;
+use crate::instance::Instance;
+use regex::Regex;
+
+#[cfg(feature = "dispatch")]
+use crate::dispatch::WindowIdentifier;
+
+/// This struct holds a matcher for a client string, like its class or title
+///
+/// Like in window rules, regular expressions have to match the whole string
+#[derive(Debug, Clone)]
+pub struct TextMatch(Matcher);
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    Exact { text: String, pattern: String },
+    Regex(Regex),
+}
+
+impl TextMatch {
+    /// Creates a matcher accepting only the exact string
+    pub fn exact(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let pattern = format!("^{}$", regex::escape(&text));
+        Self(Matcher::Exact { text, pattern })
+    }
+
+    /// Creates a matcher from a regular expression
+    pub fn regex(pattern: &str) -> crate::Result<Self> {
+        Ok(Self(Matcher::Regex(Regex::new(&format!(
+            "^(?:{pattern})$"
+        ))?)))
+    }
+
+    /// Returns the anchored regular expression, in the form Hyprland accepts
+    pub fn pattern(&self) -> &str {
+        match &self.0 {
+            Matcher::Exact { pattern, .. } => pattern,
+            Matcher::Regex(regex) => regex.as_str(),
+        }
+    }
+
+    /// Returns true if the string is matched
+    pub fn is_match(&self, text: &str) -> bool {
+        match &self.0 {
+            Matcher::Exact { text: exact, .. } => exact == text,
+            Matcher::Regex(regex) => regex.is_match(text),
+        }
+    }
+}
+
+impl From<&str> for TextMatch {
+    fn from(text: &str) -> Self {
+        Self::exact(text)
+    }
+}
+
+impl From<String> for TextMatch {
+    fn from(text: String) -> Self {
+        Self::exact(text)
+    }
+}
+
+/// This struct is a builder for finding clients, matching the properties window rules can match on
+///
+/// ```rust, no_run
+/// use hyprland::data::{ClientQuery, TextMatch};
+///
+/// fn main() -> hyprland::Result<()> {
+///     let browsers = ClientQuery::new()
+///         .class(TextMatch::regex("firefox|chromium")?)
+///         .floating(false)
+///         .get()?;
+///     println!("{browsers:#?}");
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClientQuery {
+    class: Option<TextMatch>,
+    initial_class: Option<TextMatch>,
+    title: Option<TextMatch>,
+    initial_title: Option<TextMatch>,
+    workspace: Option<WorkspaceId>,
+    monitor: Option<MonitorId>,
+    floating: Option<bool>,
+    pinned: Option<bool>,
+    fullscreen: Option<FullscreenMode>,
+    xwayland: Option<bool>,
+    pid: Option<i32>,
+    focus_history_id: Option<i8>,
+}
+
+impl ClientQuery {
+    /// Creates a query matching every client
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches the class
+    pub fn class(mut self, class: impl Into<TextMatch>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Matches the class the client had when it was opened
+    pub fn initial_class(mut self, class: impl Into<TextMatch>) -> Self {
+        self.initial_class = Some(class.into());
+        self
+    }
+
+    /// Matches the title
+    pub fn title(mut self, title: impl Into<TextMatch>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Matches the title the client had when it was opened
+    pub fn initial_title(mut self, title: impl Into<TextMatch>) -> Self {
+        self.initial_title = Some(title.into());
+        self
+    }
+
+    /// Matches clients on the workspace
+    pub fn workspace(mut self, id: WorkspaceId) -> Self {
+        self.workspace = Some(id);
+        self
+    }
+
+    /// Matches clients on the monitor
+    pub fn monitor(mut self, id: MonitorId) -> Self {
+        self.monitor = Some(id);
+        self
+    }
+
+    /// Matches floating or tiled clients
+    pub fn floating(mut self, floating: bool) -> Self {
+        self.floating = Some(floating);
+        self
+    }
+
+    /// Matches pinned or unpinned clients
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = Some(pinned);
+        self
+    }
+
+    /// Matches clients in the fullscreen mode
+    pub fn fullscreen(mut self, mode: FullscreenMode) -> Self {
+        self.fullscreen = Some(mode);
+        self
+    }
+
+    /// Matches XWayland or native Wayland clients
+    pub fn xwayland(mut self, xwayland: bool) -> Self {
+        self.xwayland = Some(xwayland);
+        self
+    }
+
+    /// Matches the client's process id
+    pub fn pid(mut self, pid: i32) -> Self {
+        self.pid = Some(pid);
+        self
+    }
+
+    /// Matches the client's place in the focus history, 0 being the focused client
+    pub fn focus_history_id(mut self, id: i8) -> Self {
+        self.focus_history_id = Some(id);
+        self
+    }
+
+    /// This method returns true if the client is matched
+    pub fn matches(&self, client: &Client) -> bool {
+        fn text(matcher: &Option<TextMatch>, text: &str) -> bool {
+            matcher.as_ref().map_or(true, |m| m.is_match(text))
+        }
+        fn value<T: PartialEq>(expected: Option<T>, value: T) -> bool {
+            expected.map_or(true, |expected| expected == value)
+        }
+        text(&self.class, &client.class)
+            && text(&self.initial_class, &client.initial_class)
+            && text(&self.title, &client.title)
+            && text(&self.initial_title, &client.initial_title)
+            && value(self.workspace, client.workspace.id)
+            && self.monitor.map_or(true, |id| client.monitor == Some(id))
+            && value(self.floating, client.floating)
+            && value(self.pinned, client.pinned)
+            && value(self.fullscreen, client.fullscreen)
+            && value(self.xwayland, client.xwayland)
+            && value(self.pid, client.pid)
+            && value(self.focus_history_id, client.focus_history_id)
+    }
+
+    /// This method returns the matched clients, most recently focused first
+    ///
+    /// Takes anything iterating over clients, like [Clients] or [Snapshot::clients]
+    pub fn find_all<'a>(&self, clients: impl IntoIterator<Item = &'a Client>) -> Vec<&'a Client> {
+        let mut found: Vec<&Client> = clients.into_iter().filter(|c| self.matches(c)).collect();
+        // clients without a focus history have a negative id, put them last
+        found.sort_by_key(|c| (c.focus_history_id < 0, c.focus_history_id));
+        found
+    }
+
+    /// This method returns the most recently focused matched client
+    pub fn find<'a>(&self, clients: impl IntoIterator<Item = &'a Client>) -> Option<&'a Client> {
+        self.find_all(clients).into_iter().next()
+    }
+
+    /// This method fetches the clients and returns the matched ones, most recently focused first
+    pub fn get(&self) -> crate::Result<Vec<Client>> {
+        self.instance_get(default_instance()?)
+    }
+
+    /// This method fetches the clients and returns the matched ones, most recently focused first
+    pub fn instance_get(&self, instance: &Instance) -> crate::Result<Vec<Client>> {
+        let clients = Clients::instance_get(instance)?;
+        Ok(self.find_all(&clients).into_iter().cloned().collect())
+    }
+
+    /// This method fetches the clients and returns the matched ones, most recently focused first (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn get_async(&self) -> crate::Result<Vec<Client>> {
+        self.instance_get_async(default_instance()?).await
+    }
+
+    /// This method fetches the clients and returns the matched ones, most recently focused first (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_get_async(&self, instance: &Instance) -> crate::Result<Vec<Client>> {
+        let clients = Clients::instance_get_async(instance).await?;
+        Ok(self.find_all(&clients).into_iter().cloned().collect())
+    }
+
+    /// This method converts the query into a [WindowIdentifier] Hyprland resolves itself,
+    /// which is only possible when it just matches the class, the title or the process id
+    ///
+    /// Use [ClientQuery::find_identifier] for any other query
+    #[cfg(feature = "dispatch")]
+    pub fn to_identifier(&self) -> Option<WindowIdentifier<'_>> {
+        let others = [
+            self.initial_class.is_some(),
+            self.initial_title.is_some(),
+            self.workspace.is_some(),
+            self.monitor.is_some(),
+            self.floating.is_some(),
+            self.pinned.is_some(),
+            self.fullscreen.is_some(),
+            self.xwayland.is_some(),
+            self.focus_history_id.is_some(),
+        ];
+        if others.contains(&true) {
+            return None;
+        }
+        match (&self.class, &self.title, self.pid) {
+            (Some(class), None, None) => {
+                Some(WindowIdentifier::ClassRegularExpression(class.pattern()))
+            }
+            (None, Some(title), None) => Some(WindowIdentifier::Title(title.pattern())),
+            (None, None, Some(pid)) => u32::try_from(pid).ok().map(WindowIdentifier::ProcessId),
+            _ => None,
+        }
+    }
+
+    /// This method returns the address of the most recently focused matched client
+    /// as a [WindowIdentifier]
+    #[cfg(feature = "dispatch")]
+    pub fn find_identifier<'a>(
+        &self,
+        clients: impl IntoIterator<Item = &'a Client>,
+    ) -> Option<WindowIdentifier<'static>> {
+        self.find(clients)
+            .map(|client| WindowIdentifier::Address(client.address.clone()))
+    }
+}
+
+impl Clients {
+    /// This method returns the clients matched by the query, most recently focused first
+    pub fn query(&self, query: &ClientQuery) -> Vec<&Client> {
+        query.find_all(self)
+    }
+}
+
+impl Snapshot {
+    /// This method returns the clients matched by the query, most recently focused first
+    pub fn query(&self, query: &ClientQuery) -> Vec<&Client> {
+        query.find_all(self.clients())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockHyprland};
+
+    fn clients() -> crate::Result<Vec<Client>> {
+        let mut clients: Vec<Client> = serde_json::from_str(fixtures::CLIENTS)?;
+        let mut foot = clients[0].clone();
+        foot.address = Address::new("0x1");
+        foot.class = "foot".into();
+        foot.title = "~/crate".into();
+        foot.floating = true;
+        foot.pid = 4242;
+        foot.focus_history_id = 1;
+        let mut hidden = foot.clone();
+        hidden.address = Address::new("0x2");
+        hidden.focus_history_id = -1;
+        clients.insert(0, hidden);
+        clients.push(foot);
+        Ok(clients)
+    }
+
+    fn addresses(clients: Vec<&Client>) -> Vec<String> {
+        clients.iter().map(|c| c.address.to_string()).collect()
+    }
+
+    #[test]
+    fn test_text_match() -> crate::Result<()> {
+        let exact = TextMatch::exact("a.b");
+        assert!(exact.is_match("a.b"));
+        assert!(!exact.is_match("axb"));
+        assert_eq!(exact.pattern(), r"^a\.b$");
+
+        let regex = TextMatch::regex("fire|chrom")?;
+        assert!(regex.is_match("fire"));
+        assert!(!regex.is_match("firefox"));
+        assert!(matches!(
+            TextMatch::regex("("),
+            Err(crate::error::HyprError::InvalidRegex(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_query() -> crate::Result<()> {
+        let clients = clients()?;
+        let all = ClientQuery::new().find_all(&clients);
+        assert_eq!(addresses(all), vec!["0x55d1c8a0e6f0", "0x1", "0x2"]);
+
+        let query = ClientQuery::new().class(TextMatch::regex("f.*")?);
+        assert_eq!(addresses(query.find_all(&clients)), vec!["0x1", "0x2"]);
+        let query = query.focus_history_id(-1);
+        assert_eq!(addresses(query.find_all(&clients)), vec!["0x2"]);
+
+        let query = ClientQuery::new()
+            .floating(false)
+            .workspace(1)
+            .xwayland(false);
+        assert_eq!(
+            query.find(&clients).map(|c| c.class.as_str()),
+            Some("kitty")
+        );
+        assert!(ClientQuery::new().monitor(1).find(&clients).is_none());
+        assert!(ClientQuery::new().pid(1).find(&clients).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_sources() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let query = ClientQuery::new().class("kitty");
+
+        let found = query.instance_get(mock.instance())?;
+        assert_eq!(found.len(), 1);
+        let clients = Clients::instance_get(mock.instance())?;
+        assert_eq!(clients.query(&query), vec![&found[0]]);
+        let snapshot = Snapshot::instance_get(mock.instance())?;
+        assert_eq!(snapshot.query(&query), vec![&found[0]]);
+        Ok(())
+    }
+
+    #[cfg(feature = "dispatch")]
+    #[test]
+    fn test_identifier() -> crate::Result<()> {
+        let class = ClientQuery::new().class(TextMatch::regex("foot|kitty")?);
+        assert_eq!(
+            class.to_identifier().map(|id| id.to_string()),
+            Some("class:^(?:foot|kitty)$".into())
+        );
+        let title = ClientQuery::new().title("nvim (1)");
+        assert_eq!(
+            title.to_identifier().map(|id| id.to_string()),
+            Some(r"title:^nvim \(1\)$".into())
+        );
+        let pid = ClientQuery::new().pid(4242);
+        assert_eq!(
+            pid.to_identifier().map(|id| id.to_string()),
+            Some("pid:4242".into())
+        );
+
+        let floating = ClientQuery::new().class("foot").floating(true);
+        assert!(floating.to_identifier().is_none());
+        assert_eq!(
+            floating
+                .find_identifier(&clients()?)
+                .map(|id| id.to_string()),
+            Some("address:0x1".into())
+        );
+        Ok(())
+    }
+}
@@ -1,40 +1,110 @@
 use super::*;
 use crate::default_instance;
-use crate::error::hypr_err;
+use crate::error::{hypr_err, HyprError};
 use crate::instance::Instance;
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::cmp::Ordering;
 
-/// This pub(crate) enum holds every socket command that returns data
-#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum DataCommands {
+/// This enum holds every socket command that returns data
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataCommands {
+    /// Gets all monitors, see [Monitors]
     #[display("monitors all")]
     Monitors,
+    /// Gets all workspaces, see [Workspaces]
     #[display("workspaces")]
     Workspaces,
+    /// Gets the active workspace, see [Workspace]
     #[display("activeworkspace")]
     ActiveWorkspace,
+    /// Gets all clients, see [Clients]
     #[display("clients")]
     Clients,
+    /// Gets the active window, see [Client]
     #[display("activewindow")]
     ActiveWindow,
+    /// Gets all layer surfaces, see [Layers]
     #[display("layers")]
     Layers,
+    /// Gets all input devices, see [Devices]
     #[display("devices")]
     Devices,
+    /// Gets the Hyprland version, see [Version]
     #[display("version")]
     Version,
+    /// Gets the cursor position, see [CursorPosition]
     #[display("cursorpos")]
     CursorPosition,
+    /// Gets all binds, see [Binds]
     #[display("binds")]
     Binds,
+    /// Gets all animations and beziers, see [Animations]
     #[display("animations")]
     Animations,
+    /// Gets all workspace rules, see [WorkspaceRules]
     #[display("workspacerules")]
     WorkspaceRules,
 }
 
+/// This enum holds the deserialized reply to a [DataCommands] query
+#[derive(Debug)]
+pub enum DataResponse {
+    /// The reply to [DataCommands::Monitors]
+    Monitors(Monitors),
+    /// The reply to [DataCommands::Workspaces]
+    Workspaces(Workspaces),
+    /// The reply to [DataCommands::ActiveWorkspace]
+    ActiveWorkspace(Workspace),
+    /// The reply to [DataCommands::Clients]
+    Clients(Clients),
+    /// The reply to [DataCommands::ActiveWindow], [None] if no window is focused
+    ActiveWindow(Option<Client>),
+    /// The reply to [DataCommands::Layers]
+    Layers(Layers),
+    /// The reply to [DataCommands::Devices]
+    Devices(Devices),
+    /// The reply to [DataCommands::Version]
+    Version(Version),
+    /// The reply to [DataCommands::CursorPosition]
+    CursorPosition(CursorPosition),
+    /// The reply to [DataCommands::Binds]
+    Binds(Binds),
+    /// The reply to [DataCommands::Animations]
+    Animations(Animations),
+    /// The reply to [DataCommands::WorkspaceRules]
+    WorkspaceRules(WorkspaceRules),
+}
+
+impl DataCommands {
+    /// Deserializes the raw JSON reply of this command
+    pub fn parse_reply(self, data: &str) -> crate::Result<DataResponse> {
+        Ok(match self {
+            DataCommands::Monitors => DataResponse::Monitors(Monitors(serde_json::from_str(data)?)),
+            DataCommands::Workspaces => {
+                DataResponse::Workspaces(Workspaces(serde_json::from_str(data)?))
+            }
+            DataCommands::ActiveWorkspace => {
+                DataResponse::ActiveWorkspace(serde_json::from_str(data)?)
+            }
+            DataCommands::Clients => DataResponse::Clients(Clients(serde_json::from_str(data)?)),
+            DataCommands::ActiveWindow => DataResponse::ActiveWindow(parse_active_window(data)?),
+            DataCommands::Layers => DataResponse::Layers(Layers(serde_json::from_str(data)?)),
+            DataCommands::Devices => DataResponse::Devices(serde_json::from_str(data)?),
+            DataCommands::Version => DataResponse::Version(serde_json::from_str(data)?),
+            DataCommands::CursorPosition => {
+                DataResponse::CursorPosition(serde_json::from_str(data)?)
+            }
+            DataCommands::Binds => DataResponse::Binds(Binds(serde_json::from_str(data)?)),
+            DataCommands::Animations => DataResponse::Animations(parse_animations(data)?),
+            DataCommands::WorkspaceRules => {
+                DataResponse::WorkspaceRules(WorkspaceRules(serde_json::from_str(data)?))
+            }
+        })
+    }
+}
+
 /// This struct holds a basic identifier for a workspace often used in other structs
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct WorkspaceBasic {
@@ -66,6 +136,80 @@ pub enum Transforms {
     Flipped270 = 7,
 }
 
+/// This struct holds a monitor mode, like `2560x1440@144.00Hz`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonitorMode {
+    /// The width (in pixels)
+    pub width: u16,
+    /// The height (in pixels)
+    pub height: u16,
+    /// The refresh rate (in hertz), if one is set
+    pub refresh_rate: Option<f64>,
+}
+
+impl MonitorMode {
+    /// Creates a mode without a refresh rate
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            refresh_rate: None,
+        }
+    }
+
+    /// Creates a mode with a refresh rate
+    pub fn with_refresh_rate(width: u16, height: u16, refresh_rate: f64) -> Self {
+        Self {
+            width,
+            height,
+            refresh_rate: Some(refresh_rate),
+        }
+    }
+
+    /// This method checks if the other mode has the same size, and a refresh rate within half
+    /// a hertz if both have one
+    pub fn matches(&self, other: &MonitorMode) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && match (self.refresh_rate, other.refresh_rate) {
+                (Some(a), Some(b)) => (a - b).abs() < 0.5,
+                _ => true,
+            }
+    }
+}
+
+impl std::fmt::Display for MonitorMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)?;
+        if let Some(refresh_rate) = self.refresh_rate {
+            // round away the noise of refresh rates reported as f32
+            write!(f, "@{}", (refresh_rate * 1000.0).round() / 1000.0)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for MonitorMode {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || HyprError::other(format!("Invalid monitor mode: {s}"));
+        let (size, refresh_rate) = match s.trim().split_once('@') {
+            Some((size, rate)) => {
+                let rate = rate.trim_end_matches("Hz");
+                (size, Some(rate.parse::<f64>().map_err(|_| invalid())?))
+            }
+            None => (s.trim(), None),
+        };
+        let (width, height) = size.split_once('x').ok_or_else(invalid)?;
+        Ok(Self {
+            width: width.parse().map_err(|_| invalid())?,
+            height: height.parse().map_err(|_| invalid())?,
+            refresh_rate,
+        })
+    }
+}
+
 /// This struct holds information for a monitor
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Monitor {
@@ -100,13 +244,140 @@ pub struct Monitor {
     pub transform: Transforms,
     /// a string that identifies if the display is active
     pub focused: bool,
-    /// The dpms status of a monitor
+    /// If the monitor is powered on, false when it was turned off with the `dpms` dispatcher
     #[serde(rename = "dpmsStatus")]
     pub dpms_status: bool,
     /// VRR state
     pub vrr: bool,
-    /// Is the monitor disabled or not
+    /// If the monitor is connected but disabled, like by a `monitor = NAME, disable` rule.
+    /// Disabled monitors are only listed by `monitors all`, and have no workspaces
     pub disabled: bool,
+    /// The manufacturer, from the EDID
+    #[serde(default)]
+    pub make: String,
+    /// The model, from the EDID
+    #[serde(default)]
+    pub model: String,
+    /// The serial number, from the EDID, can be empty
+    #[serde(default)]
+    pub serial: String,
+    /// The modes the monitor supports
+    #[serde(rename = "availableModes", default)]
+    pub available_modes: Vec<MonitorMode>,
+    /// The name of the monitor this one mirrors, if any
+    #[serde(rename = "mirrorOf", default, with = "none_as_string")]
+    pub mirror_of: Option<String>,
+    /// The pixel format, like `XRGB8888`
+    #[serde(rename = "currentFormat", default)]
+    pub current_format: String,
+    /// The window shown alone on the monitor, if any, which lets Hyprland skip drawing the rest
+    #[serde(default, with = "zero_as_none")]
+    pub solitary: Option<Address>,
+    /// If a window is currently tearing on the monitor
+    #[serde(rename = "activelyTearing", default)]
+    pub actively_tearing: bool,
+    /// The window scanned out directly to the monitor, if any
+    #[serde(rename = "directScanoutTo", default, with = "zero_as_none")]
+    pub direct_scanout_to: Option<Address>,
+}
+
+impl Monitor {
+    /// This method returns the make, model and serial of the monitor, which identify it
+    /// whatever connector it is plugged into.
+    /// It matches the start of the description, so it can be used in `desc:` monitor rules
+    pub fn identity(&self) -> String {
+        [&self.make, &self.model, &self.serial]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// This method returns the mode the monitor is in
+    pub fn current_mode(&self) -> MonitorMode {
+        MonitorMode::with_refresh_rate(self.width, self.height, self.refresh_rate.into())
+    }
+
+    /// This method returns the available mode with the size and the highest refresh rate
+    pub fn find_mode(&self, width: u16, height: u16) -> Option<&MonitorMode> {
+        self.available_modes
+            .iter()
+            .filter(|mode| mode.width == width && mode.height == height)
+            .max_by(|a, b| {
+                a.refresh_rate
+                    .partial_cmp(&b.refresh_rate)
+                    .unwrap_or(Ordering::Equal)
+            })
+    }
+
+    /// This method returns the available mode with the most pixels, then the highest refresh rate
+    pub fn highest_resolution_mode(&self) -> Option<&MonitorMode> {
+        self.available_modes.iter().max_by(|a, b| {
+            let pixels = |mode: &MonitorMode| u32::from(mode.width) * u32::from(mode.height);
+            pixels(a).cmp(&pixels(b)).then(
+                a.refresh_rate
+                    .partial_cmp(&b.refresh_rate)
+                    .unwrap_or(Ordering::Equal),
+            )
+        })
+    }
+}
+
+impl Serialize for MonitorMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MonitorMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Hyprland writes `none` for a monitor that mirrors nothing
+mod none_as_string {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<String>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(value.as_deref().unwrap_or("none"))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<String>, D::Error> {
+        Ok(Option::<String>::deserialize(deserializer)?.filter(|name| name != "none"))
+    }
+}
+
+/// Hyprland writes `0` for a window address that isn't set
+mod zero_as_none {
+    use crate::shared::Address;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Address>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(address) => serializer.collect_str(address),
+            None => serializer.serialize_str("0"),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Address>, D::Error> {
+        Ok(Option::<String>::deserialize(deserializer)?
+            .filter(|address| !matches!(address.as_str(), "" | "0" | "0x0"))
+            .map(Address::new))
+    }
 }
 
 impl HyprDataActive for Monitor {
@@ -265,6 +536,16 @@ pub struct Client {
 #[serde(deny_unknown_fields)]
 struct Empty {}
 
+fn parse_active_window(data: &str) -> crate::Result<Option<Client>> {
+    let res = serde_json::from_str::<Empty>(data);
+    if res.is_err() {
+        let t = serde_json::from_str::<Client>(data)?;
+        Ok(Some(t))
+    } else {
+        Ok(None)
+    }
+}
+
 impl HyprDataActiveOptional for Client {
     fn get_active() -> crate::Result<Option<Self>> {
         Self::instance_get_active(default_instance()?)
@@ -275,26 +556,14 @@ impl HyprDataActiveOptional for Client {
     }
     fn instance_get_active(instance: &Instance) -> crate::Result<Option<Self>> {
         let data = instance.write_to_socket(command!(JSON, "{}", DataCommands::ActiveWindow))?;
-        let res = serde_json::from_str::<Empty>(&data);
-        if res.is_err() {
-            let t = serde_json::from_str::<Client>(&data)?;
-            Ok(Some(t))
-        } else {
-            Ok(None)
-        }
+        parse_active_window(&data)
     }
     #[cfg(any(feature = "async-lite", feature = "tokio"))]
     async fn instance_get_active_async(instance: &Instance) -> crate::Result<Option<Self>> {
         let data = instance
             .write_to_socket_async(command!(JSON, "{}", DataCommands::ActiveWindow))
             .await?;
-        let res = serde_json::from_str::<Empty>(&data);
-        if res.is_err() {
-            let t = serde_json::from_str::<Client>(&data)?;
-            Ok(Some(t))
-        } else {
-            Ok(None)
-        }
+        parse_active_window(&data)
     }
 }
 
@@ -639,6 +908,24 @@ struct AnimationsRaw(Vec<AnimationRaw>, Vec<RawBezierIdent>);
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Animations(pub Vec<Animation>, pub Vec<BezierIdent>);
 
+fn parse_animations(data: &str) -> crate::Result<Animations> {
+    let des: AnimationsRaw = serde_json::from_str(data)?;
+    let AnimationsRaw(anims, beziers) = des;
+    let new_anims: Vec<Animation> = anims
+        .into_iter()
+        .map(|item| Animation {
+            name: item.name,
+            overridden: item.overridden,
+            bezier: item.bezier.into(),
+            enabled: item.enabled,
+            speed: item.speed,
+            style: item.style.into(),
+        })
+        .collect();
+    let new_bezs: Vec<BezierIdent> = beziers.into_iter().map(|item| item.name.into()).collect();
+    Ok(Animations(new_anims, new_bezs))
+}
+
 impl HyprData for Animations {
     fn get() -> crate::Result<Self> {
         Self::instance_get(default_instance()?)
@@ -651,42 +938,14 @@ impl HyprData for Animations {
 
     fn instance_get(instance: &Instance) -> crate::Result<Self> {
         let out = instance.write_to_socket(command!(JSON, "{}", DataCommands::Animations))?;
-        let des: AnimationsRaw = serde_json::from_str(&out)?;
-        let AnimationsRaw(anims, beziers) = des;
-        let new_anims: Vec<Animation> = anims
-            .into_iter()
-            .map(|item| Animation {
-                name: item.name,
-                overridden: item.overridden,
-                bezier: item.bezier.into(),
-                enabled: item.enabled,
-                speed: item.speed,
-                style: item.style.into(),
-            })
-            .collect();
-        let new_bezs: Vec<BezierIdent> = beziers.into_iter().map(|item| item.name.into()).collect();
-        Ok(Animations(new_anims, new_bezs))
+        parse_animations(&out)
     }
     #[cfg(any(feature = "async-lite", feature = "tokio"))]
     async fn instance_get_async(instance: &Instance) -> crate::Result<Self> {
         let out = instance
             .write_to_socket_async(command!(JSON, "{}", DataCommands::Animations))
             .await?;
-        let des: AnimationsRaw = serde_json::from_str(&out)?;
-        let AnimationsRaw(anims, beziers) = des;
-        let new_anims: Vec<Animation> = anims
-            .into_iter()
-            .map(|item| Animation {
-                name: item.name,
-                overridden: item.overridden,
-                bezier: item.bezier.into(),
-                enabled: item.enabled,
-                speed: item.speed,
-                style: item.style.into(),
-            })
-            .collect();
-        let new_bezs: Vec<BezierIdent> = beziers.into_iter().map(|item| item.name.into()).collect();
-        Ok(Animations(new_anims, new_bezs))
+        parse_animations(&out)
     }
 }
 
@@ -721,6 +980,9 @@ pub struct WorkspaceRuleset {
     pub decorate: Option<bool>,
     /// Is it persistent?
     pub persistent: Option<bool>,
+    /// The name the workspace gets when it is created
+    #[serde(rename = "defaultName")]
+    pub default_name: Option<String>,
 }
 
 create_data_struct!(
@@ -730,3 +992,42 @@ create_data_struct!(
     holding_type: WorkspaceRuleset,
     doc: "This struct holds a vector of workspace rules per workspace"
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures;
+
+    #[test]
+    fn test_monitor_fields() -> crate::Result<()> {
+        let monitors: Vec<Monitor> = serde_json::from_str(fixtures::MONITORS)?;
+        let monitor = &monitors[0];
+        assert_eq!(monitor.identity(), "Dell Inc. DELL U2720Q 6KFDN13");
+        assert_eq!(monitor.mirror_of, None);
+        assert_eq!(monitor.solitary, None);
+        assert_eq!(monitor.current_format, "XRGB8888");
+        assert_eq!(monitor.available_modes.len(), 3);
+        assert_eq!(
+            monitor.find_mode(2560, 1440),
+            Some(&MonitorMode::with_refresh_rate(2560, 1440, 144.0))
+        );
+        assert!(monitor.current_mode().matches(&monitor.available_modes[0]));
+        assert_eq!(
+            monitor.highest_resolution_mode().map(ToString::to_string),
+            Some("2560x1440@144".to_string())
+        );
+
+        // the optional fields serialize back to what Hyprland writes
+        let mut json = serde_json::to_value(monitor)?;
+        assert_eq!(json["mirrorOf"], "none");
+        assert_eq!(json["solitary"], "0");
+        json["solitary"] = "55d1c8a0e6f0".into();
+        json["mirrorOf"] = "DP-2".into();
+        let monitor: Monitor = serde_json::from_value(json)?;
+        assert_eq!(monitor.solitary, Some(Address::new("55d1c8a0e6f0")));
+        assert_eq!(monitor.mirror_of.as_deref(), Some("DP-2"));
+
+        assert!("2560x1440@fast".parse::<MonitorMode>().is_err());
+        Ok(())
+    }
+}
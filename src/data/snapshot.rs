@@ -0,0 +1,374 @@
+use super::*;
+use crate::batch::{Batch, BatchResponse};
+use crate::default_instance;
+use crate::error::hypr_err;
+use crate::instance::Instance;
+
+/// This struct holds the monitors, workspaces and clients fetched in a single request,
+/// and indexes relating them to each other
+///
+/// ```rust, no_run
+/// use hyprland::data::Snapshot;
+/// use hyprland::prelude::*;
+/// fn main() -> hyprland::Result<()> {
+///     let snapshot = Snapshot::get()?;
+///     if let Some(workspace) = snapshot.focused_monitor().and_then(|m| snapshot.active_workspace(m)) {
+///         for client in snapshot.clients_on_workspace(workspace.id) {
+///             println!("{}", client.title);
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    monitors: Vec<Monitor>,
+    workspaces: Vec<Workspace>,
+    clients: Vec<Client>,
+    monitor_by_id: HashMap<MonitorId, usize>,
+    monitor_by_name: HashMap<String, usize>,
+    workspace_by_id: HashMap<WorkspaceId, usize>,
+    client_by_address: HashMap<Address, usize>,
+    clients_by_workspace: HashMap<WorkspaceId, Vec<usize>>,
+    workspaces_by_monitor: HashMap<String, Vec<usize>>,
+    swallowed_by: HashMap<Address, usize>,
+}
+
+fn indexes<'a, T>(
+    items: &'a [T],
+    indexes: Option<&'a Vec<usize>>,
+) -> impl Iterator<Item = &'a T> + 'a {
+    indexes
+        .into_iter()
+        .flatten()
+        .filter_map(|index| items.get(*index))
+}
+
+impl Snapshot {
+    /// This method creates a snapshot from already fetched data
+    pub fn new(monitors: Vec<Monitor>, workspaces: Vec<Workspace>, clients: Vec<Client>) -> Self {
+        let mut snapshot = Self {
+            monitor_by_id: HashMap::default(),
+            monitor_by_name: HashMap::default(),
+            workspace_by_id: HashMap::default(),
+            client_by_address: HashMap::default(),
+            clients_by_workspace: HashMap::default(),
+            workspaces_by_monitor: HashMap::default(),
+            swallowed_by: HashMap::default(),
+            monitors,
+            workspaces,
+            clients,
+        };
+        for (index, monitor) in snapshot.monitors.iter().enumerate() {
+            snapshot.monitor_by_id.insert(monitor.id, index);
+            snapshot.monitor_by_name.insert(monitor.name.clone(), index);
+        }
+        for (index, workspace) in snapshot.workspaces.iter().enumerate() {
+            snapshot.workspace_by_id.insert(workspace.id, index);
+            snapshot
+                .workspaces_by_monitor
+                .entry(workspace.monitor.clone())
+                .or_default()
+                .push(index);
+        }
+        for (index, client) in snapshot.clients.iter().enumerate() {
+            snapshot
+                .client_by_address
+                .insert(client.address.clone(), index);
+            snapshot
+                .clients_by_workspace
+                .entry(client.workspace.id)
+                .or_default()
+                .push(index);
+            if let Some(swallowed) = &client.swallowing {
+                snapshot.swallowed_by.insert((**swallowed).clone(), index);
+            }
+        }
+        snapshot
+    }
+
+    /// This method returns the monitors, workspaces and clients the snapshot was made of
+    pub fn into_parts(self) -> (Vec<Monitor>, Vec<Workspace>, Vec<Client>) {
+        (self.monitors, self.workspaces, self.clients)
+    }
+
+    /// This method returns every monitor
+    pub fn monitors(&self) -> &[Monitor] {
+        &self.monitors
+    }
+
+    /// This method returns every workspace
+    pub fn workspaces(&self) -> &[Workspace] {
+        &self.workspaces
+    }
+
+    /// This method returns every client
+    pub fn clients(&self) -> &[Client] {
+        &self.clients
+    }
+
+    /// This method returns the monitor with the id
+    pub fn monitor(&self, id: MonitorId) -> Option<&Monitor> {
+        let index = self.monitor_by_id.get(&id)?;
+        self.monitors.get(*index)
+    }
+
+    /// This method returns the monitor with the name
+    pub fn monitor_by_name(&self, name: &str) -> Option<&Monitor> {
+        let index = self.monitor_by_name.get(name)?;
+        self.monitors.get(*index)
+    }
+
+    /// This method returns the focused monitor
+    pub fn focused_monitor(&self) -> Option<&Monitor> {
+        self.monitors.iter().find(|monitor| monitor.focused)
+    }
+
+    /// This method returns the workspace with the id
+    pub fn workspace(&self, id: WorkspaceId) -> Option<&Workspace> {
+        let index = self.workspace_by_id.get(&id)?;
+        self.workspaces.get(*index)
+    }
+
+    /// This method returns the workspace shown on the monitor
+    pub fn active_workspace(&self, monitor: &Monitor) -> Option<&Workspace> {
+        self.workspace(monitor.active_workspace.id)
+    }
+
+    /// This method returns every workspace on the monitor
+    pub fn workspaces_on_monitor(&self, monitor: &Monitor) -> impl Iterator<Item = &Workspace> {
+        indexes(
+            &self.workspaces,
+            self.workspaces_by_monitor.get(&monitor.name),
+        )
+    }
+
+    /// This method returns the monitor the workspace is on
+    pub fn monitor_for_workspace(&self, workspace: &Workspace) -> Option<&Monitor> {
+        match workspace.monitor_id {
+            Some(id) => self.monitor(id),
+            None => self.monitor_by_name(&workspace.monitor),
+        }
+    }
+
+    /// This method returns the client with the address
+    pub fn client(&self, address: &Address) -> Option<&Client> {
+        let index = self.client_by_address.get(address)?;
+        self.clients.get(*index)
+    }
+
+    /// This method returns the active window
+    pub fn active_client(&self) -> Option<&Client> {
+        self.clients
+            .iter()
+            .find(|client| client.focus_history_id == 0)
+    }
+
+    /// This method returns every client on the workspace
+    pub fn clients_on_workspace(&self, id: WorkspaceId) -> impl Iterator<Item = &Client> {
+        indexes(&self.clients, self.clients_by_workspace.get(&id))
+    }
+
+    /// This method returns every client on the monitor, across all its workspaces
+    pub fn clients_on_monitor<'a>(
+        &'a self,
+        monitor: &'a Monitor,
+    ) -> impl Iterator<Item = &'a Client> + 'a {
+        self.clients
+            .iter()
+            .filter(move |client| match self.monitor_for_client(client) {
+                Some(client_monitor) => client_monitor.id == monitor.id,
+                None => false,
+            })
+    }
+
+    /// This method returns the workspace the client is on
+    pub fn workspace_for_client(&self, client: &Client) -> Option<&Workspace> {
+        self.workspace(client.workspace.id)
+    }
+
+    /// This method returns the monitor the client is on
+    pub fn monitor_for_client(&self, client: &Client) -> Option<&Monitor> {
+        match client.monitor {
+            Some(id) => self.monitor(id),
+            None => self
+                .workspace_for_client(client)
+                .and_then(|workspace| self.monitor_for_workspace(workspace)),
+        }
+    }
+
+    /// This method returns the clients in the same group as the client, including itself
+    pub fn group<'a>(&'a self, client: &'a Client) -> impl Iterator<Item = &'a Client> + 'a {
+        client
+            .grouped
+            .iter()
+            .filter_map(move |address| self.client(address))
+    }
+
+    /// This method returns the client swallowed by the client
+    pub fn swallowed(&self, client: &Client) -> Option<&Client> {
+        client
+            .swallowing
+            .as_ref()
+            .and_then(|address| self.client(address))
+    }
+
+    /// This method returns the client that swallowed the client
+    pub fn swallowed_by(&self, client: &Client) -> Option<&Client> {
+        let index = self.swallowed_by.get(&client.address)?;
+        self.clients.get(*index)
+    }
+
+    fn batch() -> Batch {
+        Batch::new()
+            .data(DataCommands::Monitors)
+            .data(DataCommands::Workspaces)
+            .data(DataCommands::Clients)
+    }
+
+    fn from_responses(responses: Vec<BatchResponse>) -> crate::Result<Self> {
+        let mut responses = responses.into_iter();
+        match (responses.next(), responses.next(), responses.next()) {
+            (
+                Some(BatchResponse::Data(monitors)),
+                Some(BatchResponse::Data(workspaces)),
+                Some(BatchResponse::Data(clients)),
+            ) => match (monitors?, workspaces?, clients?) {
+                (
+                    DataResponse::Monitors(monitors),
+                    DataResponse::Workspaces(workspaces),
+                    DataResponse::Clients(clients),
+                ) => Ok(Self::new(
+                    monitors.to_vec(),
+                    workspaces.to_vec(),
+                    clients.to_vec(),
+                )),
+                _ => hypr_err!("Unexpected data in the snapshot replies!"),
+            },
+            _ => hypr_err!("Unexpected replies to the snapshot request!"),
+        }
+    }
+}
+
+impl HyprData for Snapshot {
+    fn get() -> crate::Result<Self> {
+        Self::instance_get(default_instance()?)
+    }
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    async fn get_async() -> crate::Result<Self> {
+        Self::instance_get_async(default_instance()?).await
+    }
+    fn instance_get(instance: &Instance) -> crate::Result<Self> {
+        Self::from_responses(Self::batch().instance_call(instance)?)
+    }
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    async fn instance_get_async(instance: &Instance) -> crate::Result<Self> {
+        Self::from_responses(Self::batch().instance_call_async(instance).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockHyprland;
+
+    fn client(address: &str, workspace: WorkspaceId, monitor: Option<MonitorId>) -> Client {
+        Client {
+            address: Address::new(address),
+            at: (0, 0),
+            size: (100, 100),
+            workspace: WorkspaceBasic {
+                id: workspace,
+                name: workspace.to_string(),
+            },
+            floating: false,
+            fullscreen: FullscreenMode::None,
+            fullscreen_client: FullscreenMode::None,
+            monitor,
+            initial_class: "foot".into(),
+            class: "foot".into(),
+            initial_title: "foot".into(),
+            title: "foot".into(),
+            pid: 1,
+            xwayland: false,
+            pinned: false,
+            grouped: vec![],
+            mapped: true,
+            swallowing: None,
+            focus_history_id: 1,
+        }
+    }
+
+    fn workspace(id: WorkspaceId, monitor: &str, monitor_id: MonitorId) -> Workspace {
+        Workspace {
+            id,
+            name: id.to_string(),
+            monitor: monitor.into(),
+            monitor_id: Some(monitor_id),
+            windows: 0,
+            fullscreen: false,
+            last_window: Address::new("0x0"),
+            last_window_title: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_snapshot_get() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let snapshot = Snapshot::instance_get(mock.instance())?;
+        assert_eq!(
+            mock.received(),
+            vec!["monitors all", "workspaces", "clients"]
+        );
+        let monitor = snapshot.focused_monitor().map(|m| m.name.as_str());
+        assert_eq!(monitor, Some("DP-1"));
+        let active = snapshot.active_client().map(|c| c.class.as_str());
+        assert_eq!(active, Some("kitty"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot_relations() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let (monitors, _, _) = Snapshot::instance_get(mock.instance())?.into_parts();
+        let mut grouped_a = client("0xa", 1, Some(0));
+        let mut grouped_b = client("0xb", 1, None);
+        let group = vec![Box::new(Address::new("0xa")), Box::new(Address::new("0xb"))];
+        grouped_a.grouped.clone_from(&group);
+        grouped_b.grouped = group;
+        let mut terminal = client("0xc", 2, Some(0));
+        terminal.swallowing = Some(Box::new(Address::new("0xd")));
+        let swallowed = client("0xd", 2, Some(0));
+        let snapshot = Snapshot::new(
+            monitors,
+            vec![workspace(1, "DP-1", 0), workspace(2, "DP-1", 0)],
+            vec![grouped_a, grouped_b, terminal, swallowed],
+        );
+
+        let on_workspace: Vec<_> = snapshot
+            .clients_on_workspace(1)
+            .map(|c| c.address.to_string())
+            .collect();
+        assert_eq!(on_workspace, vec!["0xa", "0xb"]);
+
+        let Some(monitor) = snapshot.monitor_by_name("DP-1") else {
+            panic!("monitor is missing");
+        };
+        assert_eq!(snapshot.workspaces_on_monitor(monitor).count(), 2);
+        assert_eq!(snapshot.clients_on_monitor(monitor).count(), 4);
+        assert_eq!(snapshot.active_workspace(monitor).map(|w| w.id), Some(1));
+
+        let Some(b) = snapshot.client(&Address::new("0xb")) else {
+            panic!("client is missing");
+        };
+        // the monitor is resolved through the workspace
+        assert_eq!(snapshot.monitor_for_client(b).map(|m| m.id), Some(0));
+        assert_eq!(snapshot.group(b).count(), 2);
+
+        let terminal = snapshot.client(&Address::new("0xc"));
+        let swallowed = snapshot.client(&Address::new("0xd"));
+        assert_eq!(terminal.and_then(|t| snapshot.swallowed(t)), swallowed);
+        assert_eq!(swallowed.and_then(|s| snapshot.swallowed_by(s)), terminal);
+        Ok(())
+    }
+}
@@ -19,10 +19,15 @@ use crate::dispatch::fmt::*;
 use crate::error::HyprError;
 use crate::shared::*;
 use derive_more::Display;
+use serde::{Deserialize, Serialize};
 use std::string::ToString;
 
+mod owned;
+mod parse;
+pub use crate::dispatch::owned::*;
+
 /// This enum is for identifying a window
-#[derive(Debug, Clone, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
 pub enum WindowIdentifier<'a> {
     /// The address of a window
     #[display("address:{_0}")]
@@ -39,7 +44,7 @@ pub enum WindowIdentifier<'a> {
 }
 
 /// This enum holds the fullscreen types
-#[derive(Debug, Clone, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Serialize, Deserialize)]
 pub enum FullscreenType {
     /// Fills the whole screen
     #[display("0")]
@@ -53,7 +58,7 @@ pub enum FullscreenType {
 }
 
 /// This enum holds directions, typically used for moving
-#[derive(Debug, Clone, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub enum Direction {
     #[display("u")]
@@ -67,7 +72,7 @@ pub enum Direction {
 }
 
 /// This enum is used for resizing and moving windows precisely
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Position {
     /// A delta
     Delta(i16, i16),
@@ -87,7 +92,7 @@ impl std::fmt::Display for Position {
 
 /// This enum holds a direction for cycling
 #[allow(missing_docs)]
-#[derive(Debug, Clone, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Serialize, Deserialize)]
 pub enum CycleDirection {
     #[display("")]
     Next,
@@ -97,7 +102,7 @@ pub enum CycleDirection {
 
 /// This enum holds a direction for switch windows in a group
 #[allow(missing_docs)]
-#[derive(Debug, Clone, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Serialize, Deserialize)]
 pub enum WindowSwitchDirection {
     #[display("b")]
     Back,
@@ -106,7 +111,7 @@ pub enum WindowSwitchDirection {
 }
 
 /// This enum is used for identifying monitors
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MonitorIdentifier<'a> {
     /// The monitor that is to the specified direction of the active one
     Direction(Direction),
@@ -135,7 +140,7 @@ impl std::fmt::Display for MonitorIdentifier<'_> {
 
 /// This enum holds corners
 #[allow(missing_docs)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Corner {
     BottomLeft = 0,
     BottomRight = 1,
@@ -144,18 +149,18 @@ pub enum Corner {
 }
 
 /// This enum holds options that are applied to the current workspace
-#[derive(Debug, Clone, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Serialize, Deserialize)]
 pub enum WorkspaceOptions {
     /// Makes all windows pseudo tiled
-    #[display("allfloat")]
+    #[display("allpseudo")]
     AllPseudo,
     /// Makes all windows float
-    #[display("allpseudo")]
+    #[display("allfloat")]
     AllFloat,
 }
 
 /// This struct holds options for the first empty workspace
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FirstEmpty {
     /// If the first empty workspace should be on the monitor
     pub on_monitor: bool,
@@ -226,7 +231,7 @@ pub(super) mod fmt {
         } else if int.is_negative() {
             format!("{extra}-{}", int.abs())
         } else {
-            "+0".to_owned()
+            format!("{extra}+0")
         }
     }
 }
@@ -271,7 +276,7 @@ impl std::fmt::Display for WorkspaceIdentifier<'_> {
 }
 
 /// This enum is the params to [DispatchType::MoveWindow] dispatcher
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WindowMove<'a> {
     /// Moves the window to a specified monitor
     Monitor(MonitorIdentifier<'a>),
@@ -280,7 +285,9 @@ pub enum WindowMove<'a> {
 }
 
 /// This enum holds every dispatcher
-#[derive(Debug, Clone)]
+///
+/// See [OwnedDispatchType] for a version that can be stored, sent across threads, serialized and parsed
+#[derive(Debug, Clone, PartialEq)]
 pub enum DispatchType<'a> {
     /// This lets you use dispatchers not supported by hyprland-rs yet, please make issues before
     /// using
@@ -444,7 +451,7 @@ pub enum DispatchType<'a> {
 }
 
 /// Enum used with [DispatchType::LockGroups], to determine how to lock/unlock
-#[derive(Debug, Clone, Copy, Display, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum LockType {
     /// Lock Group
     #[display("lock")]
@@ -458,7 +465,7 @@ pub enum LockType {
 }
 
 /// Param for [DispatchType::SwapWithMaster] dispatcher
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Serialize, Deserialize)]
 pub enum SwapWithMasterParam {
     /// New focus is the new master window
     #[display("master")]
@@ -472,7 +479,7 @@ pub enum SwapWithMasterParam {
 }
 
 /// Param for [DispatchType::FocusMaster] dispatcher
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Serialize, Deserialize)]
 pub enum FocusMasterParam {
     /// Focus stays at master, (even if it was selected before)
     #[display("master")]
@@ -482,10 +489,10 @@ pub enum FocusMasterParam {
     Auto,
 }
 
-pub(crate) fn gen_dispatch_str(cmd: DispatchType, dispatch: bool) -> crate::Result<CommandContent> {
+/// Returns the dispatcher name and its args, separated by `sep`
+pub(crate) fn dispatch_str(cmd: &DispatchType, sep: &str) -> String {
     use DispatchType::*;
-    let sep = if dispatch { " " } else { "," };
-    let string_to_pass = match &cmd {
+    match cmd {
         Custom(name, args) => format!("{name}{sep}{args}"),
         Exec(sh) => format!("exec{sep}{sh}"),
         Pass(win) => format!("pass{sep}{win}"),
@@ -531,7 +538,7 @@ pub(crate) fn gen_dispatch_str(cmd: DispatchType, dispatch: bool) -> crate::Resu
         FocusMonitor(mon) => format!("focusmonitor{sep}{mon}"),
         ChangeSplitRatio(ratio) => format!("splitratio {ratio}"),
         ToggleOpaque => "toggleopaque".to_string(),
-        MoveCursorToCorner(corner) => format!("movecursortocorner{sep}{}", corner.clone() as u8),
+        MoveCursorToCorner(corner) => format!("movecursortocorner{sep}{}", *corner as u8),
         MoveCursor(x, y) => format!("movecursor{sep}{x} {y}"),
         WorkspaceOption(opt) => format!("workspaceopt{sep}{opt}"),
         Exit => "exit".to_string(),
@@ -548,7 +555,7 @@ pub(crate) fn gen_dispatch_str(cmd: DispatchType, dispatch: bool) -> crate::Resu
         }
         SwapActiveWorkspaces(mon, mon2) => format!("swapactiveworkspaces{sep}{mon} {mon2}",),
         BringActiveToTop => "bringactivetotop".to_string(),
-        SetCursor(theme, size) => format!("{theme} {}", *size),
+        SetCursor(theme, size) => format!("setcursor {theme} {}", *size),
         FocusUrgentOrLast => "focusurgentorlast".to_string(),
         FocusCurrentOrLast => "focuscurrentorlast".to_string(),
         ToggleSplit => "togglesplit".to_string(),
@@ -568,10 +575,15 @@ pub(crate) fn gen_dispatch_str(cmd: DispatchType, dispatch: bool) -> crate::Resu
         LockGroups(how) => format!("lockgroups{sep}{how}"),
         MoveIntoGroup(dir) => format!("moveintogroup{sep}{dir}"),
         MoveOutOfGroup => "moveoutofgroup".to_string(),
-    };
+    }
+}
+
+pub(crate) fn gen_dispatch_str(cmd: DispatchType, dispatch: bool) -> crate::Result<CommandContent> {
+    let sep = if dispatch { " " } else { "," };
+    let string_to_pass = dispatch_str(&cmd, sep);
 
-    if let SetCursor(_, _) = cmd {
-        Ok(command!(JSON, "setcursor {string_to_pass}"))
+    if let DispatchType::SetCursor(_, _) = cmd {
+        Ok(command!(JSON, "{string_to_pass}"))
     } else if dispatch {
         Ok(command!(JSON, "dispatch {string_to_pass}"))
     } else {
@@ -682,3 +694,25 @@ macro_rules! dispatch {
         $crate::dispatch::Dispatch::call($crate::dispatch::DispatchType::$dis($($arg), *))
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workspace_options() {
+        assert_eq!(WorkspaceOptions::AllFloat.to_string(), "allfloat");
+        assert_eq!(WorkspaceOptions::AllPseudo.to_string(), "allpseudo");
+    }
+
+    #[test]
+    fn test_relative_zero() {
+        use WorkspaceIdentifierWithSpecial as W;
+        assert_eq!(W::Relative(0).to_string(), "+0");
+        assert_eq!(W::RelativeMonitor(0).to_string(), "m+0");
+        assert_eq!(W::RelativeMonitorIncludingEmpty(0).to_string(), "r+0");
+        assert_eq!(W::RelativeOpen(0).to_string(), "e+0");
+        assert_eq!(W::RelativeMonitor(-2).to_string(), "m-2");
+        assert_eq!(WorkspaceIdentifier::RelativeOpen(0).to_string(), "e+0");
+    }
+}
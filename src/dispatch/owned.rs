@@ -0,0 +1,573 @@
+use super::*;
+
+/// This enum is the owned version of [WindowIdentifier]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OwnedWindowIdentifier {
+    /// The address of a window
+    Address(Address),
+    /// A Regular Expression to match the window class (handled by Hyprland)
+    ClassRegularExpression(String),
+    /// The window title
+    Title(String),
+    /// The window's process Id
+    ProcessId(u32),
+}
+
+impl OwnedWindowIdentifier {
+    /// Borrows the identifier as a [WindowIdentifier]
+    pub fn as_borrowed(&self) -> WindowIdentifier<'_> {
+        match self {
+            Self::Address(addr) => WindowIdentifier::Address(addr.clone()),
+            Self::ClassRegularExpression(class) => WindowIdentifier::ClassRegularExpression(class),
+            Self::Title(title) => WindowIdentifier::Title(title),
+            Self::ProcessId(pid) => WindowIdentifier::ProcessId(*pid),
+        }
+    }
+}
+
+impl From<WindowIdentifier<'_>> for OwnedWindowIdentifier {
+    fn from(ident: WindowIdentifier<'_>) -> Self {
+        match ident {
+            WindowIdentifier::Address(addr) => Self::Address(addr),
+            WindowIdentifier::ClassRegularExpression(class) => {
+                Self::ClassRegularExpression(class.to_string())
+            }
+            WindowIdentifier::Title(title) => Self::Title(title.to_string()),
+            WindowIdentifier::ProcessId(pid) => Self::ProcessId(pid),
+        }
+    }
+}
+
+impl std::fmt::Display for OwnedWindowIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_borrowed())
+    }
+}
+
+/// This enum is the owned version of [MonitorIdentifier]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OwnedMonitorIdentifier {
+    /// The monitor that is to the specified direction of the active one
+    Direction(Direction),
+    /// The monitor id
+    Id(MonitorId),
+    /// The monitor name
+    Name(String),
+    /// The current monitor
+    Current,
+    /// The workspace relative to the current workspace
+    Relative(i32),
+}
+
+impl OwnedMonitorIdentifier {
+    /// Borrows the identifier as a [MonitorIdentifier]
+    pub fn as_borrowed(&self) -> MonitorIdentifier<'_> {
+        match self {
+            Self::Direction(dir) => MonitorIdentifier::Direction(*dir),
+            Self::Id(id) => MonitorIdentifier::Id(*id),
+            Self::Name(name) => MonitorIdentifier::Name(name),
+            Self::Current => MonitorIdentifier::Current,
+            Self::Relative(int) => MonitorIdentifier::Relative(*int),
+        }
+    }
+}
+
+impl From<MonitorIdentifier<'_>> for OwnedMonitorIdentifier {
+    fn from(ident: MonitorIdentifier<'_>) -> Self {
+        match ident {
+            MonitorIdentifier::Direction(dir) => Self::Direction(dir),
+            MonitorIdentifier::Id(id) => Self::Id(id),
+            MonitorIdentifier::Name(name) => Self::Name(name.to_string()),
+            MonitorIdentifier::Current => Self::Current,
+            MonitorIdentifier::Relative(int) => Self::Relative(int),
+        }
+    }
+}
+
+impl std::fmt::Display for OwnedMonitorIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_borrowed())
+    }
+}
+
+/// This enum is the owned version of [WorkspaceIdentifierWithSpecial]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OwnedWorkspaceIdentifierWithSpecial {
+    /// The workspace Id
+    Id(WorkspaceId),
+    /// The workspace relative to the current workspace
+    Relative(i32),
+    /// The workspace on the monitor relative to the current workspace
+    RelativeMonitor(i32),
+    /// The workspace on the monitor relative to the current workspace, including empty workspaces
+    RelativeMonitorIncludingEmpty(i32),
+    /// The open workspace relative to the current workspace
+    RelativeOpen(i32),
+    /// The previous Workspace
+    Previous,
+    /// The previous Workspace
+    PreviousPerMonitor,
+    /// The first available empty workspace
+    Empty(FirstEmpty),
+    /// The name of the workspace
+    Name(String),
+    /// The special workspace
+    Special(Option<String>),
+}
+
+impl OwnedWorkspaceIdentifierWithSpecial {
+    /// Borrows the identifier as a [WorkspaceIdentifierWithSpecial]
+    pub fn as_borrowed(&self) -> WorkspaceIdentifierWithSpecial<'_> {
+        use WorkspaceIdentifierWithSpecial as Borrowed;
+        match self {
+            Self::Id(id) => Borrowed::Id(*id),
+            Self::Relative(int) => Borrowed::Relative(*int),
+            Self::RelativeMonitor(int) => Borrowed::RelativeMonitor(*int),
+            Self::RelativeMonitorIncludingEmpty(int) => {
+                Borrowed::RelativeMonitorIncludingEmpty(*int)
+            }
+            Self::RelativeOpen(int) => Borrowed::RelativeOpen(*int),
+            Self::Previous => Borrowed::Previous,
+            Self::PreviousPerMonitor => Borrowed::PreviousPerMonitor,
+            Self::Empty(empty) => Borrowed::Empty(*empty),
+            Self::Name(name) => Borrowed::Name(name),
+            Self::Special(name) => Borrowed::Special(name.as_deref()),
+        }
+    }
+}
+
+impl From<WorkspaceIdentifierWithSpecial<'_>> for OwnedWorkspaceIdentifierWithSpecial {
+    fn from(ident: WorkspaceIdentifierWithSpecial<'_>) -> Self {
+        use WorkspaceIdentifierWithSpecial as Borrowed;
+        match ident {
+            Borrowed::Id(id) => Self::Id(id),
+            Borrowed::Relative(int) => Self::Relative(int),
+            Borrowed::RelativeMonitor(int) => Self::RelativeMonitor(int),
+            Borrowed::RelativeMonitorIncludingEmpty(int) => {
+                Self::RelativeMonitorIncludingEmpty(int)
+            }
+            Borrowed::RelativeOpen(int) => Self::RelativeOpen(int),
+            Borrowed::Previous => Self::Previous,
+            Borrowed::PreviousPerMonitor => Self::PreviousPerMonitor,
+            Borrowed::Empty(empty) => Self::Empty(empty),
+            Borrowed::Name(name) => Self::Name(name.to_string()),
+            Borrowed::Special(name) => Self::Special(name.map(str::to_string)),
+        }
+    }
+}
+
+impl std::fmt::Display for OwnedWorkspaceIdentifierWithSpecial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_borrowed())
+    }
+}
+
+/// This enum is the owned version of [WorkspaceIdentifier]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OwnedWorkspaceIdentifier {
+    /// The workspace Id
+    Id(WorkspaceId),
+    /// The workspace relative to the current workspace
+    Relative(i32),
+    /// The workspace on the monitor relative to the current workspace
+    RelativeMonitor(i32),
+    /// The workspace on the monitor relative to the current workspace, including empty workspaces
+    RelativeMonitorIncludingEmpty(i32),
+    /// The open workspace relative to the current workspace
+    RelativeOpen(i32),
+    /// The previous Workspace
+    Previous,
+    /// The first available empty workspace
+    Empty,
+    /// The name of the workspace
+    Name(String),
+}
+
+impl OwnedWorkspaceIdentifier {
+    /// Borrows the identifier as a [WorkspaceIdentifier]
+    pub fn as_borrowed(&self) -> WorkspaceIdentifier<'_> {
+        match self {
+            Self::Id(id) => WorkspaceIdentifier::Id(*id),
+            Self::Relative(int) => WorkspaceIdentifier::Relative(*int),
+            Self::RelativeMonitor(int) => WorkspaceIdentifier::RelativeMonitor(*int),
+            Self::RelativeMonitorIncludingEmpty(int) => {
+                WorkspaceIdentifier::RelativeMonitorIncludingEmpty(*int)
+            }
+            Self::RelativeOpen(int) => WorkspaceIdentifier::RelativeOpen(*int),
+            Self::Previous => WorkspaceIdentifier::Previous,
+            Self::Empty => WorkspaceIdentifier::Empty,
+            Self::Name(name) => WorkspaceIdentifier::Name(name),
+        }
+    }
+}
+
+impl From<WorkspaceIdentifier<'_>> for OwnedWorkspaceIdentifier {
+    fn from(ident: WorkspaceIdentifier<'_>) -> Self {
+        match ident {
+            WorkspaceIdentifier::Id(id) => Self::Id(id),
+            WorkspaceIdentifier::Relative(int) => Self::Relative(int),
+            WorkspaceIdentifier::RelativeMonitor(int) => Self::RelativeMonitor(int),
+            WorkspaceIdentifier::RelativeMonitorIncludingEmpty(int) => {
+                Self::RelativeMonitorIncludingEmpty(int)
+            }
+            WorkspaceIdentifier::RelativeOpen(int) => Self::RelativeOpen(int),
+            WorkspaceIdentifier::Previous => Self::Previous,
+            WorkspaceIdentifier::Empty => Self::Empty,
+            WorkspaceIdentifier::Name(name) => Self::Name(name.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for OwnedWorkspaceIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_borrowed())
+    }
+}
+
+/// This enum is the owned version of [WindowMove]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OwnedWindowMove {
+    /// Moves the window to a specified monitor
+    Monitor(OwnedMonitorIdentifier),
+    /// Moves the window in a specified direction
+    Direction(Direction),
+}
+
+impl OwnedWindowMove {
+    /// Borrows the param as a [WindowMove]
+    pub fn as_borrowed(&self) -> WindowMove<'_> {
+        match self {
+            Self::Monitor(mon) => WindowMove::Monitor(mon.as_borrowed()),
+            Self::Direction(dir) => WindowMove::Direction(*dir),
+        }
+    }
+}
+
+impl From<WindowMove<'_>> for OwnedWindowMove {
+    fn from(param: WindowMove<'_>) -> Self {
+        match param {
+            WindowMove::Monitor(mon) => Self::Monitor(mon.into()),
+            WindowMove::Direction(dir) => Self::Direction(dir),
+        }
+    }
+}
+
+/// This enum is the owned version of [DispatchType]
+///
+/// It can be stored, sent to other threads, serialized,
+/// and parsed from Hyprland's `name args` syntax, which is what its [Display](std::fmt::Display) impl prints
+///
+/// ```rust, no_run
+/// use hyprland::dispatch::{Dispatch, OwnedDispatchType};
+///
+/// fn main() -> hyprland::Result<()> {
+///     let dispatcher: OwnedDispatchType = "movetoworkspace 2,class:kitty".parse()?;
+///     Dispatch::call(dispatcher.as_borrowed())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OwnedDispatchType {
+    /// This lets you use dispatchers not supported by hyprland-rs yet
+    Custom(
+        /// Name of event
+        String,
+        /// Args
+        String,
+    ),
+    /// This dispatcher changes the current cursor
+    SetCursor(
+        /// The cursor theme
+        String,
+        /// The size
+        u16,
+    ),
+    /// This dispatcher executes a program
+    Exec(String),
+    /// This dispatcher passes a keybind to a window
+    Pass(OwnedWindowIdentifier),
+    /// Executes a Global Shortcut using the GlobalShortcuts portal.
+    Global(String),
+    /// This dispatcher kills the active window/client
+    KillActiveWindow,
+    /// This dispatcher closes the specified window
+    CloseWindow(OwnedWindowIdentifier),
+    /// This dispatcher changes the current workspace
+    Workspace(OwnedWorkspaceIdentifierWithSpecial),
+    /// This dispatcher moves a window (focused if not specified) to a workspace
+    MoveToWorkspace(
+        OwnedWorkspaceIdentifierWithSpecial,
+        Option<OwnedWindowIdentifier>,
+    ),
+    /// This dispatcher moves a window (focused if not specified) to a workspace, without switching to that
+    /// workspace
+    MoveToWorkspaceSilent(
+        OwnedWorkspaceIdentifierWithSpecial,
+        Option<OwnedWindowIdentifier>,
+    ),
+    /// This dispatcher floats a window (current if not specified)
+    ToggleFloating(Option<OwnedWindowIdentifier>),
+    /// This dispatcher toggles the current window fullscreen state
+    ToggleFullscreen(FullscreenType),
+    /// This dispatcher toggles the focused window’s internal
+    /// fullscreen state without altering the geometry
+    ToggleFakeFullscreen,
+    /// This dispatcher sets the DPMS status for all monitors
+    ToggleDPMS(bool, Option<String>),
+    /// This dispatcher toggles pseudo tiling for the current window
+    TogglePseudo,
+    /// This dispatcher pins the active window to all workspaces
+    TogglePin,
+    /// This dispatcher pins the specified window to all workspaces
+    TogglePinWindow(OwnedWindowIdentifier),
+    /// This dispatcher moves the window focus in a specified direction
+    MoveFocus(Direction),
+    /// This dispatcher moves the current window to a monitor or in a specified direction
+    MoveWindow(OwnedWindowMove),
+    /// This dispatcher centers the active window
+    CenterWindow,
+    /// This dispatcher resizes the active window using a [Position] enum
+    ResizeActive(Position),
+    /// This dispatcher moves the active window using a [Position] enum
+    MoveActive(Position),
+    /// This dispatcher resizes the specified window using a [Position] enum
+    ResizeWindowPixel(Position, OwnedWindowIdentifier),
+    /// This dispatcher moves the specified window using a [Position] enum
+    MoveWindowPixel(Position, OwnedWindowIdentifier),
+    /// This dispatcher cycles windows using a specified direction
+    CycleWindow(CycleDirection),
+    /// This dispatcher swaps the focused window with the window on a workspace using a specified direction
+    SwapNext(CycleDirection),
+    /// This dispatcher swaps windows using a specified direction
+    SwapWindow(Direction),
+    /// This dispatcher focuses a specified window
+    FocusWindow(OwnedWindowIdentifier),
+    /// This dispatcher focuses a specified monitor
+    FocusMonitor(OwnedMonitorIdentifier),
+    /// This dispatcher changed the split ratio
+    ChangeSplitRatio(f32),
+    /// This dispatcher toggle opacity for the current window/client
+    ToggleOpaque,
+    /// This dispatcher moves the cursor to a specified corner of a window
+    MoveCursorToCorner(Corner),
+    /// This dispatcher moves the cursor to a specified position
+    MoveCursor(i64, i64),
+    /// This dispatcher applied a option to all windows in a workspace
+    WorkspaceOption(WorkspaceOptions),
+    /// This dispatcher renames a workspace
+    RenameWorkspace(WorkspaceId, Option<String>),
+    /// This exits Hyprland **(DANGEROUS)**
+    Exit,
+    /// This dispatcher forces the renderer to reload
+    ForceRendererReload,
+    /// This dispatcher moves the current workspace to a specified monitor
+    MoveCurrentWorkspaceToMonitor(OwnedMonitorIdentifier),
+    /// This dispatcher moves a specified workspace to a specified monitor
+    MoveWorkspaceToMonitor(OwnedWorkspaceIdentifier, OwnedMonitorIdentifier),
+    /// This dispatcher swaps the active workspaces of two monitors
+    SwapActiveWorkspaces(OwnedMonitorIdentifier, OwnedMonitorIdentifier),
+    /// This dispatcher brings the active window to the top of the stack
+    BringActiveToTop,
+    /// This toggles the special workspace (AKA scratchpad)
+    ToggleSpecialWorkspace(Option<String>),
+    /// This dispatcher jump to urgent or the last window
+    FocusUrgentOrLast,
+    /// Switch focus from current to previously focused window
+    FocusCurrentOrLast,
+    /// Toggles the split (top/side) of the current window
+    ToggleSplit,
+    /// Swaps the current window with master
+    SwapWithMaster(SwapWithMasterParam),
+    /// Focuses the master window
+    FocusMaster(FocusMasterParam),
+    /// Adds a master to the master side
+    AddMaster,
+    /// Removes a master from the master side
+    RemoveMaster,
+    /// Sets the orientation for the current workspace to left
+    OrientationLeft,
+    /// Sets the orientation for the current workspace to right
+    OrientationRight,
+    /// Sets the orientation for the current workspace to top
+    OrientationTop,
+    /// Sets the orientation for the current workspace to bottom
+    OrientationBottom,
+    /// Sets the orientation for the current workspace to center
+    OrientationCenter,
+    /// Cycle to the next orientation for the current workspace (clockwise)
+    OrientationNext,
+    /// Cycle to the previous orientation for the current workspace (counter-clockwise)
+    OrientationPrev,
+    /// Toggles the current active window into a group
+    ToggleGroup,
+    /// Switches to the next window in a group.
+    ChangeGroupActive(WindowSwitchDirection),
+    /// Locks the groups
+    LockGroups(LockType),
+    /// Moves the active window into a group in a specified direction
+    MoveIntoGroup(Direction),
+    /// Moves the active window out of a group.
+    MoveOutOfGroup,
+}
+
+impl OwnedDispatchType {
+    /// Borrows the dispatcher as a [DispatchType], to call it with [Dispatch] or add it to a [Batch](crate::batch::Batch)
+    pub fn as_borrowed(&self) -> DispatchType<'_> {
+        use OwnedDispatchType::*;
+        match self {
+            Custom(name, args) => DispatchType::Custom(name, args),
+            SetCursor(theme, size) => DispatchType::SetCursor(theme, *size),
+            Exec(sh) => DispatchType::Exec(sh),
+            Pass(win) => DispatchType::Pass(win.as_borrowed()),
+            Global(name) => DispatchType::Global(name),
+            KillActiveWindow => DispatchType::KillActiveWindow,
+            CloseWindow(win) => DispatchType::CloseWindow(win.as_borrowed()),
+            Workspace(work) => DispatchType::Workspace(work.as_borrowed()),
+            MoveToWorkspace(work, win) => DispatchType::MoveToWorkspace(
+                work.as_borrowed(),
+                win.as_ref().map(OwnedWindowIdentifier::as_borrowed),
+            ),
+            MoveToWorkspaceSilent(work, win) => DispatchType::MoveToWorkspaceSilent(
+                work.as_borrowed(),
+                win.as_ref().map(OwnedWindowIdentifier::as_borrowed),
+            ),
+            ToggleFloating(win) => {
+                DispatchType::ToggleFloating(win.as_ref().map(OwnedWindowIdentifier::as_borrowed))
+            }
+            ToggleFullscreen(ftype) => DispatchType::ToggleFullscreen(*ftype),
+            ToggleFakeFullscreen => DispatchType::ToggleFakeFullscreen,
+            ToggleDPMS(stat, mon) => DispatchType::ToggleDPMS(*stat, mon.as_deref()),
+            TogglePseudo => DispatchType::TogglePseudo,
+            TogglePin => DispatchType::TogglePin,
+            TogglePinWindow(win) => DispatchType::TogglePinWindow(win.as_borrowed()),
+            MoveFocus(dir) => DispatchType::MoveFocus(*dir),
+            MoveWindow(param) => DispatchType::MoveWindow(param.as_borrowed()),
+            CenterWindow => DispatchType::CenterWindow,
+            ResizeActive(pos) => DispatchType::ResizeActive(*pos),
+            MoveActive(pos) => DispatchType::MoveActive(*pos),
+            ResizeWindowPixel(pos, win) => DispatchType::ResizeWindowPixel(*pos, win.as_borrowed()),
+            MoveWindowPixel(pos, win) => DispatchType::MoveWindowPixel(*pos, win.as_borrowed()),
+            CycleWindow(dir) => DispatchType::CycleWindow(*dir),
+            SwapNext(dir) => DispatchType::SwapNext(*dir),
+            SwapWindow(dir) => DispatchType::SwapWindow(*dir),
+            FocusWindow(win) => DispatchType::FocusWindow(win.as_borrowed()),
+            FocusMonitor(mon) => DispatchType::FocusMonitor(mon.as_borrowed()),
+            ChangeSplitRatio(ratio) => DispatchType::ChangeSplitRatio(*ratio),
+            ToggleOpaque => DispatchType::ToggleOpaque,
+            MoveCursorToCorner(corner) => DispatchType::MoveCursorToCorner(*corner),
+            MoveCursor(x, y) => DispatchType::MoveCursor(*x, *y),
+            WorkspaceOption(opt) => DispatchType::WorkspaceOption(*opt),
+            RenameWorkspace(id, name) => DispatchType::RenameWorkspace(*id, name.as_deref()),
+            Exit => DispatchType::Exit,
+            ForceRendererReload => DispatchType::ForceRendererReload,
+            MoveCurrentWorkspaceToMonitor(mon) => {
+                DispatchType::MoveCurrentWorkspaceToMonitor(mon.as_borrowed())
+            }
+            MoveWorkspaceToMonitor(work, mon) => {
+                DispatchType::MoveWorkspaceToMonitor(work.as_borrowed(), mon.as_borrowed())
+            }
+            SwapActiveWorkspaces(mon, mon2) => {
+                DispatchType::SwapActiveWorkspaces(mon.as_borrowed(), mon2.as_borrowed())
+            }
+            BringActiveToTop => DispatchType::BringActiveToTop,
+            ToggleSpecialWorkspace(name) => DispatchType::ToggleSpecialWorkspace(name.clone()),
+            FocusUrgentOrLast => DispatchType::FocusUrgentOrLast,
+            FocusCurrentOrLast => DispatchType::FocusCurrentOrLast,
+            ToggleSplit => DispatchType::ToggleSplit,
+            SwapWithMaster(param) => DispatchType::SwapWithMaster(*param),
+            FocusMaster(param) => DispatchType::FocusMaster(*param),
+            AddMaster => DispatchType::AddMaster,
+            RemoveMaster => DispatchType::RemoveMaster,
+            OrientationLeft => DispatchType::OrientationLeft,
+            OrientationRight => DispatchType::OrientationRight,
+            OrientationTop => DispatchType::OrientationTop,
+            OrientationBottom => DispatchType::OrientationBottom,
+            OrientationCenter => DispatchType::OrientationCenter,
+            OrientationNext => DispatchType::OrientationNext,
+            OrientationPrev => DispatchType::OrientationPrev,
+            ToggleGroup => DispatchType::ToggleGroup,
+            ChangeGroupActive(dir) => DispatchType::ChangeGroupActive(*dir),
+            LockGroups(how) => DispatchType::LockGroups(*how),
+            MoveIntoGroup(dir) => DispatchType::MoveIntoGroup(*dir),
+            MoveOutOfGroup => DispatchType::MoveOutOfGroup,
+        }
+    }
+}
+
+impl From<DispatchType<'_>> for OwnedDispatchType {
+    fn from(cmd: DispatchType<'_>) -> Self {
+        use DispatchType::*;
+        match cmd {
+            Custom(name, args) => Self::Custom(name.to_string(), args.to_string()),
+            SetCursor(theme, size) => Self::SetCursor(theme.to_string(), size),
+            Exec(sh) => Self::Exec(sh.to_string()),
+            Pass(win) => Self::Pass(win.into()),
+            Global(name) => Self::Global(name.to_string()),
+            KillActiveWindow => Self::KillActiveWindow,
+            CloseWindow(win) => Self::CloseWindow(win.into()),
+            Workspace(work) => Self::Workspace(work.into()),
+            MoveToWorkspace(work, win) => Self::MoveToWorkspace(work.into(), win.map(Into::into)),
+            MoveToWorkspaceSilent(work, win) => {
+                Self::MoveToWorkspaceSilent(work.into(), win.map(Into::into))
+            }
+            ToggleFloating(win) => Self::ToggleFloating(win.map(Into::into)),
+            ToggleFullscreen(ftype) => Self::ToggleFullscreen(ftype),
+            ToggleFakeFullscreen => Self::ToggleFakeFullscreen,
+            ToggleDPMS(stat, mon) => Self::ToggleDPMS(stat, mon.map(str::to_string)),
+            TogglePseudo => Self::TogglePseudo,
+            TogglePin => Self::TogglePin,
+            TogglePinWindow(win) => Self::TogglePinWindow(win.into()),
+            MoveFocus(dir) => Self::MoveFocus(dir),
+            MoveWindow(param) => Self::MoveWindow(param.into()),
+            CenterWindow => Self::CenterWindow,
+            ResizeActive(pos) => Self::ResizeActive(pos),
+            MoveActive(pos) => Self::MoveActive(pos),
+            ResizeWindowPixel(pos, win) => Self::ResizeWindowPixel(pos, win.into()),
+            MoveWindowPixel(pos, win) => Self::MoveWindowPixel(pos, win.into()),
+            CycleWindow(dir) => Self::CycleWindow(dir),
+            SwapNext(dir) => Self::SwapNext(dir),
+            SwapWindow(dir) => Self::SwapWindow(dir),
+            FocusWindow(win) => Self::FocusWindow(win.into()),
+            FocusMonitor(mon) => Self::FocusMonitor(mon.into()),
+            ChangeSplitRatio(ratio) => Self::ChangeSplitRatio(ratio),
+            ToggleOpaque => Self::ToggleOpaque,
+            MoveCursorToCorner(corner) => Self::MoveCursorToCorner(corner),
+            MoveCursor(x, y) => Self::MoveCursor(x, y),
+            WorkspaceOption(opt) => Self::WorkspaceOption(opt),
+            RenameWorkspace(id, name) => Self::RenameWorkspace(id, name.map(str::to_string)),
+            Exit => Self::Exit,
+            ForceRendererReload => Self::ForceRendererReload,
+            MoveCurrentWorkspaceToMonitor(mon) => Self::MoveCurrentWorkspaceToMonitor(mon.into()),
+            MoveWorkspaceToMonitor(work, mon) => {
+                Self::MoveWorkspaceToMonitor(work.into(), mon.into())
+            }
+            SwapActiveWorkspaces(mon, mon2) => Self::SwapActiveWorkspaces(mon.into(), mon2.into()),
+            BringActiveToTop => Self::BringActiveToTop,
+            ToggleSpecialWorkspace(name) => Self::ToggleSpecialWorkspace(name),
+            FocusUrgentOrLast => Self::FocusUrgentOrLast,
+            FocusCurrentOrLast => Self::FocusCurrentOrLast,
+            ToggleSplit => Self::ToggleSplit,
+            SwapWithMaster(param) => Self::SwapWithMaster(param),
+            FocusMaster(param) => Self::FocusMaster(param),
+            AddMaster => Self::AddMaster,
+            RemoveMaster => Self::RemoveMaster,
+            OrientationLeft => Self::OrientationLeft,
+            OrientationRight => Self::OrientationRight,
+            OrientationTop => Self::OrientationTop,
+            OrientationBottom => Self::OrientationBottom,
+            OrientationCenter => Self::OrientationCenter,
+            OrientationNext => Self::OrientationNext,
+            OrientationPrev => Self::OrientationPrev,
+            ToggleGroup => Self::ToggleGroup,
+            ChangeGroupActive(dir) => Self::ChangeGroupActive(dir),
+            LockGroups(how) => Self::LockGroups(how),
+            MoveIntoGroup(dir) => Self::MoveIntoGroup(dir),
+            MoveOutOfGroup => Self::MoveOutOfGroup,
+        }
+    }
+}
+
+impl std::fmt::Display for OwnedDispatchType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", dispatch_str(&self.as_borrowed(), " "))
+    }
+}
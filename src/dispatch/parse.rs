@@ -0,0 +1,593 @@
+use super::*;
+use std::str::FromStr;
+
+fn invalid(what: &str, value: &str) -> HyprError {
+    HyprError::InvalidDispatcher(format!("invalid {what} `{value}`"))
+}
+
+fn parse<T: FromStr>(what: &str, value: &str) -> crate::Result<T> {
+    value.parse().map_err(|_| invalid(what, value))
+}
+
+/// Parses a number with a leading `+` or `-`
+fn parse_relative(value: &str) -> Option<i32> {
+    if value.starts_with(['+', '-']) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn split<'a>(what: &str, value: &'a str, sep: char) -> crate::Result<(&'a str, &'a str)> {
+    value.split_once(sep).ok_or_else(|| invalid(what, value))
+}
+
+fn non_empty(value: &str) -> Option<&str> {
+    (!value.is_empty()).then_some(value)
+}
+
+macro_rules! impl_from_str {
+    ($name:ident { $($str:literal => $variant:ident),* $(,)? }) => {
+        impl FromStr for $name {
+            type Err = HyprError;
+
+            fn from_str(s: &str) -> crate::Result<Self> {
+                match s {
+                    $($str => Ok(Self::$variant),)*
+                    _ => Err(invalid(stringify!($name), s)),
+                }
+            }
+        }
+    };
+}
+
+impl_from_str!(Direction { "u" => Up, "d" => Down, "r" => Right, "l" => Left });
+impl_from_str!(FullscreenType { "0" => Real, "1" => Maximize, "" => NoParam });
+impl_from_str!(CycleDirection { "" => Next, "next" => Next, "prev" => Previous });
+impl_from_str!(WindowSwitchDirection { "b" => Back, "f" => Forward });
+impl_from_str!(WorkspaceOptions { "allpseudo" => AllPseudo, "allfloat" => AllFloat });
+impl_from_str!(LockType { "lock" => Lock, "unlock" => Unlock, "toggle" => ToggleLock });
+impl_from_str!(SwapWithMasterParam { "master" => Master, "child" => Child, "auto" => Auto });
+impl_from_str!(FocusMasterParam { "master" => Master, "auto" => Auto });
+impl_from_str!(Corner { "0" => BottomLeft, "1" => BottomRight, "2" => TopRight, "3" => TopLeft });
+
+impl FromStr for Position {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let (exact, values) = match s.strip_prefix("exact ") {
+            Some(values) => (true, values),
+            None => (false, s),
+        };
+        let (x, y) = split("Position", values, ' ')?;
+        let (x, y) = (parse("Position", x)?, parse("Position", y)?);
+        Ok(if exact {
+            Position::Exact(x, y)
+        } else {
+            Position::Delta(x, y)
+        })
+    }
+}
+
+impl FromStr for OwnedWindowIdentifier {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let Some((kind, value)) = s.split_once(':') else {
+            return Ok(Self::ClassRegularExpression(s.to_string()));
+        };
+        match kind {
+            "address" => Ok(Self::Address(Address::new(value))),
+            "class" => Ok(Self::ClassRegularExpression(value.to_string())),
+            "title" => Ok(Self::Title(value.to_string())),
+            "pid" => Ok(Self::ProcessId(parse("window pid", value)?)),
+            _ => Err(invalid("window identifier", s)),
+        }
+    }
+}
+
+impl FromStr for OwnedMonitorIdentifier {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        if s == "current" {
+            Ok(Self::Current)
+        } else if let Ok(dir) = s.parse() {
+            Ok(Self::Direction(dir))
+        } else if let Some(int) = parse_relative(s) {
+            Ok(Self::Relative(int))
+        } else if let Ok(id) = s.parse() {
+            Ok(Self::Id(id))
+        } else if s.is_empty() {
+            Err(invalid("monitor identifier", s))
+        } else {
+            Ok(Self::Name(s.to_string()))
+        }
+    }
+}
+
+/// Parses the identifiers both workspace identifier types share
+fn parse_workspace(s: &str) -> Option<OwnedWorkspaceIdentifierWithSpecial> {
+    use OwnedWorkspaceIdentifierWithSpecial::*;
+    if s == "previous" {
+        Some(Previous)
+    } else if let Some(name) = s.strip_prefix("name:") {
+        Some(Name(name.to_string()))
+    } else if let Some(int) = s.strip_prefix('m').and_then(parse_relative) {
+        Some(RelativeMonitor(int))
+    } else if let Some(int) = s.strip_prefix('r').and_then(parse_relative) {
+        Some(RelativeMonitorIncludingEmpty(int))
+    } else if let Some(int) = s.strip_prefix('e').and_then(parse_relative) {
+        Some(RelativeOpen(int))
+    } else if let Some(int) = parse_relative(s) {
+        Some(Relative(int))
+    } else {
+        s.parse().ok().map(Id)
+    }
+}
+
+impl FromStr for OwnedWorkspaceIdentifierWithSpecial {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        if s == "previous_per_monitor" {
+            return Ok(Self::PreviousPerMonitor);
+        }
+        if s == "special" {
+            return Ok(Self::Special(None));
+        }
+        if let Some(name) = s.strip_prefix("special:") {
+            return Ok(Self::Special(Some(name.to_string())));
+        }
+        if let Some(flags) = s.strip_prefix("empty") {
+            if flags.chars().all(|c| c == 'm' || c == 'n') {
+                return Ok(Self::Empty(FirstEmpty {
+                    on_monitor: flags.contains('m'),
+                    next: flags.contains('n'),
+                }));
+            }
+        }
+        parse_workspace(s).ok_or_else(|| invalid("workspace identifier", s))
+    }
+}
+
+impl FromStr for OwnedWorkspaceIdentifier {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        use OwnedWorkspaceIdentifierWithSpecial as Special;
+        if s == "empty" {
+            return Ok(Self::Empty);
+        }
+        Ok(match parse_workspace(s) {
+            Some(Special::Id(id)) => Self::Id(id),
+            Some(Special::Relative(int)) => Self::Relative(int),
+            Some(Special::RelativeMonitor(int)) => Self::RelativeMonitor(int),
+            Some(Special::RelativeMonitorIncludingEmpty(int)) => {
+                Self::RelativeMonitorIncludingEmpty(int)
+            }
+            Some(Special::RelativeOpen(int)) => Self::RelativeOpen(int),
+            Some(Special::Previous) => Self::Previous,
+            Some(Special::Name(name)) => Self::Name(name),
+            _ => return Err(invalid("workspace identifier", s)),
+        })
+    }
+}
+
+impl FromStr for OwnedWindowMove {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match s.strip_prefix("mon:") {
+            Some(mon) => Ok(Self::Monitor(mon.parse()?)),
+            None => Ok(Self::Direction(s.parse()?)),
+        }
+    }
+}
+
+/// Parses a workspace, optionally followed by a comma and a window
+fn parse_workspace_window(
+    args: &str,
+) -> crate::Result<(
+    OwnedWorkspaceIdentifierWithSpecial,
+    Option<OwnedWindowIdentifier>,
+)> {
+    match args.split_once(',') {
+        Some((work, win)) => Ok((work.parse()?, Some(win.parse()?))),
+        None => Ok((args.parse()?, None)),
+    }
+}
+
+/// Parses a position followed by a comma and a window
+fn parse_position_window(args: &str) -> crate::Result<(Position, OwnedWindowIdentifier)> {
+    let (pos, win) = split("window position", args, ',')?;
+    Ok((pos.parse()?, win.parse()?))
+}
+
+impl OwnedDispatchType {
+    /// This function parses a dispatcher from its name and args,
+    /// like the `dispatcher` and `arg` of a bind
+    ///
+    /// Unknown dispatchers, and known ones whose args can't be represented
+    /// by their typed variant (like `resizeactive 10% 0`),
+    /// are returned as [OwnedDispatchType::Custom]
+    pub fn from_parts(name: &str, args: &str) -> crate::Result<Self> {
+        if name.is_empty() {
+            return Err(invalid("dispatcher", name));
+        }
+        Ok(Self::parse_typed(name, args)
+            .unwrap_or_else(|_| OwnedDispatchType::Custom(name.to_string(), args.to_string())))
+    }
+
+    /// Parses a dispatcher into its typed variant, failing if the args don't fit it
+    fn parse_typed(name: &str, args: &str) -> crate::Result<Self> {
+        use OwnedDispatchType::*;
+        let without_args = |dispatcher: OwnedDispatchType| {
+            if args.trim().is_empty() {
+                Ok(dispatcher)
+            } else {
+                Err(invalid(&format!("{name} args"), args))
+            }
+        };
+        match name {
+            "setcursor" => {
+                let (theme, size) = args
+                    .rsplit_once(' ')
+                    .ok_or_else(|| invalid("setcursor args", args))?;
+                Ok(SetCursor(theme.to_string(), parse("cursor size", size)?))
+            }
+            "exec" => Ok(Exec(args.to_string())),
+            "pass" => Ok(Pass(args.parse()?)),
+            "global" => Ok(Global(args.to_string())),
+            "killactive" => without_args(KillActiveWindow),
+            "closewindow" => Ok(CloseWindow(args.parse()?)),
+            "workspace" => Ok(Workspace(args.parse()?)),
+            "movetoworkspace" => {
+                let (work, win) = parse_workspace_window(args)?;
+                Ok(MoveToWorkspace(work, win))
+            }
+            "movetoworkspacesilent" => {
+                let (work, win) = parse_workspace_window(args)?;
+                Ok(MoveToWorkspaceSilent(work, win))
+            }
+            "togglefloating" => match non_empty(args) {
+                Some(win) => Ok(ToggleFloating(Some(win.parse()?))),
+                None => Ok(ToggleFloating(None)),
+            },
+            "fullscreen" => Ok(ToggleFullscreen(args.parse()?)),
+            "fakefullscreen" => without_args(ToggleFakeFullscreen),
+            "dpms" => {
+                let (stat, mon) = args.split_once(' ').unwrap_or((args, ""));
+                let stat = match stat {
+                    "on" => true,
+                    "off" => false,
+                    _ => return Err(invalid("dpms status", stat)),
+                };
+                Ok(ToggleDPMS(stat, non_empty(mon).map(str::to_string)))
+            }
+            "pseudo" => without_args(TogglePseudo),
+            "pin" => match non_empty(args) {
+                Some(win) => Ok(TogglePinWindow(win.parse()?)),
+                None => Ok(TogglePin),
+            },
+            "movefocus" => Ok(MoveFocus(args.parse()?)),
+            "movewindow" => Ok(MoveWindow(args.parse()?)),
+            "centerwindow" => without_args(CenterWindow),
+            "resizeactive" => Ok(ResizeActive(args.parse()?)),
+            "moveactive" => Ok(MoveActive(args.parse()?)),
+            "resizewindowpixel" => {
+                let (pos, win) = parse_position_window(args)?;
+                Ok(ResizeWindowPixel(pos, win))
+            }
+            "movewindowpixel" => {
+                let (pos, win) = parse_position_window(args)?;
+                Ok(MoveWindowPixel(pos, win))
+            }
+            "cyclenext" => Ok(CycleWindow(args.parse()?)),
+            "swapnext" => Ok(SwapNext(args.parse()?)),
+            "swapwindow" => Ok(SwapWindow(args.parse()?)),
+            "focuswindow" => Ok(FocusWindow(args.parse()?)),
+            "focusmonitor" => Ok(FocusMonitor(args.parse()?)),
+            "splitratio" => Ok(ChangeSplitRatio(parse("split ratio", args)?)),
+            "toggleopaque" => without_args(ToggleOpaque),
+            "movecursortocorner" => Ok(MoveCursorToCorner(args.parse()?)),
+            "movecursor" => {
+                let (x, y) = split("cursor position", args, ' ')?;
+                Ok(MoveCursor(
+                    parse("cursor position", x)?,
+                    parse("cursor position", y)?,
+                ))
+            }
+            "workspaceopt" => Ok(WorkspaceOption(args.parse()?)),
+            "renameworkspace" => {
+                let (id, name) = args.split_once(' ').unwrap_or((args, ""));
+                // renaming a workspace to its id resets the name
+                let name = non_empty(name).filter(|name| *name != id);
+                Ok(RenameWorkspace(
+                    parse("workspace id", id)?,
+                    name.map(str::to_string),
+                ))
+            }
+            "exit" => without_args(Exit),
+            "forcerendererreload" => without_args(ForceRendererReload),
+            "movecurrentworkspacetomonitor" => Ok(MoveCurrentWorkspaceToMonitor(args.parse()?)),
+            "moveworkspacetomonitor" => {
+                let (work, mon) = args
+                    .rsplit_once(' ')
+                    .ok_or_else(|| invalid("moveworkspacetomonitor args", args))?;
+                Ok(MoveWorkspaceToMonitor(work.parse()?, mon.parse()?))
+            }
+            "swapactiveworkspaces" => {
+                let (mon, mon2) = split("swapactiveworkspaces args", args, ' ')?;
+                Ok(SwapActiveWorkspaces(mon.parse()?, mon2.parse()?))
+            }
+            "bringactivetotop" => without_args(BringActiveToTop),
+            "togglespecialworkspace" => {
+                Ok(ToggleSpecialWorkspace(non_empty(args).map(str::to_string)))
+            }
+            "focusurgentorlast" => without_args(FocusUrgentOrLast),
+            "focuscurrentorlast" => without_args(FocusCurrentOrLast),
+            "togglesplit" => without_args(ToggleSplit),
+            "swapwithmaster" => Ok(SwapWithMaster(args.parse()?)),
+            "focusmaster" => Ok(FocusMaster(args.parse()?)),
+            "addmaster" => without_args(AddMaster),
+            "removemaster" => without_args(RemoveMaster),
+            "orientationleft" => without_args(OrientationLeft),
+            "orientationright" => without_args(OrientationRight),
+            "orientationtop" => without_args(OrientationTop),
+            "orientationbottom" => without_args(OrientationBottom),
+            "orientationcenter" => without_args(OrientationCenter),
+            "orientationnext" => without_args(OrientationNext),
+            "orientationprev" => without_args(OrientationPrev),
+            "togglegroup" => without_args(ToggleGroup),
+            "changegroupactive" => Ok(ChangeGroupActive(args.parse()?)),
+            "lockgroups" => Ok(LockGroups(args.parse()?)),
+            "moveintogroup" => Ok(MoveIntoGroup(args.parse()?)),
+            "moveoutofgroup" => without_args(MoveOutOfGroup),
+            _ => Ok(Custom(name.to_string(), args.to_string())),
+        }
+    }
+}
+
+/// Parses Hyprland's `name args` syntax, the inverse of the [Display](std::fmt::Display) impl
+impl FromStr for OwnedDispatchType {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let (name, args) = s.split_once(' ').unwrap_or((s, ""));
+        Self::from_parts(name, args)
+    }
+}
+
+#[cfg(feature = "data")]
+impl crate::data::Bind {
+    /// This method parses the dispatcher the bind executes
+    pub fn dispatch_type(&self) -> crate::Result<OwnedDispatchType> {
+        OwnedDispatchType::from_parts(&self.dispatcher, &self.arg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use OwnedDispatchType::*;
+
+    fn window() -> OwnedWindowIdentifier {
+        OwnedWindowIdentifier::Address(Address::new("0x55d1c8a0e6f0"))
+    }
+
+    fn monitor() -> OwnedMonitorIdentifier {
+        OwnedMonitorIdentifier::Name("DP-1".into())
+    }
+
+    /// One of every variant
+    fn dispatchers() -> Vec<OwnedDispatchType> {
+        vec![
+            Custom("hyprexpo:expo".into(), "toggle".into()),
+            Custom("nop".into(), "".into()),
+            SetCursor("Bibata Modern".into(), 24),
+            Exec("kitty --class float, term".into()),
+            Pass(OwnedWindowIdentifier::ClassRegularExpression(
+                "^(obs)$".into(),
+            )),
+            Global("obs:record".into()),
+            KillActiveWindow,
+            CloseWindow(OwnedWindowIdentifier::Title("vim, a file".into())),
+            Workspace(OwnedWorkspaceIdentifierWithSpecial::Id(3)),
+            MoveToWorkspace(OwnedWorkspaceIdentifierWithSpecial::Relative(-1), None),
+            MoveToWorkspace(
+                OwnedWorkspaceIdentifierWithSpecial::Special(Some("scratch".into())),
+                Some(window()),
+            ),
+            MoveToWorkspaceSilent(
+                OwnedWorkspaceIdentifierWithSpecial::Name("web".into()),
+                Some(OwnedWindowIdentifier::ProcessId(4242)),
+            ),
+            ToggleFloating(None),
+            ToggleFloating(Some(window())),
+            ToggleFullscreen(FullscreenType::Real),
+            ToggleFullscreen(FullscreenType::Maximize),
+            ToggleFullscreen(FullscreenType::NoParam),
+            ToggleFakeFullscreen,
+            ToggleDPMS(true, None),
+            ToggleDPMS(false, Some("DP-1".into())),
+            TogglePseudo,
+            TogglePin,
+            TogglePinWindow(window()),
+            MoveFocus(Direction::Left),
+            MoveWindow(OwnedWindowMove::Direction(Direction::Up)),
+            MoveWindow(OwnedWindowMove::Monitor(OwnedMonitorIdentifier::Relative(
+                1,
+            ))),
+            CenterWindow,
+            ResizeActive(Position::Delta(-10, 20)),
+            MoveActive(Position::Exact(100, 200)),
+            ResizeWindowPixel(Position::Exact(640, 480), window()),
+            MoveWindowPixel(Position::Delta(5, -5), window()),
+            CycleWindow(CycleDirection::Next),
+            CycleWindow(CycleDirection::Previous),
+            SwapNext(CycleDirection::Previous),
+            SwapWindow(Direction::Right),
+            FocusWindow(OwnedWindowIdentifier::ClassRegularExpression(
+                "firefox".into(),
+            )),
+            FocusMonitor(OwnedMonitorIdentifier::Current),
+            FocusMonitor(OwnedMonitorIdentifier::Id(1)),
+            ChangeSplitRatio(-0.25),
+            ToggleOpaque,
+            MoveCursorToCorner(Corner::TopLeft),
+            MoveCursor(1280, -720),
+            WorkspaceOption(WorkspaceOptions::AllPseudo),
+            WorkspaceOption(WorkspaceOptions::AllFloat),
+            RenameWorkspace(2, Some("code".into())),
+            RenameWorkspace(2, None),
+            Exit,
+            ForceRendererReload,
+            MoveCurrentWorkspaceToMonitor(OwnedMonitorIdentifier::Direction(Direction::Down)),
+            MoveWorkspaceToMonitor(OwnedWorkspaceIdentifier::Name("chat".into()), monitor()),
+            MoveWorkspaceToMonitor(OwnedWorkspaceIdentifier::Empty, monitor()),
+            SwapActiveWorkspaces(monitor(), OwnedMonitorIdentifier::Name("HDMI-A-1".into())),
+            BringActiveToTop,
+            ToggleSpecialWorkspace(None),
+            ToggleSpecialWorkspace(Some("scratch".into())),
+            FocusUrgentOrLast,
+            FocusCurrentOrLast,
+            ToggleSplit,
+            SwapWithMaster(SwapWithMasterParam::Child),
+            FocusMaster(FocusMasterParam::Auto),
+            AddMaster,
+            RemoveMaster,
+            OrientationLeft,
+            OrientationRight,
+            OrientationTop,
+            OrientationBottom,
+            OrientationCenter,
+            OrientationNext,
+            OrientationPrev,
+            ToggleGroup,
+            ChangeGroupActive(WindowSwitchDirection::Back),
+            LockGroups(LockType::ToggleLock),
+            MoveIntoGroup(Direction::Down),
+            MoveOutOfGroup,
+        ]
+    }
+
+    #[test]
+    fn test_round_trip() -> crate::Result<()> {
+        for dispatcher in dispatchers() {
+            let string = dispatcher.to_string();
+            assert_eq!(string.parse::<OwnedDispatchType>()?, dispatcher, "{string}");
+
+            let json = serde_json::to_string(&dispatcher)?;
+            assert_eq!(
+                serde_json::from_str::<OwnedDispatchType>(&json)?,
+                dispatcher,
+                "{json}"
+            );
+
+            let owned: OwnedDispatchType = dispatcher.as_borrowed().into();
+            assert_eq!(owned, dispatcher);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_workspace_identifiers() -> crate::Result<()> {
+        use OwnedWorkspaceIdentifierWithSpecial::*;
+        let workspaces = [
+            Id(1),
+            Relative(0),
+            Relative(2),
+            RelativeMonitor(0),
+            RelativeMonitor(-1),
+            RelativeMonitorIncludingEmpty(3),
+            RelativeOpen(-2),
+            Previous,
+            PreviousPerMonitor,
+            Empty(FirstEmpty {
+                on_monitor: false,
+                next: false,
+            }),
+            Empty(FirstEmpty {
+                on_monitor: true,
+                next: true,
+            }),
+            Name("my workspace".into()),
+            Special(None),
+            Special(Some("scratch".into())),
+        ];
+        for workspace in workspaces {
+            assert_eq!(
+                workspace
+                    .to_string()
+                    .parse::<OwnedWorkspaceIdentifierWithSpecial>()?,
+                workspace
+            );
+        }
+
+        let workspaces = [
+            OwnedWorkspaceIdentifier::Id(1),
+            OwnedWorkspaceIdentifier::Relative(1),
+            OwnedWorkspaceIdentifier::RelativeMonitor(1),
+            OwnedWorkspaceIdentifier::RelativeMonitorIncludingEmpty(1),
+            OwnedWorkspaceIdentifier::RelativeOpen(1),
+            OwnedWorkspaceIdentifier::Previous,
+            OwnedWorkspaceIdentifier::Empty,
+            OwnedWorkspaceIdentifier::Name("web".into()),
+        ];
+        for workspace in workspaces {
+            assert_eq!(
+                workspace.to_string().parse::<OwnedWorkspaceIdentifier>()?,
+                workspace
+            );
+        }
+        assert!("special".parse::<OwnedWorkspaceIdentifier>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_untyped_fallback() -> crate::Result<()> {
+        assert!(matches!(
+            "".parse::<OwnedDispatchType>(),
+            Err(HyprError::InvalidDispatcher(_))
+        ));
+        let untyped = [
+            "workspace",
+            "workspace nope",
+            "movefocus x",
+            "fullscreen 2",
+            "dpms maybe",
+            "killactive now",
+            "movecursor 1",
+            "focuswindow floating:1",
+            "resizeactive 10% 0",
+        ];
+        for dispatcher in untyped {
+            let (name, args) = dispatcher.split_once(' ').unwrap_or((dispatcher, ""));
+            assert_eq!(
+                dispatcher.parse::<OwnedDispatchType>()?,
+                Custom(name.into(), args.into()),
+                "{dispatcher}"
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "data")]
+    #[test]
+    fn test_bind() -> crate::Result<()> {
+        use crate::testing::fixtures;
+        let binds: Vec<crate::data::Bind> = serde_json::from_str(fixtures::BINDS)?;
+        assert_eq!(binds[0].dispatch_type()?, Exec("kitty".into()));
+        let bind = crate::data::Bind {
+            dispatcher: "resizeactive".into(),
+            arg: "10% 0".into(),
+            ..binds[0].clone()
+        };
+        assert_eq!(
+            bind.dispatch_type()?,
+            Custom("resizeactive".into(), "10% 0".into())
+        );
+        Ok(())
+    }
+}
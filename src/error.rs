@@ -15,6 +15,41 @@ pub enum HyprError {
     Hyprpaper(crate::hyprpaper::Error),
     /// Internal Hyprland error
     Internal(String),
+    /// An event line was longer than the maximum length in bytes
+    #[display("An event line exceeded the maximum length of {_0} bytes")]
+    EventLineTooLong(usize),
+    /// A dispatcher could not be parsed
+    #[cfg(feature = "dispatch")]
+    #[display("Failed to parse dispatcher: {_0}")]
+    InvalidDispatcher(String),
+    /// A regular expression failed to compile
+    #[cfg(feature = "query")]
+    #[display("Invalid regular expression: {_0}")]
+    InvalidRegex(regex::Error),
+    /// A config option had a value of the wrong type or out of its range
+    #[cfg(feature = "keyword")]
+    #[display("Invalid option value: {_0}")]
+    InvalidOptionValue(String),
+    /// Keywords could not be rolled back after one of them failed to be set
+    #[cfg(feature = "keyword")]
+    #[display("{error}, and rolling back the keywords failed: {rollback}")]
+    RollbackFailed {
+        /// The error that made the keywords roll back
+        error: Box<HyprError>,
+        /// The error rolling back failed with
+        rollback: Box<HyprError>,
+    },
+    /// A config file could not be parsed
+    #[cfg(feature = "config")]
+    #[display("Failed to parse config: {_0}")]
+    ConfigParse(crate::config::hyprlang::ParseError),
+    /// A rule could not be built
+    #[cfg(feature = "config")]
+    #[display("Invalid rule: {_0}")]
+    InvalidRule(String),
+    /// A color or gradient could not be parsed
+    #[display("Invalid color: {_0}")]
+    InvalidColor(String),
     /// Error that occurs for other reasons. Avoid using this.
     Other(String),
 }
@@ -33,6 +68,28 @@ impl HyprError {
             #[cfg(feature = "hyprpaper")]
             Self::Hyprpaper(_) => Err(self),
             Self::Internal(s) => Ok(Self::Internal(s.clone())),
+            Self::EventLineTooLong(n) => Ok(Self::EventLineTooLong(*n)),
+            #[cfg(feature = "dispatch")]
+            Self::InvalidDispatcher(s) => Ok(Self::InvalidDispatcher(s.clone())),
+            #[cfg(feature = "query")]
+            Self::InvalidRegex(e) => Ok(Self::InvalidRegex(e.clone())),
+            #[cfg(feature = "keyword")]
+            Self::InvalidOptionValue(s) => Ok(Self::InvalidOptionValue(s.clone())),
+            #[cfg(feature = "keyword")]
+            Self::RollbackFailed { error, rollback } => {
+                match (error.try_as_cloned(), rollback.try_as_cloned()) {
+                    (Ok(error), Ok(rollback)) => Ok(Self::RollbackFailed {
+                        error: Box::new(error),
+                        rollback: Box::new(rollback),
+                    }),
+                    _ => Err(self),
+                }
+            }
+            #[cfg(feature = "config")]
+            Self::ConfigParse(e) => Ok(Self::ConfigParse(e.clone())),
+            #[cfg(feature = "config")]
+            Self::InvalidRule(s) => Ok(Self::InvalidRule(s.clone())),
+            Self::InvalidColor(s) => Ok(Self::InvalidColor(s.clone())),
             Self::Other(s) => Ok(Self::Other(s.clone())),
         }
     }
@@ -61,6 +118,13 @@ impl From<std::string::FromUtf8Error> for HyprError {
     }
 }
 
+#[cfg(feature = "query")]
+impl From<regex::Error> for HyprError {
+    fn from(error: regex::Error) -> Self {
+        HyprError::InvalidRegex(error)
+    }
+}
+
 impl error::Error for HyprError {}
 
 /// Internal macro to return a Hyprland error
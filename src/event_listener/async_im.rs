@@ -1,5 +1,6 @@
 use super::*;
 use crate::default_instance;
+use crate::error::HyprError;
 use crate::instance::Instance;
 
 /// This struct is used for adding event handlers and executing them on events
@@ -21,6 +22,9 @@ use crate::instance::Instance;
 /// ```
 pub struct AsyncEventListener {
     pub(crate) events: AsyncEvents,
+    pub(crate) reconnect: Option<ReconnectPolicy>,
+    pub(crate) remover: HandlerRemover,
+    pub(crate) error_policy: ErrorPolicy,
 }
 
 impl Default for AsyncEventListener {
@@ -39,9 +43,47 @@ impl AsyncEventListener {
     pub fn new() -> Self {
         Self {
             events: create_events_async(),
+            reconnect: None,
+            remover: HandlerRemover::default(),
+            error_policy: ErrorPolicy::default(),
         }
     }
 
+    /// This method makes the event listener reconnect when the event socket closes,
+    /// instead of returning
+    ///
+    /// ```rust
+    /// use hyprland::event_listener::{AsyncEventListener, ReconnectPolicy};
+    /// let mut listener = AsyncEventListener::new();
+    /// listener.set_reconnect_policy(ReconnectPolicy::default());
+    /// ```
+    pub fn set_reconnect_policy(&mut self, policy: ReconnectPolicy) {
+        self.reconnect = Some(policy);
+    }
+
+    /// This method sets what happens when a handler returns an error,
+    /// which by default stops the listener
+    ///
+    /// ```rust
+    /// use hyprland::data::Monitors;
+    /// use hyprland::event_listener::{AsyncEventListener, ErrorPolicy};
+    /// use hyprland::prelude::*;
+    /// let mut listener = AsyncEventListener::new();
+    /// listener.set_error_policy(ErrorPolicy::Log);
+    /// listener.add_monitor_added_handler(|_| async {
+    ///     println!("now {} monitors", Monitors::get_async().await?.iter().count());
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn set_error_policy(&mut self, policy: ErrorPolicy) {
+        self.error_policy = policy;
+    }
+
+    /// This method returns a [HandlerRemover], which removes handlers while the listener runs
+    pub fn handler_remover(&self) -> HandlerRemover {
+        self.remover.clone()
+    }
+
     /// This method starts the event listener (async)
     ///
     /// This should be ran after all of your handlers are defined
@@ -78,20 +120,27 @@ impl AsyncEventListener {
         &mut self,
         instance: &Instance,
     ) -> crate::Result<()> {
-        use crate::async_import::*;
-
+        let policy = self.reconnect.clone();
+        let mut instance = instance.clone();
         let mut stream = instance.get_event_stream_async().await?;
         let mut active_windows = vec![];
+        let mut reader = EventLineReader::default();
         loop {
-            let mut buffer = [0; 4096];
-            let bytes_read = stream.read(&mut buffer).await?;
-            if bytes_read == 0 {
-                // If no bytes were read, we can assume the stream is closed
-                break;
-            }
-            let buf = &buffer[..bytes_read];
-            let string = String::from_utf8(buf.to_vec())?;
-            let parsed: Vec<Event> = event_parser(string)?;
+            let lines = match (reader.read_lines_async(&mut stream).await, &policy) {
+                (Ok(Some(lines)), _) => lines,
+                (Ok(None) | Err(HyprError::IoError(_)), Some(policy)) => {
+                    self.event_executor_async(Event::Disconnected).await?;
+                    stream = policy.reconnect_async(&mut instance).await?;
+                    active_windows.clear();
+                    reader = EventLineReader::default();
+                    self.event_executor_async(Event::Reconnected(instance.clone()))
+                        .await?;
+                    continue;
+                }
+                (Ok(None), None) => break,
+                (Err(e), _) => return Err(e),
+            };
+            let parsed: Vec<Event> = event_parser(lines)?;
             for event in parsed {
                 self.event_primer_exec_async(event, &mut active_windows)
                     .await?;
@@ -0,0 +1,226 @@
+use super::*;
+use crate::shared::{Address, WorkspaceId};
+use std::collections::HashSet;
+
+/// This struct holds which events to keep, by their kind and what they are about.
+/// Every criterion that is set has to match, and events that don't carry what a criterion
+/// checks, like a window address, don't match it
+///
+/// ```rust
+/// use hyprland::event_listener::{EventFilter, EventKind};
+/// let filter = EventFilter::new()
+///     .kinds([EventKind::WindowOpened, EventKind::WindowMoved])
+///     .workspace("2");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    kinds: Option<HashSet<EventKind>>,
+    window: Option<Address>,
+    workspace: Option<String>,
+    workspace_id: Option<WorkspaceId>,
+    monitor: Option<String>,
+}
+
+impl EventFilter {
+    /// Creates a filter which keeps every event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps only events of these kinds, in addition to previously added kinds.
+    /// Lines of other kinds are skipped before their arguments are parsed
+    pub fn kinds<I: IntoIterator<Item = EventKind>>(mut self, kinds: I) -> Self {
+        self.kinds.get_or_insert_with(HashSet::new).extend(kinds);
+        self
+    }
+
+    /// Keeps only events about the window
+    pub fn window(mut self, address: Address) -> Self {
+        self.window = Some(address);
+        self
+    }
+
+    /// Keeps only events about the workspace with the name, like `2` or `special:scratch`
+    pub fn workspace(mut self, name: impl Into<String>) -> Self {
+        self.workspace = Some(name.into());
+        self
+    }
+
+    /// Keeps only events about the workspace with the id
+    pub fn workspace_id(mut self, id: WorkspaceId) -> Self {
+        self.workspace_id = Some(id);
+        self
+    }
+
+    /// Keeps only events about the monitor with the name
+    pub fn monitor(mut self, name: impl Into<String>) -> Self {
+        self.monitor = Some(name.into());
+        self
+    }
+
+    /// Returns if the event passes the filter
+    pub fn matches(&self, event: &Event) -> bool {
+        self.kinds
+            .as_ref()
+            .map_or(true, |kinds| kinds.contains(&event.kind()))
+            && self
+                .window
+                .as_ref()
+                .map_or(true, |window| windows(event).contains(&window))
+            && self.workspace.as_ref().map_or(true, |name| {
+                workspace(event)
+                    .1
+                    .is_some_and(|event_name| event_name == *name)
+            })
+            && self
+                .workspace_id
+                .map_or(true, |id| workspace(event).0 == Some(id))
+            && self
+                .monitor
+                .as_ref()
+                .map_or(true, |name| monitor(event) == Some(name.as_str()))
+    }
+
+    /// Returns if lines of the type can produce an event that passes the kinds
+    pub(crate) fn accepts(&self, event_type: Option<ParsedEventType>) -> bool {
+        let Some(kinds) = &self.kinds else {
+            return true;
+        };
+        match event_type {
+            Some(event_type) => event_type.kinds().iter().any(|kind| kinds.contains(kind)),
+            None => kinds.contains(&EventKind::Unknown),
+        }
+    }
+}
+
+/// Returns the windows the event is about
+fn windows(event: &Event) -> Vec<&Address> {
+    use Event::*;
+    match event {
+        ActiveWindowChanged(Some(data)) => vec![&data.address],
+        WindowOpened(data) => vec![&data.window_address],
+        WindowMoved(data) => vec![&data.window_address],
+        WindowMovedV1(data) => vec![&data.window_address],
+        FloatStateChanged(data) => vec![&data.address],
+        WindowTitleChanged(data) => vec![&data.address],
+        WindowPinned(data) => vec![&data.address],
+        WindowMinimized(data) => vec![&data.address],
+        GroupToggled(data) => data.window_addresses.iter().collect(),
+        WindowClosed(address)
+        | UrgentStateChanged(address)
+        | WindowMovedIntoGroup(address)
+        | WindowMovedOutOfGroup(address)
+        | WindowTitleChangedV1(address)
+        | Bell(Some(address)) => vec![address],
+        _ => vec![],
+    }
+}
+
+/// Returns the id and name of the workspace the event is about, if it has them
+fn workspace(event: &Event) -> (Option<WorkspaceId>, Option<String>) {
+    use Event::*;
+    match event {
+        WorkspaceChanged(data) | WorkspaceAdded(data) | WorkspaceDeleted(data) => {
+            (Some(data.id), Some(data.name.to_string()))
+        }
+        WorkspaceMoved(data) => (Some(data.id), Some(data.name.to_string())),
+        WorkspaceRenamed(data) => (Some(data.id), Some(data.name.clone())),
+        WindowMoved(data) => (
+            Some(data.workspace_id),
+            Some(data.workspace_name.to_string()),
+        ),
+        ActiveSpecialChanged(data) => (data.workspace_id, data.workspace_name.clone()),
+        ActiveMonitorChangedV2(data) => (data.workspace_id, None),
+        ActiveMonitorChanged(data) => (None, data.workspace_name.as_ref().map(String::from)),
+        WindowOpened(data) => (None, Some(data.workspace_name.clone())),
+        ChangedSpecial(data) => (None, Some(data.workspace_name.clone())),
+        WorkspaceMovedV1(data) => (None, Some(data.name.to_string())),
+        WindowMovedV1(data) => (None, Some(data.workspace_name.to_string())),
+        WorkspaceChangedV1(name) | WorkspaceAddedV1(name) | WorkspaceDeletedV1(name) => {
+            (None, Some(name.to_string()))
+        }
+        _ => (None, None),
+    }
+}
+
+/// Returns the name of the monitor the event is about
+fn monitor(event: &Event) -> Option<&str> {
+    use Event::*;
+    match event {
+        ActiveMonitorChanged(MonitorEventData { monitor_name, .. })
+        | ChangedSpecial(ChangedSpecialEventData { monitor_name, .. })
+        | ActiveMonitorChangedV2(MonitorV2EventData { monitor_name, .. })
+        | ActiveSpecialChanged(ActiveSpecialEventData { monitor_name, .. }) => Some(monitor_name),
+        WorkspaceMoved(WorkspaceMovedEventData { monitor, .. })
+        | WorkspaceMovedV1(WorkspaceMovedV1EventData { monitor, .. }) => Some(monitor),
+        MonitorAdded(data) | MonitorRemovedV2(data) => Some(&data.name),
+        SpecialRemoved(name) | MonitorRemoved(name) | MonitorAddedV1(name) => Some(name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::WorkspaceType;
+
+    #[test]
+    fn test_filter() -> crate::Result<()> {
+        let lines = "workspacev2>>oops,2\nopenwindow>>55d1c8a0e6f0,2,kitty,~\nclosewindow>>1234\n";
+        assert!(event_parser(lines.into()).is_err());
+
+        // the broken workspace line is skipped before its id is parsed
+        let filter = EventFilter::new().kinds([EventKind::WindowOpened, EventKind::WindowClosed]);
+        let events = event_parser_filtered(lines.into(), &filter)?;
+        assert_eq!(
+            events.iter().map(Event::kind).collect::<Vec<_>>(),
+            [EventKind::WindowOpened, EventKind::WindowClosed]
+        );
+
+        let filter = filter.window(Address::new("1234"));
+        assert!(!filter.matches(&events[0]));
+        assert!(filter.matches(&events[1]));
+
+        let filter = EventFilter::new().workspace("2");
+        assert!(filter.matches(&events[0]));
+        assert!(!filter.matches(&events[1]));
+
+        let moved = Event::WorkspaceMoved(WorkspaceMovedEventData {
+            name: WorkspaceType::Special(Some("scratch".into())),
+            id: -98,
+            monitor: "DP-1".into(),
+        });
+        assert!(EventFilter::new()
+            .workspace("special:scratch")
+            .workspace_id(-98)
+            .monitor("DP-1")
+            .matches(&moved));
+        assert!(!EventFilter::new().monitor("HDMI-A-1").matches(&moved));
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_typed_stream() -> crate::Result<()> {
+        use crate::testing::MockHyprland;
+        use futures_lite::StreamExt;
+        use std::time::Duration;
+
+        let mock = MockHyprland::new()?;
+        let mut opened = EventStream::instance_new(mock.instance().clone()).window_opened();
+        let server = std::thread::spawn(move || -> crate::Result<()> {
+            assert!(mock.wait_for_event_clients(1, Duration::from_secs(5)));
+            mock.push_event("closewindow>>1234")?;
+            mock.push_event("openwindow>>55d1c8a0e6f0,2,kitty,~")?;
+            std::thread::sleep(Duration::from_millis(50));
+            Ok(())
+        });
+        let window = opened.next().await.transpose()?;
+        assert_eq!(
+            window.map(|window| (window.window_address, window.window_class)),
+            Some((Address::new("55d1c8a0e6f0"), "kitty".to_string()))
+        );
+        assert!(matches!(server.join(), Ok(Ok(()))));
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::default_instance;
+use crate::error::HyprError;
 use crate::instance::Instance;
 
 /// This struct is used for adding event handlers and executing them on events
@@ -21,6 +22,9 @@ use crate::instance::Instance;
 /// ```
 pub struct EventListener {
     pub(crate) events: Events,
+    pub(crate) reconnect: Option<ReconnectPolicy>,
+    pub(crate) remover: HandlerRemover,
+    pub(crate) error_policy: ErrorPolicy,
 }
 
 impl Default for EventListener {
@@ -39,9 +43,50 @@ impl EventListener {
     pub fn new() -> EventListener {
         EventListener {
             events: create_events(),
+            reconnect: None,
+            remover: HandlerRemover::default(),
+            error_policy: ErrorPolicy::default(),
         }
     }
 
+    /// This method makes the event listener reconnect when the event socket closes,
+    /// instead of returning
+    ///
+    /// ```rust
+    /// use hyprland::event_listener::{EventListener, ReconnectPolicy};
+    /// let mut listener = EventListener::new();
+    /// listener.set_reconnect_policy(ReconnectPolicy::default());
+    /// listener.add_reconnected_handler(|_| println!("Hyprland restarted"));
+    /// ```
+    pub fn set_reconnect_policy(&mut self, policy: ReconnectPolicy) {
+        self.reconnect = Some(policy);
+    }
+
+    /// This method sets what happens when a handler returns an error,
+    /// which by default stops the listener
+    ///
+    /// ```rust
+    /// use hyprland::data::Monitors;
+    /// use hyprland::event_listener::{ErrorPolicy, EventListener};
+    /// use hyprland::prelude::*;
+    /// let mut listener = EventListener::new();
+    /// listener.set_error_policy(ErrorPolicy::Log);
+    /// let mut added = 0;
+    /// listener.add_monitor_added_handler(move |_| {
+    ///     added += 1;
+    ///     println!("{added} monitors added, now {}", Monitors::get()?.iter().count());
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn set_error_policy(&mut self, policy: ErrorPolicy) {
+        self.error_policy = policy;
+    }
+
+    /// This method returns a [HandlerRemover], which removes handlers while the listener runs
+    pub fn handler_remover(&self) -> HandlerRemover {
+        self.remover.clone()
+    }
+
     /// This method starts the event listener (async)
     ///
     /// This should be ran after all of your handlers are defined
@@ -80,20 +125,27 @@ impl EventListener {
         &mut self,
         instance: &Instance,
     ) -> crate::Result<()> {
-        use crate::async_import::*;
+        let policy = self.reconnect.clone();
+        let mut instance = instance.clone();
         let mut stream = instance.get_event_stream_async().await?;
 
         let mut active_windows = vec![];
+        let mut reader = EventLineReader::default();
         loop {
-            let mut buffer = [0; 4096];
-            let bytes_read = stream.read(&mut buffer).await?;
-            if bytes_read == 0 {
-                // If no bytes were read, we can assume the stream is closed
-                break;
-            }
-            let buf = &buffer[..bytes_read];
-            let string = String::from_utf8(buf.to_vec())?;
-            let parsed: Vec<Event> = event_parser(string)?;
+            let lines = match (reader.read_lines_async(&mut stream).await, &policy) {
+                (Ok(Some(lines)), _) => lines,
+                (Ok(None) | Err(HyprError::IoError(_)), Some(policy)) => {
+                    self.event_executor(Event::Disconnected)?;
+                    stream = policy.reconnect_async(&mut instance).await?;
+                    active_windows.clear();
+                    reader = EventLineReader::default();
+                    self.event_executor(Event::Reconnected(instance.clone()))?;
+                    continue;
+                }
+                (Ok(None), None) => break,
+                (Err(e), _) => return Err(e),
+            };
+            let parsed: Vec<Event> = event_parser(lines)?;
             for event in parsed {
                 self.event_primer(event, &mut active_windows)?;
             }
@@ -133,21 +185,27 @@ impl EventListener {
     /// }
     /// ```
     pub fn instance_start_listener(&mut self, instance: &Instance) -> crate::Result<()> {
-        // use io::prelude::*;
-        use std::io::Read;
+        let policy = self.reconnect.clone();
+        let mut instance = instance.clone();
         let mut stream = instance.get_event_stream()?;
 
         let mut active_windows = vec![];
+        let mut reader = EventLineReader::default();
         loop {
-            let mut buffer = [0; 4096];
-            let bytes_read = stream.read(&mut buffer)?;
-            if bytes_read == 0 {
-                // If no bytes were read, we can assume the stream is closed
-                break;
-            }
-            let buf = &buffer[..bytes_read];
-            let string = String::from_utf8(buf.to_vec())?;
-            let parsed: Vec<Event> = event_parser(string)?;
+            let lines = match (reader.read_lines(&mut stream), &policy) {
+                (Ok(Some(lines)), _) => lines,
+                (Ok(None) | Err(HyprError::IoError(_)), Some(policy)) => {
+                    self.event_executor(Event::Disconnected)?;
+                    stream = policy.reconnect(&mut instance)?;
+                    active_windows.clear();
+                    reader = EventLineReader::default();
+                    self.event_executor(Event::Reconnected(instance.clone()))?;
+                    continue;
+                }
+                (Ok(None), None) => break,
+                (Err(e), _) => return Err(e),
+            };
+            let parsed: Vec<Event> = event_parser(lines)?;
             for event in parsed {
                 self.event_primer(event, &mut active_windows)?;
             }
@@ -155,3 +213,79 @@ impl EventListener {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::Address;
+    use std::sync::{Arc, Mutex, PoisonError};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[test]
+    fn test_remove_handlers() -> crate::Result<()> {
+        let mut listener = EventListener::new();
+        let calls = Rc::new(RefCell::new(vec![]));
+
+        let (c1, c2, c3) = (calls.clone(), calls.clone(), calls.clone());
+        let every = listener.add_bell_handler(move |_| c1.borrow_mut().push("every"));
+        let once = listener.add_bell_once_handler(move |_| c2.borrow_mut().push("once"));
+        let removed = listener.add_bell_handler(move |_| c3.borrow_mut().push("removed"));
+        assert!(listener.remove_handler(removed));
+        assert!(!listener.remove_handler(removed));
+
+        listener.event_executor(Event::Bell(None))?;
+        listener.event_executor(Event::Bell(None))?;
+        assert_eq!(*calls.borrow(), ["every", "once", "every"]);
+        assert!(!listener.remove_handler(once));
+
+        listener.handler_remover().remove(every);
+        listener.event_executor(Event::Bell(None))?;
+        assert_eq!(calls.borrow().len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fallible_handlers() -> crate::Result<()> {
+        let mut listener = EventListener::new();
+        let calls = Rc::new(RefCell::new(0));
+        let counter = calls.clone();
+        let mut count = 0;
+        listener.add_window_title_changed_v1_handler(move |_| {
+            count += 1;
+            *counter.borrow_mut() = count;
+            if count % 2 == 0 {
+                return Err(HyprError::Other(format!("title {count}")));
+            }
+            Ok(())
+        });
+        // once handlers are removed even when they fail
+        listener.add_window_title_changed_v1_once_handler(|_| Err(HyprError::other("once")));
+        let title = || Event::WindowTitleChangedV1(Address::new("0x1"));
+
+        assert!(matches!(
+            listener.event_executor(title()),
+            Err(HyprError::Other(message)) if message == "once"
+        ));
+        assert_eq!(*calls.borrow(), 1);
+        assert!(matches!(
+            listener.event_executor(title()),
+            Err(HyprError::Other(message)) if message == "title 2"
+        ));
+
+        let errors = Arc::new(Mutex::new(vec![]));
+        let sink = errors.clone();
+        listener.set_error_policy(ErrorPolicy::handle(move |error| {
+            sink.lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(error.to_string());
+        }));
+        listener.event_executor(title())?;
+        listener.event_executor(title())?;
+        assert_eq!(*calls.borrow(), 4);
+        assert_eq!(
+            *errors.lock().unwrap_or_else(PoisonError::into_inner),
+            ["title 4"]
+        );
+        Ok(())
+    }
+}
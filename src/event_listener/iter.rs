@@ -0,0 +1,288 @@
+use super::*;
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::os::unix::net::UnixStream;
+use std::time::Duration;
+
+use crate::default_instance;
+use crate::error::HyprError;
+use crate::instance::Instance;
+use crate::shared::Address;
+
+/// Event listener, but blocking [Iterator]
+///
+/// It pulls events from the event socket when asked, so it fits in scripts and existing
+/// poll loops without async or taking over the thread like [EventListener]
+///
+/// # Examples
+/// ```rust, no_run
+/// use hyprland::event_listener::EventIter;
+/// use std::time::Duration;
+///
+/// fn main() -> hyprland::Result<()> {
+///     for event in EventIter::new() {
+///         println!("{:?}", event?);
+///     }
+///
+///     let mut events = EventIter::new();
+///     loop {
+///         while let Some(event) = events.try_next()? {
+///             println!("{event:?}");
+///         }
+///         // do other work
+///         std::thread::sleep(Duration::from_millis(100));
+///     }
+/// }
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EventIter {
+    instance: Option<Instance>,
+    policy: Option<ReconnectPolicy>,
+    filter: EventFilter,
+    read_timeout: Option<Duration>,
+    socket: Option<UnixStream>,
+    reader: EventLineReader,
+    active_windows: Vec<ActiveWindowState>,
+    pending: VecDeque<Event>,
+    finished: bool,
+}
+
+/// How long a read waits for the socket
+#[derive(Clone, Copy)]
+enum Wait {
+    /// Until the read timeout of the iterator, if any
+    ReadTimeout,
+    /// Until the duration
+    For(Duration),
+    /// Not at all
+    Never,
+}
+
+impl Default for EventIter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventIter {
+    /// Creates a new [EventIter]
+    pub fn new() -> Self {
+        Self::create(None, None)
+    }
+
+    /// Creates a new [EventIter]
+    pub fn instance_new(instance: Instance) -> Self {
+        Self::create(Some(instance), None)
+    }
+
+    /// Creates a new [EventIter] which reconnects when the event socket closes, instead of ending
+    pub fn with_reconnect(policy: ReconnectPolicy) -> Self {
+        Self::create(None, Some(policy))
+    }
+
+    /// Creates a new [EventIter] which reconnects when the event socket closes, instead of ending
+    pub fn instance_with_reconnect(instance: Instance, policy: ReconnectPolicy) -> Self {
+        Self::create(Some(instance), Some(policy))
+    }
+
+    fn create(instance: Option<Instance>, policy: Option<ReconnectPolicy>) -> Self {
+        Self {
+            instance,
+            policy,
+            filter: EventFilter::default(),
+            read_timeout: None,
+            socket: None,
+            reader: EventLineReader::default(),
+            active_windows: vec![],
+            pending: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /// This method makes [Iterator::next] wait at most the timeout for an event.
+    /// When it runs out, the iterator yields an [HyprError::IoError] of kind
+    /// [ErrorKind::WouldBlock] or [ErrorKind::TimedOut], and keeps going after it
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout).filter(|timeout| !timeout.is_zero());
+        self
+    }
+
+    /// This method makes the iterator yield only the events that pass the filter, replacing the
+    /// filters set before. Errors are always yielded
+    pub fn with_filter(mut self, filter: EventFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// This method makes the iterator yield only events of these kinds.
+    /// Lines of other kinds are skipped before they are parsed
+    pub fn filter_kinds<I: IntoIterator<Item = EventKind>>(mut self, kinds: I) -> Self {
+        self.filter = self.filter.kinds(kinds);
+        self
+    }
+
+    /// This method makes the iterator yield only events about the window
+    pub fn filter_window(mut self, address: Address) -> Self {
+        self.filter = self.filter.window(address);
+        self
+    }
+
+    /// This method makes the iterator yield only events about the workspace with the name
+    pub fn filter_workspace(mut self, name: impl Into<String>) -> Self {
+        self.filter = self.filter.workspace(name);
+        self
+    }
+
+    /// This method makes the iterator yield only events about the monitor with the name
+    pub fn filter_monitor(mut self, name: impl Into<String>) -> Self {
+        self.filter = self.filter.monitor(name);
+        self
+    }
+
+    /// This method returns the next event if one was already received, without blocking.
+    /// It returns `Ok(None)` when there is none, or when the iterator has ended
+    ///
+    /// When the socket closes and a [ReconnectPolicy] is set, this blocks while reconnecting
+    pub fn try_next(&mut self) -> crate::Result<Option<Event>> {
+        self.pull(Wait::Never)
+    }
+
+    /// This method waits at most the timeout for the next event.
+    /// It returns `Ok(None)` when none arrives in time, or when the iterator has ended
+    pub fn next_timeout(&mut self, timeout: Duration) -> crate::Result<Option<Event>> {
+        if timeout.is_zero() {
+            return self.try_next();
+        }
+        self.pull(Wait::For(timeout))
+    }
+
+    /// This method returns if the iterator has ended, because the socket was closed
+    /// without a [ReconnectPolicy] or because of an error
+    pub fn is_finished(&self) -> bool {
+        self.finished && self.pending.is_empty()
+    }
+
+    fn pull(&mut self, wait: Wait) -> crate::Result<Option<Event>> {
+        let result = self.pull_inner(wait);
+        match &result {
+            Err(HyprError::IoError(e)) if is_timeout(e) => {}
+            Err(_) => self.finished = true,
+            Ok(_) => {}
+        }
+        result
+    }
+
+    fn pull_inner(&mut self, wait: Wait) -> crate::Result<Option<Event>> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(Some(event));
+            }
+            if self.finished {
+                return Ok(None);
+            }
+            let socket = match &mut self.socket {
+                Some(socket) => socket,
+                socket => socket.insert(instance(&mut self.instance)?.get_event_stream()?),
+            };
+            match wait {
+                Wait::ReadTimeout => {
+                    socket.set_nonblocking(false)?;
+                    socket.set_read_timeout(self.read_timeout)?;
+                }
+                Wait::For(timeout) => {
+                    socket.set_nonblocking(false)?;
+                    socket.set_read_timeout(Some(timeout))?;
+                }
+                Wait::Never => socket.set_nonblocking(true)?,
+            }
+
+            let lines = match (self.reader.read_lines(socket), &self.policy) {
+                (Ok(Some(lines)), _) => lines,
+                (Err(HyprError::IoError(e)), _) if is_timeout(&e) => {
+                    return match wait {
+                        Wait::ReadTimeout => Err(HyprError::IoError(e)),
+                        Wait::For(_) | Wait::Never => Ok(None),
+                    };
+                }
+                (Ok(None) | Err(HyprError::IoError(_)), Some(policy)) => {
+                    let policy = policy.clone();
+                    self.socket = None;
+                    self.push(Event::Disconnected);
+                    let instance = instance(&mut self.instance)?;
+                    self.socket = Some(policy.reconnect(instance)?);
+                    let reconnected = Event::Reconnected(instance.clone());
+                    self.active_windows.clear();
+                    self.reader = EventLineReader::default();
+                    self.push(reconnected);
+                    continue;
+                }
+                (Ok(None), None) => {
+                    self.finished = true;
+                    continue;
+                }
+                (Err(e), _) => return Err(e),
+            };
+            for event in event_parser_filtered(lines, &self.filter)? {
+                for primed_event in event_primer_noexec(event, &mut self.active_windows)? {
+                    self.push(primed_event);
+                }
+            }
+        }
+    }
+
+    fn push(&mut self, event: Event) {
+        if self.filter.matches(&event) {
+            self.pending.push_back(event);
+        }
+    }
+}
+
+/// Returns the instance, which is the default one unless it was set
+fn instance(instance: &mut Option<Instance>) -> crate::Result<&mut Instance> {
+    let resolved = match instance.take() {
+        Some(instance) => instance,
+        None => default_instance()?.clone(),
+    };
+    Ok(instance.insert(resolved))
+}
+
+fn is_timeout(error: &std::io::Error) -> bool {
+    matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut)
+}
+
+impl Iterator for EventIter {
+    type Item = crate::Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pull(Wait::ReadTimeout).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockHyprland;
+
+    #[test]
+    fn test_event_iter() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let mut events = EventIter::instance_new(mock.instance().clone())
+            .filter_kinds([EventKind::WindowClosed])
+            .read_timeout(Duration::from_secs(5));
+        assert!(events.try_next()?.is_none());
+        assert!(mock.wait_for_event_clients(1, Duration::from_secs(5)));
+        assert!(events.next_timeout(Duration::from_millis(20))?.is_none());
+
+        mock.push_event("openwindow>>55d1c8a0e6f0,2,kitty,~")?;
+        mock.push_event("closewindow>>1234")?;
+        assert!(matches!(
+            events.next(),
+            Some(Ok(Event::WindowClosed(address))) if address == Address::new("1234")
+        ));
+
+        mock.disconnect_event_clients();
+        assert!(events.next().is_none());
+        assert!(events.is_finished());
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+use crate::error::HyprError;
+
+/// The maximum length in bytes of a single event line read from the event socket
+///
+/// Longer lines fail with [HyprError::EventLineTooLong] instead of being buffered forever
+pub const MAX_EVENT_LINE_LENGTH: usize = 64 * 1024;
+
+const READ_SIZE: usize = 4096;
+
+/// This struct frames the event socket into whole lines
+///
+/// Bytes after the last newline of a read (including incomplete UTF-8 sequences)
+/// are kept until the rest of the line arrives
+#[derive(Debug)]
+pub(crate) struct EventLineReader {
+    buffer: Vec<u8>,
+    max_line_length: usize,
+}
+
+impl Default for EventLineReader {
+    fn default() -> Self {
+        Self::new(MAX_EVENT_LINE_LENGTH)
+    }
+}
+
+impl EventLineReader {
+    pub(crate) fn new(max_line_length: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(READ_SIZE),
+            max_line_length,
+        }
+    }
+
+    fn check_length(&self, line: &[u8]) -> crate::Result<()> {
+        if line.len() > self.max_line_length {
+            Err(HyprError::EventLineTooLong(self.max_line_length))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Adds read bytes, and returns every complete line received so far
+    fn push(&mut self, bytes: &[u8]) -> crate::Result<Option<String>> {
+        self.buffer.extend_from_slice(bytes);
+        let Some(last_newline) = self.buffer.iter().rposition(|byte| *byte == b'\n') else {
+            self.check_length(&self.buffer)?;
+            return Ok(None);
+        };
+        let rest = self.buffer.split_off(last_newline + 1);
+        let lines = std::mem::replace(&mut self.buffer, rest);
+        self.check_length(&self.buffer)?;
+        for line in lines.split(|byte| *byte == b'\n') {
+            self.check_length(line)?;
+        }
+        Ok(Some(String::from_utf8(lines)?))
+    }
+
+    /// Returns the unterminated line left when the socket is closed
+    fn finish(&mut self) -> crate::Result<Option<String>> {
+        if self.buffer.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(String::from_utf8(std::mem::take(&mut self.buffer))?))
+        }
+    }
+
+    /// Reads until at least one complete line is received,
+    /// returns [None] once the socket is closed
+    pub(crate) fn read_lines<R: std::io::Read>(
+        &mut self,
+        stream: &mut R,
+    ) -> crate::Result<Option<String>> {
+        let mut chunk = [0; READ_SIZE];
+        loop {
+            let bytes_read = match stream.read(&mut chunk) {
+                Ok(n) => n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            if bytes_read == 0 {
+                // If no bytes were read, we can assume the stream is closed
+                return self.finish();
+            }
+            if let Some(lines) = self.push(&chunk[..bytes_read])? {
+                return Ok(Some(lines));
+            }
+        }
+    }
+
+    /// Reads until at least one complete line is received,
+    /// returns [None] once the socket is closed (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub(crate) async fn read_lines_async(
+        &mut self,
+        stream: &mut crate::async_import::UnixStream,
+    ) -> crate::Result<Option<String>> {
+        use crate::async_import::*;
+        let mut chunk = [0; READ_SIZE];
+        loop {
+            let bytes_read = stream.read(&mut chunk).await?;
+            if bytes_read == 0 {
+                // If no bytes were read, we can assume the stream is closed
+                return self.finish();
+            }
+            if let Some(lines) = self.push(&chunk[..bytes_read])? {
+                return Ok(Some(lines));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    /// Returns one chunk per read, like a socket would
+    struct Chunks(Vec<Vec<u8>>);
+
+    impl Read for Chunks {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.0.is_empty() {
+                return Ok(0);
+            }
+            let chunk = self.0.remove(0);
+            buf[..chunk.len()].copy_from_slice(&chunk);
+            Ok(chunk.len())
+        }
+    }
+
+    fn read_all(reader: &mut EventLineReader, chunks: Vec<Vec<u8>>) -> crate::Result<Vec<String>> {
+        let mut stream = Chunks(chunks);
+        let mut reads = vec![];
+        while let Some(lines) = reader.read_lines(&mut stream)? {
+            reads.push(lines);
+        }
+        Ok(reads)
+    }
+
+    #[test]
+    fn test_line_across_reads() -> crate::Result<()> {
+        let reads = read_all(
+            &mut EventLineReader::default(),
+            vec![
+                b"workspacev2>>1,1\nwindowtit".to_vec(),
+                b"lev2>>55d1c8a0e6f0,a very".to_vec(),
+                b" long title\n".to_vec(),
+            ],
+        )?;
+        assert_eq!(
+            reads,
+            vec![
+                "workspacev2>>1,1\n",
+                "windowtitlev2>>55d1c8a0e6f0,a very long title\n"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_codepoint_across_reads() -> crate::Result<()> {
+        let line = "windowtitlev2>>55d1c8a0e6f0,ünïcödé 🦀\n".as_bytes();
+        let crab = line.len() - 3;
+        let reads = read_all(
+            &mut EventLineReader::default(),
+            vec![line[..crab].to_vec(), line[crab..].to_vec()],
+        )?;
+        assert_eq!(reads, vec!["windowtitlev2>>55d1c8a0e6f0,ünïcödé 🦀\n"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unterminated_line() -> crate::Result<()> {
+        let reads = read_all(
+            &mut EventLineReader::default(),
+            vec![b"closewindow>>55d1c8a0e6f0\nopenlayer>>waybar".to_vec()],
+        )?;
+        assert_eq!(
+            reads,
+            vec!["closewindow>>55d1c8a0e6f0\n", "openlayer>>waybar"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_too_long() {
+        let result = read_all(
+            &mut EventLineReader::new(16),
+            vec![b"windowtitlev2>>".to_vec(), b"55d1c8a0e6f0,kitty".to_vec()],
+        );
+        assert!(matches!(result, Err(HyprError::EventLineTooLong(16))));
+
+        let result = read_all(
+            &mut EventLineReader::new(16),
+            vec![b"windowtitlev2>>55d1c8a0e6f0,kitty\n".to_vec()],
+        );
+        assert!(matches!(result, Err(HyprError::EventLineTooLong(16))));
+    }
+
+    #[test]
+    fn test_listener_with_split_events() -> crate::Result<()> {
+        use crate::event_listener::EventListener;
+        use crate::testing::MockHyprland;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use std::time::Duration;
+
+        let mock = MockHyprland::new()?;
+        let titles = Rc::new(RefCell::new(vec![]));
+        let mut listener = EventListener::new();
+        let handler_titles = titles.clone();
+        listener.add_window_title_changed_handler(move |data| {
+            handler_titles.borrow_mut().push(data.title)
+        });
+        std::thread::scope(|scope| {
+            let writer = scope.spawn(|| {
+                assert!(mock.wait_for_event_clients(1, Duration::from_secs(5)));
+                let line = "windowtitlev2>>55d1c8a0e6f0,crab 🦀 title\n".as_bytes();
+                for byte in line {
+                    mock.push_event_bytes(&[*byte])?;
+                    std::thread::sleep(Duration::from_micros(200));
+                }
+                mock.disconnect_event_clients();
+                crate::Result::Ok(())
+            });
+            let listened = listener.instance_start_listener(mock.instance());
+            writer
+                .join()
+                .map_err(|_| crate::error::HyprError::other("the event writer panicked"))??;
+            listened
+        })?;
+        assert_eq!(*titles.borrow(), vec!["crab 🦀 title"]);
+        Ok(())
+    }
+}
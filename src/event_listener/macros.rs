@@ -3,7 +3,7 @@ macro_rules! events {
         paste! {
             pub(crate) struct Events {
                 $(
-                    pub(crate) [<$name:snake _events>]: type_if! {(),$data,Vec<EmptyClosure>, Closures<$data>}
+                    pub(crate) [<$name:snake _events>]: type_if! {(),$data,EmptyClosures, Closures<$data>}
                 ),*
             }
 
@@ -11,9 +11,49 @@ macro_rules! events {
             #[allow(clippy::type_complexity)]
             pub(crate) struct AsyncEvents {
                 $(
-                    pub(crate) [<$name:snake _events>]: type_if! {(),$data,Vec<EmptyAsyncClosure>, AsyncClosures<$data>}
+                    pub(crate) [<$name:snake _events>]: type_if! {(),$data,EmptyAsyncClosures, AsyncClosures<$data>}
                 ),*
             }
+            /// This enum holds the kinds of [Event]s, without their data
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum EventKind {
+                $(
+                    #[doc = concat!("The kind of [Event::", stringify!($name), "], emitted when ", $descr1)]
+                    $name
+                ),*
+            }
+
+            impl Event {
+                /// This method returns the kind of the event
+                pub fn kind(&self) -> EventKind {
+                    match self {
+                        $(
+                            expr_if! {(),$data, Event::$name, Event::$name(_)} => EventKind::$name,
+                        )*
+                        Event::ActiveWindowChangedV1(_) | Event::ActiveWindowChangedV2(_) => {
+                            EventKind::ActiveWindowChanged
+                        }
+                    }
+                }
+            }
+
+            #[cfg(any(feature = "async-lite", feature = "tokio"))]
+            impl EventStream {
+                $(
+                    #[doc = concat!("This method returns a stream of the data of the events emitted when ", $descr1, ".\nLines of other events are skipped before they are parsed")]
+                    pub fn [<$name:snake>](self) -> impl futures_lite::Stream<Item = crate::Result<$data>> + Send + Unpin {
+                        let stream = self.filter_kinds([EventKind::$name]);
+                        futures_lite::StreamExt::filter_map(stream, |event| match event {
+                            Ok(expr_if! {(),$data, Event::$name, Event::$name($id)}) => {
+                                Some(Ok(expr_if! {(),$data, (), $id}))
+                            }
+                            Ok(_) => None,
+                            Err(e) => Some(Err(e)),
+                        })
+                    }
+                )*
+            }
+
             pub(crate) fn create_events() -> Events {
                 Events {
                     $([<$name:snake _events>]: vec![]),*
@@ -27,9 +67,39 @@ macro_rules! events {
                 }
             }
 
+            #[cfg(any(feature = "async-lite", feature = "tokio"))]
+            impl AsyncEventListener {
+                /// This method removes a handler, and returns false if the listener doesn't have it
+                pub fn remove_handler(&mut self, id: HandlerId) -> bool {
+                    $(
+                        if let Some(index) = self.events.[<$name:snake _events>].iter().position(|handler| handler.id == id) {
+                            self.events.[<$name:snake _events>].remove(index);
+                            return true;
+                        }
+                    )*
+                    false
+                }
+            }
+
+            impl EventListener {
+                /// This method removes a handler, and returns false if the listener doesn't have it
+                pub fn remove_handler(&mut self, id: HandlerId) -> bool {
+                    $(
+                        if let Some(index) = self.events.[<$name:snake _events>].iter().position(|handler| handler.id == id) {
+                            self.events.[<$name:snake _events>].remove(index);
+                            return true;
+                        }
+                    )*
+                    false
+                }
+            }
+
             #[cfg(any(feature = "async-lite", feature = "tokio"))]
             impl HasAsyncExecutor for AsyncEventListener {
                 async fn event_executor_async(&mut self, event: Event) -> crate::Result<()> {
+                    for id in self.remover.take() {
+                        self.remove_handler(id);
+                    }
                     use Event::*;
                     match event {
                         $(
@@ -47,6 +117,9 @@ macro_rules! events {
             }
             impl HasExecutor for EventListener {
                 fn event_executor(&mut self, event: Event) -> crate::Result<()> {
+                    for id in self.remover.take() {
+                        self.remove_handler(id);
+                    }
                     use Event::*;
                     match event {
                         $(
@@ -82,47 +155,57 @@ macro_rules! events {
 
 macro_rules! add_listener {
     ($name:ident,$f:ty,$c:literal,$c2:literal => $id:ident) => {
-        add_listener_reg!($name,$f,$c,$c2 => $id);
+        add_listener_reg!($name,($id: $f),$c,$c2);
         #[cfg(any(feature = "async-lite", feature = "tokio"))]
-        add_async_listener!($name,$f,$c,$c2 => $id);
+        add_async_listener!($name,($id: $f),$c,$c2);
     };
     ($name:ident,$c:literal,$c2:literal => $id:ident) => {
-        add_listener_reg!($name,$c,$c2 => $id);
+        add_listener_reg!($name,(),$c,$c2);
         #[cfg(any(feature = "async-lite", feature = "tokio"))]
-        add_async_listener!($name,$c,$c2 => $id);
+        add_async_listener!($name,(),$c,$c2);
     };
 }
 
-#[cfg(any(feature = "async-lite", feature = "tokio"))]
-macro_rules! add_async_listener {
-    ($name:ident,$f:ty,$c:literal,$c2:expr => $id:ident) => {
-        add_async_listener_raw!($name,$name,impl Fn($f) -> VoidFuture + Send + Sync + 'static,$c,$c2 => $id);
-    };
-    ($name:ident,$c:literal,$c2:expr => $id:ident) => {
-        add_async_listener_raw!($name,$name,impl Fn() -> VoidFuture + Send + Sync + 'static,$c,$c2 => $id);
-    };
-}
 macro_rules! add_listener_reg {
-    ($name:ident,$f:ty,$c:literal,$c2:expr => $id:ident) => {
-        add_listener_reg_raw!($name,$name,impl Fn($f) + 'static,$c,$c2 => $id);
-    };
-    ($name:ident,$c:literal,$c2:expr => $id:ident) => {
-        add_listener_reg_raw!($name,$name,impl Fn() + 'static,$c,$c2 => $id);
-    };
-}
-
-macro_rules! add_listener_reg_raw {
-    ($name:ident,$list_name:ident,$f:ty,$c:literal,$c2:expr => $id:ident) => {
+    ($name:ident,($($arg:ident: $t:ty)?),$c:literal,$c2:expr) => {
         paste! {
             impl EventListener {
                 #[doc = concat!("This method adds an event which executes when", stringify!($c), r#"
 ```rust, no_run
 use hyprland::event_listener::EventListener;
 let mut listener = EventListener::new();
-listener.add_"#, stringify!($name), r#"_handler("#, handler_example_closure! { $f, $c2, $id }, r#");
-listener.start_listener();"#)]
-                pub fn [<add_ $name _handler>](&mut self, f: $f) {
-                    self.events.[<$list_name _events>].push(Box::new(f));
+listener.add_"#, stringify!($name), r#"_handler("#, handler_example_closure! { $c2 $(, $arg)? }, r#");
+listener.start_listener();
+```"#)]
+                ///
+                /// The handler can return a [crate::Result], and errors are handled by the [ErrorPolicy]
+                pub fn [<add_ $name _handler>]<R: HandlerOutput>(
+                    &mut self,
+                    f: impl FnMut($($t)?) -> R + 'static,
+                ) -> HandlerId {
+                    self.[<push_ $name _handler>](f, false)
+                }
+
+                #[doc = concat!("This method adds a handler like [Self::add_", stringify!($name), "_handler], which is removed after it executes once")]
+                pub fn [<add_ $name _once_handler>]<R: HandlerOutput>(
+                    &mut self,
+                    f: impl FnMut($($t)?) -> R + 'static,
+                ) -> HandlerId {
+                    self.[<push_ $name _handler>](f, true)
+                }
+
+                fn [<push_ $name _handler>]<R: HandlerOutput>(
+                    &mut self,
+                    mut f: impl FnMut($($t)?) -> R + 'static,
+                    once: bool,
+                ) -> HandlerId {
+                    let handler = Handler::new(
+                        Box::new(move |$($arg: $t)?| f($($arg)?).into_result()) as _,
+                        once,
+                    );
+                    let id = handler.id;
+                    self.events.[<$name _events>].push(handler);
+                    id
                 }
             }
         }
@@ -130,82 +213,110 @@ listener.start_listener();"#)]
 }
 
 #[cfg(any(feature = "async-lite", feature = "tokio"))]
-macro_rules! add_async_listener_raw {
-    ($name:ident,$list_name:ident,$f:ty,$c:literal,$c2:expr => $id:ident) => {
+macro_rules! add_async_listener {
+    ($name:ident,($($arg:ident: $t:ty)?),$c:literal,$c2:expr) => {
         paste! {
             impl AsyncEventListener {
                 #[doc = concat!("This method adds an event which executes when ", $c, r#"
 ```rust, no_run
 use hyprland::event_listener::EventListener;
 let mut listener = EventListener::new();
-listener.add_"#, stringify!($name), r#"_handler("#, handler_example_async_closure! { $f, $c2, $id }, r#");
-listener.start_listener();"#)]
-                pub fn [<add_ $name _handler>](&mut self, f: $f) {
-                    self.events.[<$list_name _events>].push(Box::pin(f));
+listener.add_"#, stringify!($name), r#"_handler("#, handler_example_closure! { $c2 $(, $arg)? }, r#");
+listener.start_listener();
+```"#)]
+                ///
+                /// The future can output a [crate::Result], and errors are handled by the [ErrorPolicy]
+                pub fn [<add_ $name _handler>]<R: HandlerOutput, F: std::future::Future<Output = R> + Send + 'static>(
+                    &mut self,
+                    f: impl FnMut($($t)?) -> F + Send + 'static,
+                ) -> HandlerId {
+                    self.[<push_ $name _handler>](f, false)
+                }
+
+                #[doc = concat!("This method adds a handler like [Self::add_", stringify!($name), "_handler], which is removed after it executes once")]
+                pub fn [<add_ $name _once_handler>]<R: HandlerOutput, F: std::future::Future<Output = R> + Send + 'static>(
+                    &mut self,
+                    f: impl FnMut($($t)?) -> F + Send + 'static,
+                ) -> HandlerId {
+                    self.[<push_ $name _handler>](f, true)
+                }
+
+                fn [<push_ $name _handler>]<R: HandlerOutput, F: std::future::Future<Output = R> + Send + 'static>(
+                    &mut self,
+                    mut f: impl FnMut($($t)?) -> F + Send + 'static,
+                    once: bool,
+                ) -> HandlerId {
+                    let handler = Handler::new(
+                        Box::new(move |$($arg: $t)?| {
+                            let future = f($($arg)?);
+                            Box::pin(async move { future.await.into_result() }) as HandlerFuture
+                        }) as _,
+                        once,
+                    );
+                    let id = handler.id;
+                    self.events.[<$name _events>].push(handler);
+                    id
                 }
             }
         }
     };
 }
+
 /// Expands to an example closure for documenting event listeners.
 macro_rules! handler_example_closure {
-    ($f:ty, $c2:expr, $id:ident) => {
-        type_if! {
-            impl Fn() + 'static ,
-            $f,
-            concat!(
-                r#"|| println!(""#, $c2, r#"")"#,
-            ),
-            concat!(
-                r#"|"#, stringify!($id), r#"| println!(""#, $c2, ": {", stringify!($id), r#":#?}")"#,
-            )
-        }
-    }
-}
-/// Expands to an example closure for documenting async event listeners.
-#[cfg(any(feature = "async-lite", feature = "tokio"))]
-macro_rules! handler_example_async_closure {
-    ($f:ty, $c2:expr, $id:ident) => {
-        type_if! {
-            impl Fn() -> VoidFuture + Send + Sync + 'static ,
-            $f,
-            concat!(
-                r#"|| println!(""#, $c2, r#"")"#,
-            ),
-            concat!(
-                r#"|"#, stringify!($id), r#"| println!(""#, $c2, ": {", stringify!($id), r#":#?}")"#,
-            )
-        }
-    }
+    ($c2:expr) => {
+        concat!(r#"|| println!(""#, $c2, r#"")"#)
+    };
+    ($c2:expr, $id:ident) => {
+        concat!(
+            r#"|"#,
+            stringify!($id),
+            r#"| println!(""#,
+            $c2,
+            ": {",
+            stringify!($id),
+            r#":#?}")"#,
+        )
+    };
 }
 
 macro_rules! arm {
-    ($val:expr,$nam:ident,$se:ident) => {{
-        let events = &$se.events.$nam;
-        for item in events.iter() {
-            execute_closure(item, $val.clone());
-        }
-    }};
-    ($nam:ident,$se:ident) => {{
-        let events = &$se.events.$nam;
-        for item in events.iter() {
-            execute_empty_closure(item);
+    // Runs the handlers until the error policy stops, and removes the once handlers that ran
+    (@run $se:ident, $nam:ident, $f:ident => $call:expr) => {{
+        let events = &mut $se.events.$nam;
+        let mut result = Ok(());
+        let mut ran = 0;
+        for item in events.iter_mut() {
+            ran += 1;
+            let $f = &mut item.f;
+            if let Err(error) = $call {
+                result = $se.error_policy.apply(error);
+                if result.is_err() {
+                    break;
+                }
+            }
         }
+        let mut index = 0;
+        events.retain(|item| {
+            index += 1;
+            index > ran || !item.once
+        });
+        result?;
     }};
+    ($val:expr,$nam:ident,$se:ident) => {
+        arm!(@run $se, $nam, f => execute_closure(f, $val.clone()))
+    };
+    ($nam:ident,$se:ident) => {
+        arm!(@run $se, $nam, f => execute_empty_closure(f))
+    };
 }
 
 #[cfg(any(feature = "async-lite", feature = "tokio"))]
 macro_rules! arm_async {
-    ($val:expr,$nam:ident,$se:ident) => {{
-        let events = &$se.events.$nam;
-        for item in events.iter() {
-            execute_closure_async(item, $val.clone()).await;
-        }
-    }};
-    ($nam:ident,$se:ident) => {{
-        let events = &$se.events.$nam;
-        for item in events.iter() {
-            execute_empty_closure_async(item).await;
-        }
-    }};
+    ($val:expr,$nam:ident,$se:ident) => {
+        arm!(@run $se, $nam, f => execute_closure_async(f, $val.clone()).await)
+    };
+    ($nam:ident,$se:ident) => {
+        arm!(@run $se, $nam, f => execute_empty_closure_async(f).await)
+    };
 }
@@ -1,6 +1,7 @@
 //! # Event Listener Module
 //! for documentation go to:
 //! * [EventStream] for the event listener implementation based on the [futures_lite::Stream] api
+//! * [EventIter] for the blocking event listener implementation based on the [Iterator] api
 //! * [EventListener] for the normal [Fn] based event listener
 //! * [AsyncEventListener] for the [Fn] based event listener which uses closures that return [std::future::Future]s
 
@@ -10,6 +11,16 @@ mod macros;
 mod shared;
 pub use crate::event_listener::shared::*;
 
+mod filter;
+pub use crate::event_listener::filter::EventFilter;
+
+mod line_reader;
+pub(crate) use crate::event_listener::line_reader::EventLineReader;
+
+mod reconnect;
+pub use crate::event_listener::line_reader::MAX_EVENT_LINE_LENGTH;
+pub use crate::event_listener::reconnect::ReconnectPolicy;
+
 mod immutable;
 pub use crate::event_listener::immutable::EventListener;
 
@@ -18,11 +29,15 @@ mod async_im;
 #[cfg(any(feature = "async-lite", feature = "tokio"))]
 pub use crate::event_listener::async_im::AsyncEventListener;
 
+mod iter;
+pub use crate::event_listener::iter::EventIter;
+
 #[cfg(any(feature = "async-lite", feature = "tokio"))]
 mod stream;
 #[cfg(any(feature = "async-lite", feature = "tokio"))]
 pub use crate::event_listener::stream::EventStream;
-use crate::shared::Address;
+use crate::instance::Instance;
+use crate::shared::{Address, WorkspaceType};
 
 // generates code for the closure based event listeners
 events! {
@@ -56,5 +71,20 @@ events! {
     GroupToggled => GroupToggledEventData, "a group was toggled", "the group toggle state was set to" => data;
     WindowMovedIntoGroup => Address, "a window was moved into a group", "a window was moved into a group with the address of" => addr;
     WindowMovedOutOfGroup => Address, "a window was moved out of a group", "a window was moved out of a group with the address of" => addr;
+    ActiveMonitorChangedV2 => MonitorV2EventData, "the active monitor is changed, with the id of its workspace", "Active monitor changed to" => data;
+    ActiveSpecialChanged => ActiveSpecialEventData, "a special workspace is opened or closed on a monitor", "Special workspace changed" => data;
+    WorkspaceChangedV1 => WorkspaceType, "on workspace change, with only the workspace name", "changed workspace to" => name;
+    WorkspaceAddedV1 => WorkspaceType, "a workspace is created, with only the workspace name", "workspace was added" => name;
+    WorkspaceDeletedV1 => WorkspaceType, "a workspace is destroyed, with only the workspace name", "a workspace was destroyed" => name;
+    WorkspaceMovedV1 => WorkspaceMovedV1EventData, "a workspace is moved, without the workspace id", "workspace was moved" => data;
+    MonitorAddedV1 => String, "a new monitor is added, with only the monitor name", "Monitor added" => name;
+    MonitorRemovedV2 => MonitorAddedEventData, "a monitor is removed, with its id and description", "Monitor removed" => data;
+    WindowMovedV1 => WindowMovedV1EventData, "a window is moved, without the workspace id", "Window moved" => data;
+    WindowTitleChangedV1 => Address, "a window title is changed, without the title", "A window title changed" => addr;
+    WindowMinimized => WindowMinimizedEventData, "a window requests to be minimized or unminimized", "Window minimized" => data;
+    Bell => Option<Address>, "a window rings the bell", "Bell rung by" => addr;
+    Custom => String, "the event dispatcher is called", "Custom event" => data;
+    Disconnected => (), "the event socket is disconnected", "event socket disconnected" => _empty;
+    Reconnected => Instance, "the event socket is reconnected", "event socket reconnected to" => instance;
     Unknown => UnknownEventData, "the state of some unknown event changed", "unknown state changed to" => value
 }
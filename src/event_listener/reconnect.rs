@@ -0,0 +1,280 @@
+use crate::instance::Instance;
+use std::time::Duration;
+
+/// This struct configures how event listeners reconnect when the event socket closes,
+/// for example when Hyprland is restarted
+///
+/// When a policy is set, [Event::Disconnected](super::Event::Disconnected) is emitted once the socket closes,
+/// and [Event::Reconnected](super::Event::Reconnected) once a new connection is made.
+/// Events sent while disconnected are lost, so consumers should resync their state on reconnect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// The delay before the first reconnection attempt
+    pub initial_backoff: Duration,
+    /// The maximum delay between reconnection attempts
+    pub max_backoff: Duration,
+    /// The factor the delay is multiplied with after every failed attempt
+    pub multiplier: u32,
+    /// The amount of attempts before giving up and returning the connection error,
+    /// `None` means retrying forever
+    pub max_attempts: Option<u32>,
+    /// If the socket of the old instance is gone,
+    /// connect to the newest instance from [crate::ctl::instance::instance_list] instead
+    #[cfg(feature = "ctl")]
+    pub resolve_instance: bool,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2,
+            max_attempts: None,
+            #[cfg(feature = "ctl")]
+            resolve_instance: true,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Returns the delay before the attempt, starting at 1
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let factor = self
+            .multiplier
+            .max(1)
+            .saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        self.max_attempts.map_or(true, |max| attempt < max)
+    }
+
+    /// Swaps the instance for the newest running one if its event socket is gone
+    #[cfg(feature = "ctl")]
+    fn resolve(&self, instance: &mut Instance) {
+        self.resolve_with(instance, || {
+            let instances = crate::ctl::instance::instance_list()?;
+            let newest = instances.into_iter().max_by_key(|instance| instance.time);
+            newest
+                .map(|new| Instance::from_instance(new.instance))
+                .transpose()
+        });
+    }
+
+    /// Swaps the instance for the one returned by `newest` if its event socket is gone
+    #[cfg(feature = "ctl")]
+    fn resolve_with(
+        &self,
+        instance: &mut Instance,
+        newest: impl FnOnce() -> crate::Result<Option<Instance>>,
+    ) {
+        if !self.resolve_instance || instance.event_socket_exists() {
+            return;
+        }
+        if let Ok(Some(new)) = newest() {
+            *instance = new;
+        }
+    }
+
+    #[cfg(not(feature = "ctl"))]
+    fn resolve(&self, _instance: &mut Instance) {}
+
+    /// Connects to the event socket again (blocking)
+    pub(crate) fn reconnect(
+        &self,
+        instance: &mut Instance,
+    ) -> crate::Result<std::os::unix::net::UnixStream> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            std::thread::sleep(self.backoff(attempt));
+            self.resolve(instance);
+            match instance.get_event_stream() {
+                Ok(stream) => return Ok(stream),
+                Err(e) if !self.should_retry(attempt) => return Err(e),
+                Err(_) => {}
+            }
+        }
+    }
+
+    /// Connects to the event socket again (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub(crate) async fn reconnect_async(
+        &self,
+        instance: &mut Instance,
+    ) -> crate::Result<crate::async_import::UnixStream> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            sleep(self.backoff(attempt)).await;
+            self.resolve(instance);
+            match instance.get_event_stream_async().await {
+                Ok(stream) => return Ok(stream),
+                Err(e) if !self.should_retry(attempt) => return Err(e),
+                Err(_) => {}
+            }
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(all(feature = "async-lite", not(feature = "tokio")))]
+async fn sleep(duration: Duration) {
+    async_io::Timer::after(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_listener::EventListener;
+    use crate::testing::MockHyprland;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_backoff() {
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(1000),
+            multiplier: 3,
+            #[cfg(feature = "ctl")]
+            resolve_instance: false,
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(300));
+        assert_eq!(policy.backoff(3), Duration::from_millis(900));
+        assert_eq!(policy.backoff(4), Duration::from_millis(1000));
+        assert_eq!(policy.backoff(100), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn test_listener_reconnects() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let instance = mock.instance().clone();
+        let log = Rc::new(RefCell::new(vec![]));
+
+        let mut listener = EventListener::new();
+        listener.set_reconnect_policy(ReconnectPolicy {
+            initial_backoff: Duration::from_millis(10),
+            max_attempts: Some(3),
+            #[cfg(feature = "ctl")]
+            resolve_instance: false,
+            ..Default::default()
+        });
+        let handler_log = log.clone();
+        listener.add_sub_map_changed_handler(move |submap| handler_log.borrow_mut().push(submap));
+        let handler_log = log.clone();
+        listener
+            .add_disconnected_handler(move || handler_log.borrow_mut().push("disconnected".into()));
+        let handler_log = log.clone();
+        listener
+            .add_reconnected_handler(move |_| handler_log.borrow_mut().push("reconnected".into()));
+
+        let server = std::thread::spawn(move || -> crate::Result<()> {
+            assert!(mock.wait_for_event_clients(1, Duration::from_secs(5)));
+            mock.push_event("submap>>resize")?;
+            mock.disconnect_event_clients();
+            assert!(mock.wait_for_event_clients(1, Duration::from_secs(5)));
+            mock.push_event("submap>>move")?;
+            std::thread::sleep(Duration::from_millis(50));
+            // dropping the mock removes the socket, so reconnecting fails
+            Ok(())
+        });
+        let result = listener.instance_start_listener(&instance);
+        assert!(matches!(server.join(), Ok(Ok(()))));
+        assert!(result.is_err());
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                "resize",
+                "disconnected",
+                "reconnected",
+                "move",
+                "disconnected"
+            ]
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_stream_reconnects() -> crate::Result<()> {
+        use crate::event_listener::{Event, EventStream};
+        use futures_lite::StreamExt;
+
+        let mock = MockHyprland::new()?;
+        let mut stream = EventStream::instance_with_reconnect(
+            mock.instance().clone(),
+            ReconnectPolicy {
+                initial_backoff: Duration::from_millis(10),
+                max_attempts: Some(3),
+                #[cfg(feature = "ctl")]
+                resolve_instance: false,
+                ..Default::default()
+            },
+        );
+        let server = std::thread::spawn(move || -> crate::Result<()> {
+            assert!(mock.wait_for_event_clients(1, Duration::from_secs(5)));
+            mock.disconnect_event_clients();
+            assert!(mock.wait_for_event_clients(1, Duration::from_secs(5)));
+            mock.push_event("configreloaded>>")?;
+            std::thread::sleep(Duration::from_millis(50));
+            Ok(())
+        });
+        assert!(matches!(stream.next().await, Some(Ok(Event::Disconnected))));
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(Event::Reconnected(_)))
+        ));
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(Event::ConfigReloaded))
+        ));
+        assert!(matches!(stream.next().await, Some(Ok(Event::Disconnected))));
+        assert!(matches!(stream.next().await, Some(Err(_))));
+        assert!(stream.next().await.is_none());
+        assert!(matches!(server.join(), Ok(Ok(()))));
+        Ok(())
+    }
+
+    #[cfg(feature = "ctl")]
+    #[test]
+    fn test_resolve_instance() -> crate::Result<()> {
+        let old = MockHyprland::new()?;
+        let new = MockHyprland::new()?;
+        let running = new.instance().clone();
+        let resolved = || Ok(Some(running.clone()));
+        let policy = ReconnectPolicy::default();
+
+        // the old instance is still running, so it's kept
+        let mut instance = old.instance().clone();
+        policy.resolve_with(&mut instance, || panic!("resolved a running instance"));
+        assert!(instance.event_socket_exists());
+
+        drop(old);
+        assert!(!instance.event_socket_exists());
+        ReconnectPolicy {
+            resolve_instance: false,
+            ..policy.clone()
+        }
+        .resolve_with(&mut instance, resolved);
+        assert!(!instance.event_socket_exists());
+
+        policy.resolve_with(&mut instance, resolved);
+        assert!(instance.event_socket_exists());
+        assert_eq!(
+            instance.get_event_stream()?.peer_addr()?.as_pathname(),
+            Some(new.path().join(".socket2.sock").as_path())
+        );
+        Ok(())
+    }
+}
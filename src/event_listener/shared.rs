@@ -1,5 +1,9 @@
 use crate::shared::*;
-use std::{fmt::Debug, pin::Pin};
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex, PoisonError};
+#[cfg(any(feature = "async-lite", feature = "tokio"))]
+use std::{future::Future, pin::Pin};
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) enum ActiveWindowValue<T> {
@@ -72,7 +76,6 @@ pub(crate) trait HasExecutor {
     }
 }
 
-#[cfg(any(feature = "async-lite", feature = "tokio"))]
 pub(crate) fn event_primer_noexec(
     event: Event,
     abuf: &mut Vec<ActiveWindowState>,
@@ -212,16 +215,131 @@ pub(crate) fn into<T>(from: Option<(T, T)>) -> (ActiveWindowValue<T>, ActiveWind
 }
 
 pub(crate) type EventType<T> = Box<T>;
-pub(crate) type AsyncEventType<T> = Pin<Box<T>>;
 
-pub(crate) type VoidFuture = Pin<Box<dyn std::future::Future<Output = ()> + Send>>;
+#[cfg(any(feature = "async-lite", feature = "tokio"))]
+pub(crate) type HandlerFuture = Pin<Box<dyn Future<Output = crate::Result<()>> + Send>>;
+
+pub(crate) type EmptyClosure = EventType<dyn FnMut() -> crate::Result<()>>;
+pub(crate) type Closure<T> = EventType<dyn FnMut(T) -> crate::Result<()>>;
+#[cfg(any(feature = "async-lite", feature = "tokio"))]
+pub(crate) type AsyncClosure<T> = EventType<dyn Send + FnMut(T) -> HandlerFuture>;
+#[cfg(any(feature = "async-lite", feature = "tokio"))]
+pub(crate) type EmptyAsyncClosure = EventType<dyn Send + FnMut() -> HandlerFuture>;
+pub(crate) type Handlers<F> = Vec<Handler<F>>;
+pub(crate) type EmptyClosures = Handlers<EmptyClosure>;
+pub(crate) type Closures<T> = Handlers<Closure<T>>;
+#[cfg(any(feature = "async-lite", feature = "tokio"))]
+pub(crate) type EmptyAsyncClosures = Handlers<EmptyAsyncClosure>;
+#[cfg(any(feature = "async-lite", feature = "tokio"))]
+pub(crate) type AsyncClosures<T> = Handlers<AsyncClosure<T>>;
+
+/// An event handler, with the id it can be removed by
+pub(crate) struct Handler<F> {
+    pub(crate) id: HandlerId,
+    /// If the handler is removed after it executes
+    pub(crate) once: bool,
+    pub(crate) f: F,
+}
+
+impl<F> Handler<F> {
+    pub(crate) fn new(f: F, once: bool) -> Self {
+        Self {
+            id: HandlerId::next(),
+            once,
+            f,
+        }
+    }
+}
+
+/// This trait is implemented for what event handlers can return:
+/// `()` for handlers that can't fail, and [crate::Result] for those that can
+pub trait HandlerOutput {
+    /// Converts the output to a result
+    fn into_result(self) -> crate::Result<()>;
+}
+
+impl HandlerOutput for () {
+    fn into_result(self) -> crate::Result<()> {
+        Ok(())
+    }
+}
+
+impl HandlerOutput for crate::Result<()> {
+    fn into_result(self) -> crate::Result<()> {
+        self
+    }
+}
+
+/// This enum holds what an event listener does when a handler returns an error
+#[derive(Default)]
+pub enum ErrorPolicy {
+    /// Stop the listener, which returns the error
+    #[default]
+    Stop,
+    /// Print the error to stderr and keep listening
+    Log,
+    /// Pass the error to the closure and keep listening
+    Handle(Box<dyn FnMut(HyprError) + Send>),
+}
+
+impl ErrorPolicy {
+    /// Creates a policy that passes errors to the closure
+    pub fn handle(f: impl FnMut(HyprError) + Send + 'static) -> Self {
+        Self::Handle(Box::new(f))
+    }
+
+    /// Applies the policy to the error of a handler
+    pub(crate) fn apply(&mut self, error: HyprError) -> crate::Result<()> {
+        match self {
+            Self::Stop => return Err(error),
+            Self::Log => eprintln!("hyprland event handler failed: {error}"),
+            Self::Handle(f) => f(error),
+        }
+        Ok(())
+    }
+}
+
+impl Debug for ErrorPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stop => write!(f, "Stop"),
+            Self::Log => write!(f, "Log"),
+            Self::Handle(_) => write!(f, "Handle(..)"),
+        }
+    }
+}
 
-pub(crate) type EmptyClosure = EventType<dyn Fn()>;
-pub(crate) type Closure<T> = EventType<dyn Fn(T)>;
-pub(crate) type AsyncClosure<T> = AsyncEventType<dyn Sync + Send + Fn(T) -> VoidFuture>;
-pub(crate) type EmptyAsyncClosure = AsyncEventType<dyn Sync + Send + Fn() -> VoidFuture>;
-pub(crate) type Closures<T> = Vec<Closure<T>>;
-pub(crate) type AsyncClosures<T> = Vec<AsyncClosure<T>>;
+/// This struct identifies an event handler, which can be removed with it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HandlerId(u64);
+
+impl HandlerId {
+    fn next() -> Self {
+        // unique across listeners, so an id can't remove the handler of another listener
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, AtomicOrdering::Relaxed))
+    }
+}
+
+/// This struct removes handlers from a running event listener, before it handles the next event.
+/// It can be cloned and sent to other threads, or moved into handlers
+#[derive(Debug, Clone, Default)]
+pub struct HandlerRemover(Arc<Mutex<Vec<HandlerId>>>);
+
+impl HandlerRemover {
+    /// This method removes the handler before the next event is handled
+    pub fn remove(&self, id: HandlerId) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(id);
+    }
+
+    /// Returns the handlers to remove
+    pub(crate) fn take(&self) -> Vec<HandlerId> {
+        std::mem::take(&mut *self.0.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
 
 /// Event data for screencast event
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -377,22 +495,22 @@ impl State {
     }
 }
 
-pub(crate) fn execute_empty_closure(f: &EmptyClosure) {
-    f();
+pub(crate) fn execute_empty_closure(f: &mut EmptyClosure) -> crate::Result<()> {
+    f()
 }
 
-pub(crate) fn execute_closure<T: Clone>(f: &Closure<T>, val: T) {
-    f(val);
+pub(crate) fn execute_closure<T: Clone>(f: &mut Closure<T>, val: T) -> crate::Result<()> {
+    f(val)
 }
 
 #[cfg(any(feature = "async-lite", feature = "tokio"))]
-pub(crate) async fn execute_empty_closure_async(f: &EmptyAsyncClosure) {
-    f().await;
+pub(crate) async fn execute_empty_closure_async(f: &mut EmptyAsyncClosure) -> crate::Result<()> {
+    f().await
 }
 
 #[cfg(any(feature = "async-lite", feature = "tokio"))]
-pub(crate) async fn execute_closure_async<T>(f: &AsyncClosure<T>, val: T) {
-    f(val).await;
+pub(crate) async fn execute_closure_async<T>(f: &mut AsyncClosure<T>, val: T) -> crate::Result<()> {
+    f(val).await
 }
 
 /// This struct holds workspace event data
@@ -512,6 +630,53 @@ impl UnknownEventData {
             .collect()
     }
 }
+/// This struct holds the data for the [Event::ActiveMonitorChangedV2] event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonitorV2EventData {
+    /// The monitor name
+    pub monitor_name: String,
+    /// The id of the active workspace on the monitor, if it has one
+    pub workspace_id: Option<WorkspaceId>,
+}
+
+/// This struct holds the data for the [Event::ActiveSpecialChanged] event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveSpecialEventData {
+    /// The monitor name
+    pub monitor_name: String,
+    /// The special workspace id, [None] if the special workspace was closed
+    pub workspace_id: Option<WorkspaceId>,
+    /// The special workspace name, [None] if the special workspace was closed
+    pub workspace_name: Option<String>,
+}
+
+/// This struct holds the data for the [Event::WorkspaceMovedV1] event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceMovedV1EventData {
+    /// The workspace name
+    pub name: WorkspaceType,
+    /// The monitor name
+    pub monitor: String,
+}
+
+/// This struct holds the data for the [Event::WindowMovedV1] event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowMovedV1EventData {
+    /// Window address
+    pub window_address: Address,
+    /// The workspace name
+    pub workspace_name: WorkspaceType,
+}
+
+/// This struct holds the data for the [Event::WindowMinimized] event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowMinimizedEventData {
+    /// The window address
+    pub address: Address,
+    /// The minimized state
+    pub minimized: bool,
+}
+
 /// This struct holds the data for the [Event::GroupToggled] event
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GroupToggledEventData {
@@ -623,9 +788,54 @@ pub enum Event {
     /// And event that emits when a window is moved out of a group,
     /// it is the equivelant of the `moveoutofgroup`
     WindowMovedOutOfGroup(Address),
+    /// An event that emits when the active monitor is changed, with the id of its workspace,
+    /// it is the equivelant of the `focusedmonv2` event
+    ActiveMonitorChangedV2(MonitorV2EventData),
+    /// An event that emits when a special workspace is opened or closed on a monitor,
+    /// it is the equivelant of the `activespecialv2` event
+    ActiveSpecialChanged(ActiveSpecialEventData),
+    /// An event that emits when the current workspace is changed, with only its name,
+    /// it is the equivelant of the `workspace` event, which is sent along with [Event::WorkspaceChanged]
+    WorkspaceChangedV1(WorkspaceType),
+    /// An event that emits when a workspace is created, with only its name,
+    /// it is the equivelant of the `createworkspace` event, which is sent along with [Event::WorkspaceAdded]
+    WorkspaceAddedV1(WorkspaceType),
+    /// An event that emits when a workspace is destroyed, with only its name,
+    /// it is the equivelant of the `destroyworkspace` event, which is sent along with [Event::WorkspaceDeleted]
+    WorkspaceDeletedV1(WorkspaceType),
+    /// An event that emits when a workspace is moved to another monitor, without its id,
+    /// it is the equivelant of the `moveworkspace` event, which is sent along with [Event::WorkspaceMoved]
+    WorkspaceMovedV1(WorkspaceMovedV1EventData),
+    /// An event that emits when a new monitor is added/connected, with only its name,
+    /// it is the equivelant of the `monitoradded` event, which is sent along with [Event::MonitorAdded]
+    MonitorAddedV1(String),
+    /// An event that emits when a monitor is removed/disconnected, with its id and description,
+    /// it is the equivelant of the `monitorremovedv2` event, which is sent along with [Event::MonitorRemoved]
+    MonitorRemovedV2(MonitorAddedEventData),
+    /// An event that emits when a window is moved to a different workspace, without the workspace id,
+    /// it is the equivelant of the `movewindow` event, which is sent along with [Event::WindowMoved]
+    WindowMovedV1(WindowMovedV1EventData),
+    /// An event that emits when the title of a window changes, without the title,
+    /// it is the equivelant of the `windowtitle` event, which is sent along with [Event::WindowTitleChanged]
+    WindowTitleChangedV1(Address),
+    /// An event that emits when a window requests to be minimized or unminimized,
+    /// it is the equivelant of the `minimized` event
+    WindowMinimized(WindowMinimizedEventData),
+    /// An event that emits when a window rings the system bell, if the window is known,
+    /// it is the equivelant of the `bell` event
+    Bell(Option<Address>),
+    /// An event that emits when the `event` dispatcher is called, with its data,
+    /// it is the equivelant of the `custom` event
+    Custom(String),
+    /// An event that emits when the event socket is closed,
+    /// only sent when a [ReconnectPolicy](super::ReconnectPolicy) is set
+    Disconnected,
+    /// An event that emits when the event socket is connected again after [Event::Disconnected],
+    /// it holds the instance that was connected to
+    Reconnected(Instance),
 }
 
-fn parse_string_as_work(str: String) -> WorkspaceType {
+pub(crate) fn parse_string_as_work(str: String) -> WorkspaceType {
     if str == "special" {
         WorkspaceType::Special(None)
     } else if str.starts_with("special:") {
@@ -674,6 +884,71 @@ pub(crate) enum ParsedEventType {
     ToggleGroup,
     MoveIntoGroup,
     MoveOutOfGroup,
+    ActiveMonitorChangedV2,
+    ActiveSpecialV2,
+    WorkspaceChangedV1,
+    WorkspaceAddedV1,
+    WorkspaceDeletedV1,
+    WorkspaceMovedV1,
+    MonitorAddedV1,
+    MonitorRemovedV2,
+    WindowMovedV1,
+    WindowTitleChangedV1,
+    Minimized,
+    Bell,
+    Custom,
+}
+
+impl ParsedEventType {
+    /// Returns the kinds of events the type is parsed into
+    pub(crate) fn kinds(self) -> &'static [EventKind] {
+        match self {
+            Self::WorkspaceChangedV2 => &[EventKind::WorkspaceChanged],
+            Self::WorkspaceDeletedV2 => &[EventKind::WorkspaceDeleted],
+            Self::WorkspaceAddedV2 => &[EventKind::WorkspaceAdded],
+            Self::WorkspaceMovedV2 => &[EventKind::WorkspaceMoved],
+            Self::WorkspaceRename => &[EventKind::WorkspaceRenamed],
+            Self::ActiveWindowChangedV1 | Self::ActiveWindowChangedV2 => {
+                &[EventKind::ActiveWindowChanged]
+            }
+            Self::ActiveMonitorChanged => &[EventKind::ActiveMonitorChanged],
+            Self::FullscreenStateChanged => &[EventKind::FullscreenStateChanged],
+            Self::MonitorAddedV2 => &[EventKind::MonitorAdded],
+            Self::MonitorRemoved => &[EventKind::MonitorRemoved],
+            Self::WindowOpened => &[EventKind::WindowOpened],
+            Self::WindowClosed => &[EventKind::WindowClosed],
+            Self::WindowMovedV2 => &[EventKind::WindowMoved],
+            Self::ActiveSpecial => &[EventKind::SpecialRemoved, EventKind::ChangedSpecial],
+            Self::LayoutChanged => &[EventKind::LayoutChanged],
+            Self::SubMapChanged => &[EventKind::SubMapChanged],
+            Self::LayerOpened => &[EventKind::LayerOpened],
+            Self::LayerClosed => &[EventKind::LayerClosed],
+            Self::FloatStateChanged => &[EventKind::FloatStateChanged],
+            Self::UrgentStateChanged => &[EventKind::UrgentStateChanged],
+            Self::WindowTitleChangedV2 => &[EventKind::WindowTitleChanged],
+            Self::Screencast => &[EventKind::Screencast],
+            Self::ConfigReloaded => &[EventKind::ConfigReloaded],
+            Self::IgnoreGroupLock => &[EventKind::IgnoreGroupLockStateChanged],
+            Self::LockGroups => &[EventKind::LockGroupsStateChanged],
+            Self::Pin => &[EventKind::WindowPinned],
+            Self::ToggleGroup => &[EventKind::GroupToggled],
+            Self::MoveIntoGroup => &[EventKind::WindowMovedIntoGroup],
+            Self::MoveOutOfGroup => &[EventKind::WindowMovedOutOfGroup],
+            Self::ActiveMonitorChangedV2 => &[EventKind::ActiveMonitorChangedV2],
+            Self::ActiveSpecialV2 => &[EventKind::ActiveSpecialChanged],
+            Self::WorkspaceChangedV1 => &[EventKind::WorkspaceChangedV1],
+            Self::WorkspaceAddedV1 => &[EventKind::WorkspaceAddedV1],
+            Self::WorkspaceDeletedV1 => &[EventKind::WorkspaceDeletedV1],
+            Self::WorkspaceMovedV1 => &[EventKind::WorkspaceMovedV1],
+            Self::MonitorAddedV1 => &[EventKind::MonitorAddedV1],
+            Self::MonitorRemovedV2 => &[EventKind::MonitorRemovedV2],
+            Self::WindowMovedV1 => &[EventKind::WindowMovedV1],
+            Self::WindowTitleChangedV1 => &[EventKind::WindowTitleChangedV1],
+            Self::Minimized => &[EventKind::WindowMinimized],
+            Self::Bell => &[EventKind::Bell],
+            Self::Custom => &[EventKind::Custom],
+        }
+    }
 }
 
 /// All Hyprland events's arg count and enum variant.
@@ -720,37 +995,53 @@ pub(crate) static EVENTS: &[(&str, (usize, ParsedEventType))] = &[
     ("togglegroup", (2, ParsedEventType::ToggleGroup)),
     ("moveintogroup", (1, ParsedEventType::MoveIntoGroup)),
     ("moveoutofgroup", (1, ParsedEventType::MoveOutOfGroup)),
+    ("focusedmonv2", (2, ParsedEventType::ActiveMonitorChangedV2)),
+    ("activespecialv2", (3, ParsedEventType::ActiveSpecialV2)),
+    ("workspace", (1, ParsedEventType::WorkspaceChangedV1)),
+    ("createworkspace", (1, ParsedEventType::WorkspaceAddedV1)),
+    ("destroyworkspace", (1, ParsedEventType::WorkspaceDeletedV1)),
+    ("moveworkspace", (2, ParsedEventType::WorkspaceMovedV1)),
+    ("monitoradded", (1, ParsedEventType::MonitorAddedV1)),
+    ("monitorremovedv2", (3, ParsedEventType::MonitorRemovedV2)),
+    ("movewindow", (2, ParsedEventType::WindowMovedV1)),
+    ("windowtitle", (1, ParsedEventType::WindowTitleChangedV1)),
+    ("minimized", (2, ParsedEventType::Minimized)),
+    ("bell", (1, ParsedEventType::Bell)),
+    ("custom", (1, ParsedEventType::Custom)),
 ];
 
 use crate::default_instance;
 use crate::error::HyprError;
+use crate::event_listener::{EventFilter, EventKind};
 use crate::instance::Instance;
 use either::Either;
 
 type KnownEvent = (ParsedEventType, Vec<String>);
 type UnknownEvent = (String, String);
 
-fn new_event_parser(input: &str) -> crate::Result<Either<KnownEvent, UnknownEvent>> {
-    input
-        .to_string()
-        .split_once(">>")
-        .ok_or(HyprError::Other(
-            "could not get event name from Hyprland IPC data (not hyprland-rs)".to_string(),
+fn new_event_parser(
+    input: &str,
+    filter: &EventFilter,
+) -> crate::Result<Option<Either<KnownEvent, UnknownEvent>>> {
+    let (name, x) = input.split_once(">>").ok_or(HyprError::Other(
+        "could not get event name from Hyprland IPC data (not hyprland-rs)".to_string(),
+    ))?;
+    let event = EVENTS
+        .iter()
+        .find(|(i_name, _)| *i_name == name)
+        .map(|(_, event)| event);
+    // skip lines the filter drops before splitting their arguments
+    if !filter.accepts(event.map(|event| event.1)) {
+        return Ok(None);
+    }
+    Ok(Some(if let Some(event) = event {
+        Either::Left((
+            event.1,
+            x.splitn(event.0, ",").map(|y| y.to_string()).collect(),
         ))
-        .map(|(name, x)| {
-            if let Some(event) = EVENTS
-                .iter()
-                .find(|(i_name, _)| *i_name == name)
-                .map(|(_, event)| event)
-            {
-                Either::Left((
-                    event.1,
-                    x.splitn(event.0, ",").map(|y| y.to_string()).collect(),
-                ))
-            } else {
-                Either::Right((name.to_string(), x.to_string()))
-            }
-        })
+    } else {
+        Either::Right((name.to_string(), x.to_string()))
+    }))
 }
 
 macro_rules! parse_int {
@@ -783,12 +1074,20 @@ macro_rules! get {
 
 /// This internal function parses event strings
 pub(crate) fn event_parser(event: String) -> crate::Result<Vec<Event>> {
+    event_parser_filtered(event, &EventFilter::default())
+}
+
+/// Parses the lines, skipping the ones of kinds the filter drops
+pub(crate) fn event_parser_filtered(
+    event: String,
+    filter: &EventFilter,
+) -> crate::Result<Vec<Event>> {
     // TODO: Optimize nested looped regex capturing. Maybe pull in rayon if possible.
     let event_iter = event.trim().lines().filter_map(|event_line| {
         if event_line.is_empty() {
             None
         } else {
-            Some(new_event_parser(event_line))
+            new_event_parser(event_line, filter).transpose()
         }
     });
 
@@ -896,7 +1195,7 @@ pub(crate) fn event_parser(event: String) -> crate::Result<Vec<Event>> {
             ParsedEventType::LayerOpened => Ok(Event::LayerOpened(get![args;0])),
             ParsedEventType::LayerClosed => Ok(Event::LayerClosed(get![args;0])),
             ParsedEventType::FloatStateChanged => {
-                let state = get![ref args;1] == "0"; // FIXME: does 0 mean it's floating?
+                let state = get![ref args;1] == "1";
                 Ok(Event::FloatStateChanged(WindowFloatEventData {
                     address: Address::new(get![ref args;0]),
                     floating: state,
@@ -940,6 +1239,68 @@ pub(crate) fn event_parser(event: String) -> crate::Result<Vec<Event>> {
             ParsedEventType::MoveOutOfGroup => {
                 Ok(Event::WindowMovedOutOfGroup(Address::new(get![ref args;0])))
             }
+            ParsedEventType::ActiveMonitorChangedV2 => {
+                Ok(Event::ActiveMonitorChangedV2(MonitorV2EventData {
+                    monitor_name: get![args;0],
+                    // a monitor without a workspace has `?` or the invalid id -1
+                    workspace_id: get![ref args;1].parse().ok().filter(|id| *id != -1),
+                }))
+            }
+            ParsedEventType::ActiveSpecialV2 => {
+                let workspace_name = get![args;1];
+                Ok(Event::ActiveSpecialChanged(ActiveSpecialEventData {
+                    monitor_name: get![args;2],
+                    workspace_id: if workspace_name.is_empty() {
+                        None
+                    } else {
+                        Some(parse_int!(get![ref args;0], event: "ActiveSpecialV2"))
+                    },
+                    workspace_name: Some(workspace_name).filter(|name| !name.is_empty()),
+                }))
+            }
+            ParsedEventType::WorkspaceChangedV1 => Ok(Event::WorkspaceChangedV1(
+                parse_string_as_work(get![args;0]),
+            )),
+            ParsedEventType::WorkspaceAddedV1 => {
+                Ok(Event::WorkspaceAddedV1(parse_string_as_work(get![args;0])))
+            }
+            ParsedEventType::WorkspaceDeletedV1 => Ok(Event::WorkspaceDeletedV1(
+                parse_string_as_work(get![args;0]),
+            )),
+            ParsedEventType::WorkspaceMovedV1 => {
+                Ok(Event::WorkspaceMovedV1(WorkspaceMovedV1EventData {
+                    name: parse_string_as_work(get![args;0]),
+                    monitor: get![args;1],
+                }))
+            }
+            ParsedEventType::MonitorAddedV1 => Ok(Event::MonitorAddedV1(get![args;0])),
+            ParsedEventType::MonitorRemovedV2 => {
+                Ok(Event::MonitorRemovedV2(MonitorAddedEventData {
+                    id: parse_int!(get![ref args;0], event: "MonitorRemovedV2" => MonitorId),
+                    name: get![args;1],
+                    description: get![args;2],
+                }))
+            }
+            ParsedEventType::WindowMovedV1 => Ok(Event::WindowMovedV1(WindowMovedV1EventData {
+                window_address: Address::new(get![ref args;0]),
+                workspace_name: parse_string_as_work(get![args;1]),
+            })),
+            ParsedEventType::WindowTitleChangedV1 => {
+                Ok(Event::WindowTitleChangedV1(Address::new(get![ref args;0])))
+            }
+            ParsedEventType::Minimized => Ok(Event::WindowMinimized(WindowMinimizedEventData {
+                address: Address::new(get![ref args;0]),
+                minimized: get![ref args;1] == "1",
+            })),
+            ParsedEventType::Bell => {
+                let address = get![ref args;0];
+                Ok(Event::Bell(if address.is_empty() {
+                    None
+                } else {
+                    Some(Address::new(address))
+                }))
+            }
+            ParsedEventType::Custom => Ok(Event::Custom(get![args;0])),
         },
     });
 
@@ -951,3 +1312,156 @@ pub(crate) fn event_parser(event: String) -> crate::Result<Vec<Event>> {
 
     Ok(events)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> crate::Result<Event> {
+        let mut events = event_parser(line.to_string())?;
+        assert_eq!(events.len(), 1, "{line}");
+        Ok(events.remove(0))
+    }
+
+    #[test]
+    fn test_monitor_events() -> crate::Result<()> {
+        assert!(matches!(
+            parse("focusedmonv2>>DP-1,3")?,
+            Event::ActiveMonitorChangedV2(data)
+                if data == MonitorV2EventData { monitor_name: "DP-1".into(), workspace_id: Some(3) }
+        ));
+        assert!(matches!(
+            parse("focusedmonv2>>DP-1,-1")?,
+            Event::ActiveMonitorChangedV2(MonitorV2EventData {
+                workspace_id: None,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse("monitoradded>>HDMI-A-1")?,
+            Event::MonitorAddedV1(name) if name == "HDMI-A-1"
+        ));
+        assert!(matches!(
+            parse("monitorremovedv2>>1,HDMI-A-1,LG Electronics LG, ULTRAGEAR")?,
+            Event::MonitorRemovedV2(data) if data == MonitorAddedEventData {
+                id: 1,
+                name: "HDMI-A-1".into(),
+                description: "LG Electronics LG, ULTRAGEAR".into(),
+            }
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_special_events() -> crate::Result<()> {
+        assert!(matches!(
+            parse("activespecialv2>>-98,special:scratch,DP-1")?,
+            Event::ActiveSpecialChanged(data) if data == ActiveSpecialEventData {
+                monitor_name: "DP-1".into(),
+                workspace_id: Some(-98),
+                workspace_name: Some("special:scratch".into()),
+            }
+        ));
+        assert!(matches!(
+            parse("activespecialv2>>,,DP-1")?,
+            Event::ActiveSpecialChanged(data) if data == ActiveSpecialEventData {
+                monitor_name: "DP-1".into(),
+                workspace_id: None,
+                workspace_name: None,
+            }
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_v1_events() -> crate::Result<()> {
+        assert!(matches!(
+            parse("workspace>>web")?,
+            Event::WorkspaceChangedV1(WorkspaceType::Regular(name)) if name == "web"
+        ));
+        assert!(matches!(
+            parse("createworkspace>>special:scratch")?,
+            Event::WorkspaceAddedV1(WorkspaceType::Special(Some(name))) if name == "scratch"
+        ));
+        assert!(matches!(
+            parse("destroyworkspace>>4")?,
+            Event::WorkspaceDeletedV1(WorkspaceType::Regular(name)) if name == "4"
+        ));
+        assert!(matches!(
+            parse("moveworkspace>>2,DP-2")?,
+            Event::WorkspaceMovedV1(data) if data == WorkspaceMovedV1EventData {
+                name: WorkspaceType::Regular("2".into()),
+                monitor: "DP-2".into(),
+            }
+        ));
+        assert!(matches!(
+            parse("movewindow>>55d1c8a0e6f0,special")?,
+            Event::WindowMovedV1(data) if data == WindowMovedV1EventData {
+                window_address: Address::new("0x55d1c8a0e6f0"),
+                workspace_name: WorkspaceType::Special(None),
+            }
+        ));
+        assert!(matches!(
+            parse("windowtitle>>55d1c8a0e6f0")?,
+            Event::WindowTitleChangedV1(address) if address == Address::new("55d1c8a0e6f0")
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_events() -> crate::Result<()> {
+        assert!(matches!(
+            parse("changefloatingmode>>55d1c8a0e6f0,1")?,
+            Event::FloatStateChanged(data) if data == WindowFloatEventData {
+                address: Address::new("55d1c8a0e6f0"),
+                floating: true,
+            }
+        ));
+        assert!(matches!(
+            parse("changefloatingmode>>55d1c8a0e6f0,0")?,
+            Event::FloatStateChanged(data) if data == WindowFloatEventData {
+                address: Address::new("55d1c8a0e6f0"),
+                floating: false,
+            }
+        ));
+        assert!(matches!(
+            parse("minimized>>55d1c8a0e6f0,1")?,
+            Event::WindowMinimized(data) if data == WindowMinimizedEventData {
+                address: Address::new("55d1c8a0e6f0"),
+                minimized: true,
+            }
+        ));
+        assert!(matches!(
+            parse("bell>>55d1c8a0e6f0")?,
+            Event::Bell(Some(address)) if address == Address::new("55d1c8a0e6f0")
+        ));
+        assert!(matches!(parse("bell>>")?, Event::Bell(None)));
+        assert!(matches!(
+            parse("custom>>reload, now")?,
+            Event::Custom(data) if data == "reload, now"
+        ));
+        assert!(matches!(
+            parse("notanevent>>1,2")?,
+            Event::Unknown(data) if data.name == "notanevent" && data.args == "1,2"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_kinds() {
+        for (name, (args, event_type)) in EVENTS {
+            let mut lines = vec![format!("{name}>>{}", vec!["1"; *args].join(","))];
+            // empty args parse into other events for some types, like a removed special workspace
+            lines.push(format!("{name}>>{}", vec![""; *args].join(",")));
+            let mut parsed = false;
+            for line in lines {
+                let Ok(event) = parse(&line) else {
+                    continue;
+                };
+                assert!(event_type.kinds().contains(&event.kind()), "{line}");
+                parsed = true;
+            }
+            assert!(parsed, "{name}");
+        }
+    }
+}
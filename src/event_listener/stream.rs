@@ -1,12 +1,14 @@
 use super::*;
 use std::{
     pin::Pin,
-    task::{Context, Poll},
+    task::{ready, Context, Poll},
 };
 
 use crate::default_instance;
+use crate::error::HyprError;
 use crate::instance::Instance;
-use futures_lite::{Stream, StreamExt};
+use crate::shared::Address;
+use futures_lite::Stream;
 
 /// Event listener, but [Stream]
 /// This is the new prefered way of listening for events
@@ -30,7 +32,10 @@ use futures_lite::{Stream, StreamExt};
 /// ```
 #[must_use = "streams nothing unless polled"]
 pub struct EventStream {
-    stream: Pin<Box<dyn Stream<Item = crate::Result<Event>> + Send>>,
+    instance: Option<Instance>,
+    policy: Option<ReconnectPolicy>,
+    filter: EventFilter,
+    stream: Option<Pin<Box<dyn Stream<Item = crate::Result<Event>> + Send>>>,
 }
 impl Default for EventStream {
     fn default() -> Self {
@@ -41,48 +46,107 @@ impl Default for EventStream {
 impl EventStream {
     /// Creates a new [EventStream]
     pub fn new() -> Self {
-        use crate::async_import::*;
-        let stream = async_stream::try_stream! {
-        let mut stream: UnixStream = default_instance()?.get_event_stream_async().await?;
-            let mut active_windows = vec![];
-            loop {
-                let mut buffer = [0; 4096];
-                let bytes_read = stream.read(&mut buffer).await?;
-                if bytes_read == 0 {
-                    // If no bytes were read, we can assume the stream is closed
-                    break;
-                }
-                let buf = &buffer[..bytes_read];
-                let string = String::from_utf8(buf.to_vec())?;
-                let parsed: Vec<Event> = event_parser(string)?;
-                for event in parsed {
-                    for primed_event in event_primer_noexec(event, &mut active_windows)? {
-                        yield primed_event;
-                    }
-                }
-            }
-        };
-        Self {
-            stream: Box::pin(stream),
-        }
+        Self::create(None, None)
     }
 
     /// Creates a new [EventStream]
     pub fn instance_new(instance: Instance) -> Self {
-        use crate::async_import::*;
+        Self::create(Some(instance), None)
+    }
+
+    /// Creates a new [EventStream] which reconnects when the event socket closes, instead of ending
+    pub fn with_reconnect(policy: ReconnectPolicy) -> Self {
+        Self::create(None, Some(policy))
+    }
+
+    /// Creates a new [EventStream] which reconnects when the event socket closes, instead of ending
+    pub fn instance_with_reconnect(instance: Instance, policy: ReconnectPolicy) -> Self {
+        Self::create(Some(instance), Some(policy))
+    }
+
+    /// This method makes the stream yield only the events that pass the filter, replacing the
+    /// filters set before. Errors are always yielded
+    ///
+    /// ```rust, no_run
+    /// use futures_lite::StreamExt;
+    /// use hyprland::event_listener::{EventFilter, EventKind, EventStream};
+    ///
+    /// # async fn function() -> hyprland::Result<()> {
+    /// let filter = EventFilter::new().kinds([EventKind::WindowOpened, EventKind::WindowClosed]);
+    /// let mut stream = EventStream::new().with_filter(filter).filter_monitor("DP-1");
+    /// while let Some(event) = stream.next().await {
+    ///     println!("{:?}", event?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_filter(mut self, filter: EventFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// This method makes the stream yield only events of these kinds.
+    /// Lines of other kinds are skipped before they are parsed
+    pub fn filter_kinds<I: IntoIterator<Item = EventKind>>(mut self, kinds: I) -> Self {
+        self.filter = self.filter.kinds(kinds);
+        self
+    }
+
+    /// This method makes the stream yield only events about the window
+    pub fn filter_window(mut self, address: Address) -> Self {
+        self.filter = self.filter.window(address);
+        self
+    }
+
+    /// This method makes the stream yield only events about the workspace with the name
+    pub fn filter_workspace(mut self, name: impl Into<String>) -> Self {
+        self.filter = self.filter.workspace(name);
+        self
+    }
+
+    /// This method makes the stream yield only events about the monitor with the name
+    pub fn filter_monitor(mut self, name: impl Into<String>) -> Self {
+        self.filter = self.filter.monitor(name);
+        self
+    }
+
+    fn create(instance: Option<Instance>, policy: Option<ReconnectPolicy>) -> Self {
+        Self {
+            instance,
+            policy,
+            filter: EventFilter::default(),
+            stream: None,
+        }
+    }
+
+    fn start(
+        instance: Option<Instance>,
+        policy: Option<ReconnectPolicy>,
+        filter: EventFilter,
+    ) -> Pin<Box<dyn Stream<Item = crate::Result<Event>> + Send>> {
         let stream = async_stream::try_stream! {
-        let mut stream: UnixStream = instance.get_event_stream_async().await?;
+            let mut instance = match instance {
+                Some(instance) => instance,
+                None => default_instance()?.clone(),
+            };
+            let mut stream = instance.get_event_stream_async().await?;
             let mut active_windows = vec![];
+            let mut reader = EventLineReader::default();
             loop {
-                let mut buffer = [0; 4096];
-                let bytes_read = stream.read(&mut buffer).await?;
-                if bytes_read == 0 {
-                    // If no bytes were read, we can assume the stream is closed
-                    break;
-                }
-                let buf = &buffer[..bytes_read];
-                let string = String::from_utf8(buf.to_vec())?;
-                let parsed: Vec<Event> = event_parser(string)?;
+                let lines = match (reader.read_lines_async(&mut stream).await, &policy) {
+                    (Ok(Some(lines)), _) => lines,
+                    (Ok(None) | Err(HyprError::IoError(_)), Some(policy)) => {
+                        yield Event::Disconnected;
+                        stream = policy.reconnect_async(&mut instance).await?;
+                        active_windows.clear();
+                        reader = EventLineReader::default();
+                        yield Event::Reconnected(instance.clone());
+                        continue;
+                    }
+                    (Ok(None), None) => break,
+                    (Err(e), _) => Err(e)?,
+                };
+                let parsed: Vec<Event> = event_parser_filtered(lines, &filter)?;
                 for event in parsed {
                     for primed_event in event_primer_noexec(event, &mut active_windows)? {
                         yield primed_event;
@@ -90,16 +154,28 @@ impl EventStream {
                 }
             }
         };
-        Self {
-            stream: Box::pin(stream),
-        }
+        Box::pin(stream)
     }
 }
 
 impl Stream for EventStream {
     type Item = crate::Result<Event>;
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.as_mut().stream.poll_next(cx)
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        // the stream starts on the first poll, so the filters set before it apply to parsing
+        let stream = this.stream.get_or_insert_with(|| {
+            Self::start(
+                this.instance.take(),
+                this.policy.take(),
+                this.filter.clone(),
+            )
+        });
+        loop {
+            match ready!(stream.as_mut().poll_next(cx)) {
+                Some(Ok(event)) if !this.filter.matches(&event) => continue,
+                item => return Poll::Ready(item),
+            }
+        }
     }
 }
@@ -0,0 +1,500 @@
+//! # Geometry module
+//!
+//! This module provides points and rectangles in Hyprland's global layout coordinates,
+//! and relates monitors, clients and the cursor to them
+//!
+//! ## Usage
+//!
+//! ```rust, no_run
+//! use hyprland::data::{Client, CursorPosition, Monitors};
+//! use hyprland::geometry::monitor_at;
+//! use hyprland::prelude::*;
+//!
+//! fn main() -> hyprland::Result<()> {
+//!     let monitors = Monitors::get()?;
+//!     let cursor = CursorPosition::get()?;
+//!     if let Some(monitor) = monitor_at(&monitors, cursor.into()) {
+//!         println!("the cursor is on {}, usable area {:?}", monitor.name, monitor.usable_rect());
+//!     }
+//!     if let Some(client) = Client::get_active()? {
+//!         println!("the active window is at {:?}", client.rect());
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+#[cfg(feature = "data")]
+use crate::data::{Client, CursorPosition, Monitor, Snapshot, Transforms};
+#[cfg(feature = "dispatch")]
+use crate::dispatch::Position;
+
+/// This struct holds a point in logical global coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    /// The x coordinate
+    pub x: i32,
+    /// The y coordinate
+    pub y: i32,
+}
+
+impl Point {
+    /// Creates a point
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the point moved by the offsets
+    pub fn offset(self, dx: i32, dy: i32) -> Self {
+        Self::new(self.x.saturating_add(dx), self.y.saturating_add(dy))
+    }
+
+    /// Converts the point into an exact [Position], as used by [DispatchType::MoveWindowPixel](crate::dispatch::DispatchType::MoveWindowPixel)
+    #[cfg(feature = "dispatch")]
+    pub fn to_position(self) -> Position {
+        Position::Exact(clamp_i16(self.x), clamp_i16(self.y))
+    }
+
+    /// Converts the distance to another point into a [Position::Delta]
+    #[cfg(feature = "dispatch")]
+    pub fn delta_to(self, other: Point) -> Position {
+        Position::Delta(
+            clamp_i16(other.x.saturating_sub(self.x)),
+            clamp_i16(other.y.saturating_sub(self.y)),
+        )
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// This enum holds the edges of a [Rect]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edge {
+    /// The left edge
+    Left,
+    /// The top edge
+    Top,
+    /// The right edge
+    Right,
+    /// The bottom edge
+    Bottom,
+}
+
+/// This struct holds a rectangle in logical global coordinates
+///
+/// The right and bottom edges are exclusive, so `x + width` is outside the rectangle.
+/// Coordinates are computed with saturating arithmetic, so they stop at the bounds of `i32`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rect {
+    /// The x coordinate of the top left corner
+    pub x: i32,
+    /// The y coordinate of the top left corner
+    pub y: i32,
+    /// The width
+    pub width: i32,
+    /// The height
+    pub height: i32,
+}
+
+impl Rect {
+    /// Creates a rectangle
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the top left corner
+    pub fn origin(&self) -> Point {
+        Point::new(self.x, self.y)
+    }
+
+    /// Returns the x coordinate after the right edge
+    pub fn right(&self) -> i32 {
+        self.x.saturating_add(self.width)
+    }
+
+    /// Returns the y coordinate after the bottom edge
+    pub fn bottom(&self) -> i32 {
+        self.y.saturating_add(self.height)
+    }
+
+    /// Returns the center, rounded towards the top left
+    pub fn center(&self) -> Point {
+        Point::new(
+            self.x.saturating_add(self.width / 2),
+            self.y.saturating_add(self.height / 2),
+        )
+    }
+
+    /// Returns the area
+    pub fn area(&self) -> i64 {
+        i64::from(self.width.max(0)) * i64::from(self.height.max(0))
+    }
+
+    /// Returns true if the rectangle has no area
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+
+    /// Returns the rectangle moved by the offsets
+    pub fn offset(&self, dx: i32, dy: i32) -> Self {
+        Self::new(
+            self.x.saturating_add(dx),
+            self.y.saturating_add(dy),
+            self.width,
+            self.height,
+        )
+    }
+
+    /// Returns the rectangle shrunk by the amounts on each edge
+    pub fn inset(&self, left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        Self::new(
+            self.x.saturating_add(left),
+            self.y.saturating_add(top),
+            self.width.saturating_sub(left).saturating_sub(right).max(0),
+            self.height
+                .saturating_sub(top)
+                .saturating_sub(bottom)
+                .max(0),
+        )
+    }
+
+    /// Returns true if the point is inside the rectangle
+    pub fn contains(&self, point: Point) -> bool {
+        (self.x..self.right()).contains(&point.x) && (self.y..self.bottom()).contains(&point.y)
+    }
+
+    /// Returns true if the other rectangle is completely inside this one
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.right() <= self.right()
+            && other.bottom() <= self.bottom()
+    }
+
+    /// Returns the area both rectangles cover, if any
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let rect = Rect::new(
+            x,
+            y,
+            self.right().min(other.right()).saturating_sub(x),
+            self.bottom().min(other.bottom()).saturating_sub(y),
+        );
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    /// Returns true if the rectangles overlap
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the edge of this rectangle the other one touches from outside,
+    /// if they share a part of it without overlapping
+    pub fn adjacent_edge(&self, other: &Rect) -> Option<Edge> {
+        let shares_x = self.x < other.right() && other.x < self.right();
+        let shares_y = self.y < other.bottom() && other.y < self.bottom();
+        if shares_y && other.right() == self.x {
+            Some(Edge::Left)
+        } else if shares_y && other.x == self.right() {
+            Some(Edge::Right)
+        } else if shares_x && other.bottom() == self.y {
+            Some(Edge::Top)
+        } else if shares_x && other.y == self.bottom() {
+            Some(Edge::Bottom)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the rectangles touch without overlapping
+    pub fn is_adjacent(&self, other: &Rect) -> bool {
+        self.adjacent_edge(other).is_some()
+    }
+
+    /// Converts the top left corner into an exact [Position], as used by [DispatchType::MoveWindowPixel](crate::dispatch::DispatchType::MoveWindowPixel)
+    #[cfg(feature = "dispatch")]
+    pub fn move_position(&self) -> Position {
+        self.origin().to_position()
+    }
+
+    /// Converts the size into an exact [Position], as used by [DispatchType::ResizeWindowPixel](crate::dispatch::DispatchType::ResizeWindowPixel)
+    #[cfg(feature = "dispatch")]
+    pub fn resize_position(&self) -> Position {
+        Position::Exact(clamp_i16(self.width), clamp_i16(self.height))
+    }
+}
+
+#[cfg(feature = "dispatch")]
+fn clamp_i16(value: i32) -> i16 {
+    value.clamp(i16::MIN.into(), i16::MAX.into()) as i16
+}
+
+#[cfg(feature = "data")]
+impl Transforms {
+    /// Returns true if the transform rotates by 90 or 270 degrees, swapping width and height
+    pub fn is_rotated(self) -> bool {
+        matches!(
+            self,
+            Transforms::Normal90
+                | Transforms::Normal270
+                | Transforms::Flipped90
+                | Transforms::Flipped270
+        )
+    }
+}
+
+#[cfg(feature = "data")]
+impl Monitor {
+    /// This method returns the size in pixels after applying the transform
+    pub fn physical_size(&self) -> (i32, i32) {
+        let (width, height) = (i32::from(self.width), i32::from(self.height));
+        if self.transform.is_rotated() {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    /// This method returns the size in layout coordinates, which is the physical size divided by the scale
+    pub fn logical_size(&self) -> (i32, i32) {
+        let scale = if self.scale > 0.0 { self.scale } else { 1.0 };
+        let (width, height) = self.physical_size();
+        (
+            (width as f32 / scale).round() as i32,
+            (height as f32 / scale).round() as i32,
+        )
+    }
+
+    /// This method returns the area of the monitor in the layout
+    pub fn rect(&self) -> Rect {
+        let (width, height) = self.logical_size();
+        Rect::new(self.x, self.y, width, height)
+    }
+
+    /// This method returns the area windows can be tiled in,
+    /// which excludes the space reserved by bars and other layers
+    pub fn usable_rect(&self) -> Rect {
+        let (left, top, right, bottom) = self.reserved;
+        self.rect()
+            .inset(left.into(), top.into(), right.into(), bottom.into())
+    }
+}
+
+#[cfg(feature = "data")]
+impl Client {
+    /// This method returns the area of the window in the layout
+    pub fn rect(&self) -> Rect {
+        Rect::new(
+            self.at.0.into(),
+            self.at.1.into(),
+            self.size.0.into(),
+            self.size.1.into(),
+        )
+    }
+}
+
+#[cfg(feature = "data")]
+impl From<CursorPosition> for Point {
+    fn from(cursor: CursorPosition) -> Self {
+        Self::new(
+            cursor.x.clamp(i32::MIN.into(), i32::MAX.into()) as i32,
+            cursor.y.clamp(i32::MIN.into(), i32::MAX.into()) as i32,
+        )
+    }
+}
+
+/// Returns the enabled monitor containing the point
+#[cfg(feature = "data")]
+pub fn monitor_at<'a>(
+    monitors: impl IntoIterator<Item = &'a Monitor>,
+    point: Point,
+) -> Option<&'a Monitor> {
+    monitors
+        .into_iter()
+        .find(|monitor| !monitor.disabled && monitor.rect().contains(point))
+}
+
+/// Returns the enabled monitor covering the most of the rectangle, like the one a window is shown on
+#[cfg(feature = "data")]
+pub fn monitor_for_rect<'a>(
+    monitors: impl IntoIterator<Item = &'a Monitor>,
+    rect: &Rect,
+) -> Option<&'a Monitor> {
+    monitors
+        .into_iter()
+        .filter(|monitor| !monitor.disabled)
+        .filter_map(|monitor| Some((monitor.rect().intersection(rect)?.area(), monitor)))
+        .max_by_key(|(area, _)| *area)
+        .map(|(_, monitor)| monitor)
+}
+
+#[cfg(feature = "data")]
+impl Snapshot {
+    /// This method returns the enabled monitor containing the point
+    pub fn monitor_at(&self, point: Point) -> Option<&Monitor> {
+        monitor_at(self.monitors(), point)
+    }
+
+    /// This method returns the clients whose area contains the point, most recently focused first
+    pub fn clients_at(&self, point: Point) -> Vec<&Client> {
+        let mut clients: Vec<&Client> = self
+            .clients()
+            .iter()
+            .filter(|client| client.mapped && client.rect().contains(point))
+            .collect();
+        clients.sort_by_key(|c| (c.focus_history_id < 0, c.focus_history_id));
+        clients
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect() {
+        let rect = Rect::new(0, 0, 100, 50);
+        assert!(rect.contains(Point::new(0, 0)));
+        assert!(rect.contains(Point::new(99, 49)));
+        assert!(!rect.contains(Point::new(100, 49)));
+        assert_eq!(rect.center(), Point::new(50, 25));
+        assert_eq!(rect.inset(10, 5, 10, 5), Rect::new(10, 5, 80, 40));
+        assert!(rect.contains_rect(&Rect::new(10, 5, 80, 40)));
+        assert!(!rect.contains_rect(&Rect::new(10, 5, 100, 40)));
+
+        let other = Rect::new(50, 25, 100, 100);
+        assert_eq!(rect.intersection(&other), Some(Rect::new(50, 25, 50, 25)));
+        assert!(rect.overlaps(&other));
+        assert!(!rect.is_adjacent(&other));
+    }
+
+    #[test]
+    fn test_extremes() {
+        let rect = Rect::new(i32::MAX - 10, i32::MIN + 10, 100, -100);
+        assert_eq!(rect.right(), i32::MAX);
+        assert_eq!(rect.bottom(), i32::MIN);
+        assert_eq!(rect.center(), Point::new(i32::MAX, i32::MIN));
+        assert_eq!(
+            rect.offset(i32::MAX, i32::MIN),
+            Rect::new(i32::MAX, i32::MIN, 100, -100)
+        );
+        assert_eq!(
+            Point::new(i32::MIN, i32::MAX).offset(-1, 1),
+            Point::new(i32::MIN, i32::MAX)
+        );
+        assert_eq!(
+            Rect::new(0, 0, i32::MIN, 10).inset(0, 0, i32::MAX, 0),
+            Rect::new(0, 0, 0, 10)
+        );
+        let huge = Rect::new(i32::MIN, i32::MIN, i32::MAX, i32::MAX);
+        assert!(!huge.contains(Point::new(0, 0)));
+        assert_eq!(
+            huge.intersection(&Rect::new(i32::MAX, i32::MAX, -10, -10)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_adjacency() {
+        let rect = Rect::new(0, 0, 100, 100);
+        assert_eq!(
+            rect.adjacent_edge(&Rect::new(100, 50, 10, 10)),
+            Some(Edge::Right)
+        );
+        assert_eq!(
+            rect.adjacent_edge(&Rect::new(-10, 0, 10, 100)),
+            Some(Edge::Left)
+        );
+        assert_eq!(
+            rect.adjacent_edge(&Rect::new(20, -5, 10, 5)),
+            Some(Edge::Top)
+        );
+        assert_eq!(
+            rect.adjacent_edge(&Rect::new(0, 100, 100, 1)),
+            Some(Edge::Bottom)
+        );
+        // touching only at a corner is not adjacent
+        assert_eq!(rect.adjacent_edge(&Rect::new(100, 100, 10, 10)), None);
+        assert!(!rect.overlaps(&Rect::new(100, 0, 10, 10)));
+    }
+
+    #[cfg(feature = "dispatch")]
+    #[test]
+    fn test_positions() {
+        let rect = Rect::new(10, 20, 300, 200);
+        assert_eq!(rect.move_position().to_string(), "exact 10 20");
+        assert_eq!(rect.resize_position().to_string(), "exact 300 200");
+        assert_eq!(
+            Point::new(10, 20).delta_to(Point::new(0, 40)).to_string(),
+            "-10 20"
+        );
+        assert_eq!(
+            Point::new(100_000, -100_000).to_position().to_string(),
+            "exact 32767 -32768"
+        );
+    }
+
+    #[cfg(feature = "data")]
+    #[test]
+    fn test_monitors() -> crate::Result<()> {
+        use crate::data::Monitors;
+        use crate::shared::{HyprData, HyprDataVec};
+        use crate::testing::{fixtures, MockHyprland};
+
+        let mock = MockHyprland::new()?;
+        let mut monitors = Monitors::instance_get(mock.instance())?.to_vec();
+        let mut second = monitors[0].clone();
+
+        let first = &mut monitors[0];
+        first.width = 3840;
+        first.height = 2160;
+        first.scale = 1.5;
+        first.reserved = (0, 30, 0, 0);
+        assert_eq!(first.physical_size(), (3840, 2160));
+        assert_eq!(first.rect(), Rect::new(0, 0, 2560, 1440));
+        assert_eq!(first.usable_rect(), Rect::new(0, 30, 2560, 1410));
+
+        second.name = "HDMI-A-1".into();
+        second.width = 1920;
+        second.height = 1080;
+        second.scale = 1.0;
+        second.transform = Transforms::Normal90;
+        second.x = 2560;
+        assert_eq!(second.rect(), Rect::new(2560, 0, 1080, 1920));
+        monitors.push(second);
+
+        let first = &monitors[0];
+        let second = &monitors[1];
+        assert!(first.rect().is_adjacent(&second.rect()));
+        let name = |m: Option<&Monitor>| m.map(|m| m.name.clone());
+        assert_eq!(
+            name(monitor_at(&monitors, Point::new(2560, 0))),
+            Some("HDMI-A-1".into())
+        );
+        assert_eq!(name(monitor_at(&monitors, Point::new(0, 1900))), None);
+        assert_eq!(
+            name(monitor_for_rect(&monitors, &Rect::new(2000, 0, 1000, 100))),
+            Some("DP-1".into())
+        );
+
+        let clients: Vec<Client> = serde_json::from_str(fixtures::CLIENTS)?;
+        let snapshot = Snapshot::new(monitors, vec![], clients);
+        let client = &snapshot.clients()[0];
+        assert_eq!(snapshot.clients_at(client.rect().center()), vec![client]);
+        let cursor = CursorPosition { x: 2600, y: 10 };
+        assert_eq!(
+            name(snapshot.monitor_at(cursor.into())),
+            Some("HDMI-A-1".into())
+        );
+        Ok(())
+    }
+}
@@ -64,9 +64,14 @@ impl Instance {
 
 impl Instance {
     pub(crate) fn write_to_socket(&self, content: CommandContent) -> crate::Result<String> {
+        self.write_raw_to_socket(&content.as_bytes())
+    }
+
+    /// Writes the request as-is, without any command flags prepended
+    pub(crate) fn write_raw_to_socket(&self, request: &[u8]) -> crate::Result<String> {
         use std::io::{Read, Write};
         let mut stream = std::os::unix::net::UnixStream::connect(&self.stream)?;
-        stream.write_all(&content.as_bytes())?;
+        stream.write_all(request)?;
         let mut response = Vec::new();
         stream.read_to_end(&mut response)?;
         Ok(String::from_utf8(response)?)
@@ -77,9 +82,15 @@ impl Instance {
         &self,
         content: CommandContent,
     ) -> crate::Result<String> {
+        self.write_raw_to_socket_async(&content.as_bytes()).await
+    }
+
+    /// Writes the request as-is, without any command flags prepended (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub(crate) async fn write_raw_to_socket_async(&self, request: &[u8]) -> crate::Result<String> {
         use crate::async_import::{AsyncReadExt, AsyncWriteExt};
         let mut stream = crate::async_import::UnixStream::connect(&self.stream).await?;
-        stream.write_all(&content.as_bytes()).await?;
+        stream.write_all(request).await?;
         let mut response = Vec::new();
         stream.read_to_end(&mut response).await?;
         Ok(String::from_utf8(response)?)
@@ -111,6 +122,11 @@ impl Instance {
         Ok(String::from_utf8(response)?)
     }
 
+    #[cfg(all(feature = "listener", feature = "ctl"))]
+    pub(crate) fn event_socket_exists(&self) -> bool {
+        self.event_socket_path.exists()
+    }
+
     #[cfg(feature = "listener")]
     pub(crate) fn get_event_stream(&self) -> crate::Result<std::os::unix::net::UnixStream> {
         let stream = std::os::unix::net::UnixStream::connect(&self.event_socket_path)?;
@@ -21,12 +21,20 @@ use crate::shared::*;
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+pub mod options;
+
+/// Keyword overrides that restore the previous values
+mod guard;
+pub use crate::keyword::guard::*;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub(crate) struct OptionRaw {
     pub option: String,
     pub int: Option<i64>,
     pub float: Option<f64>,
     pub str: Option<String>,
+    pub vec2: Option<[f64; 2]>,
+    pub custom: Option<String>,
     pub set: bool,
 }
 
@@ -53,6 +61,8 @@ impl From<OptionValue> for String {
 trait IsString {}
 impl IsString for String {}
 impl IsString for &str {}
+impl IsString for crate::color::Color {}
+impl IsString for crate::color::Gradient {}
 
 impl<Str: ToString + IsString> From<Str> for OptionValue {
     fn from(str: Str) -> Self {
@@ -106,18 +116,26 @@ impl Keyword {
             int,
             float,
             str,
+            vec2,
+            custom,
             set,
         }: OptionRaw,
     ) -> crate::Result<Keyword> {
         let int_exists = int.is_some() as u8;
         let float_exists = float.is_some() as u8;
         let str_exists = str.is_some() as u8;
+        let vec2_exists = vec2.is_some() as u8;
+        let custom_exists = custom.is_some() as u8;
 
         // EXPLANATION: if at least two types of value is exists then we stop execution.
-        if int_exists + float_exists + str_exists > 1 {
+        if int_exists + float_exists + str_exists + vec2_exists + custom_exists > 1 {
             hypr_err!("Expected single value type, but received more than one! Please open an issue with hyprland-rs with the information: Option {{ option: {option}, int: {int:?}, float: {float:?}, str: {str:?}, set: {set} }}!");
         }
 
+        // vec2 and custom values (like gradients) are passed as strings, the same way they are set
+        let str = str
+            .or(custom)
+            .or_else(|| vec2.map(|[x, y]| format!("{x} {y}")));
         let value = match (int, float, str) {
             (Some(int), _, _) => OptionValue::Int(int),
             (_, Some(float), _) => OptionValue::Float(float),
@@ -128,6 +146,18 @@ impl Keyword {
         Ok(Keyword { option, value, set })
     }
 
+    pub(crate) fn gen_set_str<Str: ToString, Opt: Into<OptionValue>>(
+        key: Str,
+        value: Opt,
+    ) -> CommandContent {
+        command!(
+            Empty,
+            "keyword {} {}",
+            key.to_string(),
+            value.into().to_string()
+        )
+    }
+
     /// This function sets a keyword's value
     pub fn set<Str: ToString, Opt: Into<OptionValue>>(key: Str, value: Opt) -> crate::Result<()> {
         Self::instance_set(default_instance()?, key, value)
@@ -139,12 +169,7 @@ impl Keyword {
         key: Str,
         value: Opt,
     ) -> crate::Result<()> {
-        instance.write_to_socket(command!(
-            Empty,
-            "keyword {} {}",
-            key.to_string(),
-            value.into().to_string()
-        ))?;
+        instance.write_to_socket(Self::gen_set_str(key, value))?;
         Ok(())
     }
 
@@ -165,12 +190,7 @@ impl Keyword {
         value: Opt,
     ) -> crate::Result<()> {
         instance
-            .write_to_socket_async(command!(
-                Empty,
-                "keyword {} {}",
-                key.to_string(),
-                value.into().to_string()
-            ))
+            .write_to_socket_async(Self::gen_set_str(key, value))
             .await?;
         Ok(())
     }
@@ -0,0 +1,381 @@
+use super::*;
+use crate::batch::{check_batch, Batch, BatchResponse};
+use crate::error::HyprError;
+
+/// This struct holds keywords that were overridden, and restores their previous values
+/// when it is dropped or [KeywordGuard::restore] is called
+///
+/// Guards created by the async functions restore the values on a separate thread when dropped,
+/// so dropping them doesn't block the runtime; [KeywordGuard::restore_async] waits for the restore
+/// and returns its errors
+///
+/// ```rust, no_run
+/// use hyprland::keyword::{KeywordGroup, KeywordGuard};
+/// fn main() -> hyprland::Result<()> {
+///     let _gaps = KeywordGuard::set("general:gaps_out", 0)?;
+///     let presentation = KeywordGroup::new()
+///         .set("decoration:blur:enabled", 0)
+///         .set("animations:enabled", 0)
+///         .apply()?;
+///     // ...
+///     presentation.restore()?;
+///     Ok(())
+/// }
+/// ```
+#[must_use = "the previous values are restored as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct KeywordGuard<'a> {
+    instance: &'a Instance,
+    originals: Vec<Keyword>,
+    restored: bool,
+    restore_in_thread: bool,
+}
+
+impl<'a> KeywordGuard<'a> {
+    /// This function overrides a keyword until the guard is dropped
+    pub fn set<Str: ToString, Opt: Into<OptionValue>>(
+        key: Str,
+        value: Opt,
+    ) -> crate::Result<KeywordGuard<'static>> {
+        KeywordGuard::instance_set(default_instance()?, key, value)
+    }
+
+    /// This function overrides a keyword until the guard is dropped
+    pub fn instance_set<Str: ToString, Opt: Into<OptionValue>>(
+        instance: &'a Instance,
+        key: Str,
+        value: Opt,
+    ) -> crate::Result<Self> {
+        KeywordGroup::new().set(key, value).instance_apply(instance)
+    }
+
+    /// This function overrides a keyword until the guard is dropped (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn set_async<Str: ToString, Opt: Into<OptionValue>>(
+        key: Str,
+        value: Opt,
+    ) -> crate::Result<KeywordGuard<'static>> {
+        KeywordGuard::instance_set_async(default_instance()?, key, value).await
+    }
+
+    /// This function overrides a keyword until the guard is dropped (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_set_async<Str: ToString, Opt: Into<OptionValue>>(
+        instance: &'a Instance,
+        key: Str,
+        value: Opt,
+    ) -> crate::Result<Self> {
+        KeywordGroup::new()
+            .set(key, value)
+            .instance_apply_async(instance)
+            .await
+    }
+
+    /// This method returns the values the keywords had before they were overridden
+    pub fn originals(&self) -> &[Keyword] {
+        &self.originals
+    }
+
+    /// This method keeps the overrides, so nothing is restored when the guard is dropped
+    pub fn keep(mut self) {
+        self.restored = true;
+    }
+
+    /// This method restores the previous values of the keywords
+    pub fn restore(mut self) -> crate::Result<()> {
+        self.restored = true;
+        check_batch(restore_batch(&self.originals).instance_call(self.instance)?)
+    }
+
+    /// This method restores the previous values of the keywords (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn restore_async(mut self) -> crate::Result<()> {
+        self.restored = true;
+        check_batch(
+            restore_batch(&self.originals)
+                .instance_call_async(self.instance)
+                .await?,
+        )
+    }
+}
+
+impl Drop for KeywordGuard<'_> {
+    fn drop(&mut self) {
+        if self.restored {
+            return;
+        }
+        // errors can't be returned from drop, restore() reports them
+        let batch = restore_batch(&self.originals);
+        if self.restore_in_thread {
+            let instance = self.instance.clone();
+            std::thread::spawn(move || {
+                let _ = batch.instance_call(&instance);
+            });
+        } else {
+            let _ = batch.instance_call(self.instance);
+        }
+    }
+}
+
+/// Sets the keywords back in reverse order, so a keyword overridden twice gets its first value
+fn restore_batch(originals: &[Keyword]) -> Batch {
+    originals.iter().rev().fold(Batch::new(), |batch, keyword| {
+        batch.keyword(&keyword.option, keyword.value.clone())
+    })
+}
+
+/// Returns the error that made the keywords roll back, along with the rollback error if there is one
+fn rolled_back(error: HyprError, rollback: crate::Result<()>) -> HyprError {
+    match rollback {
+        Ok(()) => error,
+        Err(rollback) => HyprError::RollbackFailed {
+            error: Box::new(error),
+            rollback: Box::new(rollback),
+        },
+    }
+}
+
+/// This struct accumulates keyword overrides, to apply them together as a [KeywordGuard]
+///
+/// If one of the keywords can't be set, the ones that were set are rolled back,
+/// and if that fails too, [HyprError::RollbackFailed] holds both errors
+#[derive(Debug, Default, Clone)]
+pub struct KeywordGroup {
+    overrides: Vec<(String, OptionValue)>,
+}
+
+impl KeywordGroup {
+    /// This method creates a new empty group
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This method adds a keyword override to the group
+    pub fn set<Str: ToString, Opt: Into<OptionValue>>(mut self, key: Str, value: Opt) -> Self {
+        self.overrides.push((key.to_string(), value.into()));
+        self
+    }
+
+    fn batch(&self) -> Batch {
+        self.overrides
+            .iter()
+            .fold(Batch::new(), |batch, (key, value)| {
+                batch.keyword(key, value.clone())
+            })
+    }
+
+    /// Keeps the originals of the keywords that were set, and rolls them back if one failed
+    fn finish<'a>(
+        instance: &'a Instance,
+        mut originals: Vec<Keyword>,
+        responses: Vec<BatchResponse>,
+        restore_in_thread: bool,
+    ) -> Result<KeywordGuard<'a>, (KeywordGuard<'a>, HyprError)> {
+        let mut error = None;
+        let mut applied = responses.into_iter().map(|response| match response {
+            BatchResponse::Keyword(Err(e)) => {
+                error.get_or_insert(e);
+                false
+            }
+            _ => true,
+        });
+        originals.retain(|_| applied.next().unwrap_or(false));
+        let guard = KeywordGuard {
+            instance,
+            originals,
+            restored: false,
+            restore_in_thread,
+        };
+        match error {
+            Some(e) => Err((guard, e)),
+            None => Ok(guard),
+        }
+    }
+
+    /// This method reads the current values of the keywords and applies the overrides
+    pub fn apply(self) -> crate::Result<KeywordGuard<'static>> {
+        self.instance_apply(default_instance()?)
+    }
+
+    /// This method reads the current values of the keywords and applies the overrides
+    pub fn instance_apply(self, instance: &Instance) -> crate::Result<KeywordGuard<'_>> {
+        let originals = self
+            .overrides
+            .iter()
+            .map(|(key, _)| Keyword::instance_get(instance, key))
+            .collect::<crate::Result<Vec<_>>>()?;
+        let responses = self.batch().instance_call(instance)?;
+        match Self::finish(instance, originals, responses, false) {
+            Ok(guard) => Ok(guard),
+            Err((guard, e)) => Err(rolled_back(e, guard.restore())),
+        }
+    }
+
+    /// This method reads the current values of the keywords and applies the overrides (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn apply_async(self) -> crate::Result<KeywordGuard<'static>> {
+        self.instance_apply_async(default_instance()?).await
+    }
+
+    /// This method reads the current values of the keywords and applies the overrides (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_apply_async(
+        self,
+        instance: &Instance,
+    ) -> crate::Result<KeywordGuard<'_>> {
+        let mut originals = Vec::with_capacity(self.overrides.len());
+        for (key, _) in &self.overrides {
+            originals.push(Keyword::instance_get_async(instance, key).await?);
+        }
+        let responses = self.batch().instance_call_async(instance).await?;
+        match Self::finish(instance, originals, responses, true) {
+            Ok(guard) => Ok(guard),
+            Err((guard, e)) => Err(rolled_back(e, guard.restore_async().await)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockHyprland;
+
+    fn mock() -> crate::Result<MockHyprland> {
+        let mock = MockHyprland::new()?;
+        mock.respond_with(
+            "getoption general:gaps_out",
+            r#"{"option": "general:gaps_out", "custom": "20 20 20 20", "set": true}"#,
+        );
+        mock.respond_with(
+            "getoption animations:enabled",
+            r#"{"option": "animations:enabled", "int": 1, "set": false}"#,
+        );
+        mock.respond_with(
+            "getoption decoration:active_opacity",
+            r#"{"option": "decoration:active_opacity", "float": 0.9, "set": true}"#,
+        );
+        Ok(mock)
+    }
+
+    #[test]
+    fn test_guard() -> crate::Result<()> {
+        let mock = mock()?;
+        {
+            let guard = KeywordGuard::instance_set(mock.instance(), "general:gaps_out", 0)?;
+            assert_eq!(guard.originals().len(), 1);
+        }
+        let guard = KeywordGuard::instance_set(mock.instance(), "animations:enabled", 0)?;
+        guard.restore()?;
+        KeywordGuard::instance_set(mock.instance(), "animations:enabled", 0)?.keep();
+        assert_eq!(
+            mock.received(),
+            [
+                "getoption general:gaps_out",
+                "keyword general:gaps_out 0",
+                "keyword general:gaps_out 20 20 20 20",
+                "getoption animations:enabled",
+                "keyword animations:enabled 0",
+                "keyword animations:enabled 1",
+                "getoption animations:enabled",
+                "keyword animations:enabled 0",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_group() -> crate::Result<()> {
+        let mock = mock()?;
+        let guard = KeywordGroup::new()
+            .set("animations:enabled", 0)
+            .set("decoration:active_opacity", 1.0)
+            .set("animations:enabled", 1)
+            .instance_apply(mock.instance())?;
+        drop(guard);
+        assert_eq!(
+            mock.received()[3..],
+            [
+                "keyword animations:enabled 0",
+                "keyword decoration:active_opacity 1",
+                "keyword animations:enabled 1",
+                "keyword animations:enabled 1",
+                "keyword decoration:active_opacity 0.9",
+                "keyword animations:enabled 1",
+            ]
+        );
+
+        mock.clear_received();
+        mock.respond_with("keyword general:gaps_out nope", "invalid value");
+        let result = KeywordGroup::new()
+            .set("animations:enabled", 0)
+            .set("general:gaps_out", "nope")
+            .set("decoration:active_opacity", 1.0)
+            .instance_apply(mock.instance());
+        assert!(matches!(result, Err(HyprError::Internal(e)) if e == "invalid value"));
+        assert_eq!(
+            mock.received()[3..],
+            [
+                "keyword animations:enabled 0",
+                "keyword general:gaps_out nope",
+                "keyword decoration:active_opacity 1",
+                "keyword decoration:active_opacity 0.9",
+                "keyword animations:enabled 1",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rollback_error() -> crate::Result<()> {
+        let mock = mock()?;
+        mock.respond_with("keyword general:gaps_out nope", "invalid value");
+        mock.respond_with("keyword animations:enabled 1", "rollback failed");
+        let result = KeywordGroup::new()
+            .set("animations:enabled", 0)
+            .set("general:gaps_out", "nope")
+            .instance_apply(mock.instance());
+        assert!(matches!(
+            result,
+            Err(HyprError::RollbackFailed { error, rollback })
+                if matches!(*error, HyprError::Internal(ref e) if e == "invalid value")
+                    && matches!(*rollback, HyprError::Internal(ref e) if e == "rollback failed")
+        ));
+        assert_eq!(
+            mock.received()[2..],
+            [
+                "keyword animations:enabled 0",
+                "keyword general:gaps_out nope",
+                "keyword animations:enabled 1",
+            ]
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_async_guard() -> crate::Result<()> {
+        let mock = mock()?;
+        let guard =
+            KeywordGuard::instance_set_async(mock.instance(), "general:gaps_out", 0).await?;
+        drop(guard);
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while mock.received().len() < 3 && std::time::Instant::now() < deadline {
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        let guard =
+            KeywordGuard::instance_set_async(mock.instance(), "animations:enabled", 0).await?;
+        guard.restore_async().await?;
+        assert_eq!(
+            mock.received(),
+            [
+                "getoption general:gaps_out",
+                "keyword general:gaps_out 0",
+                "keyword general:gaps_out 20 20 20 20",
+                "getoption animations:enabled",
+                "keyword animations:enabled 0",
+                "keyword animations:enabled 1",
+            ]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,1168 @@
+//! # Options module
+//!
+//! This module holds a typed catalog of Hyprland's config options,
+//! which are read and written with [Keyword]
+//!
+//! ## Usage
+//!
+//! ```rust, no_run
+//! use hyprland::keyword::options::{decoration, dwindle, general, ForceSplit};
+//! fn main() -> hyprland::Result<()> {
+//!     let border_size: i64 = general::BORDER_SIZE.get()?;
+//!     general::BORDER_SIZE.set(border_size + 1)?;
+//!     decoration::blur::ENABLED.set(false)?;
+//!     dwindle::FORCE_SPLIT.set(ForceSplit::Right)?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use super::{Keyword, OptionValue};
+use crate::default_instance;
+use crate::error::HyprError;
+use crate::instance::Instance;
+use std::marker::PhantomData;
+
+fn invalid_value(name: &str, value: &OptionValue) -> HyprError {
+    HyprError::InvalidOptionValue(format!("`{value}` is not a valid value for {name}"))
+}
+
+/// This trait is implemented by the types config options can hold
+pub trait OptionType: Sized {
+    /// This function converts the value Hyprland returned for the option
+    fn from_option(value: OptionValue) -> Option<Self>;
+    /// This method converts the value to what Hyprland expects when setting the option
+    fn to_option(&self) -> OptionValue;
+    /// This method returns the value as a number, for options with a minimum
+    fn as_number(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl OptionType for bool {
+    fn from_option(value: OptionValue) -> Option<Self> {
+        match value {
+            OptionValue::Int(0) => Some(false),
+            OptionValue::Int(1) => Some(true),
+            OptionValue::String(s) => match s.as_str() {
+                "true" | "yes" | "on" | "1" => Some(true),
+                "false" | "no" | "off" | "0" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+    fn to_option(&self) -> OptionValue {
+        OptionValue::Int(*self as i64)
+    }
+}
+
+impl OptionType for i64 {
+    fn from_option(value: OptionValue) -> Option<Self> {
+        match value {
+            OptionValue::Int(i) => Some(i),
+            _ => None,
+        }
+    }
+    fn to_option(&self) -> OptionValue {
+        OptionValue::Int(*self)
+    }
+    fn as_number(&self) -> Option<f64> {
+        Some(*self as f64)
+    }
+}
+
+impl OptionType for f64 {
+    fn from_option(value: OptionValue) -> Option<Self> {
+        match value {
+            OptionValue::Float(f) => Some(f),
+            OptionValue::Int(i) => Some(i as f64),
+            _ => None,
+        }
+    }
+    fn to_option(&self) -> OptionValue {
+        OptionValue::Float(*self)
+    }
+    fn as_number(&self) -> Option<f64> {
+        Some(*self)
+    }
+}
+
+impl OptionType for String {
+    fn from_option(value: OptionValue) -> Option<Self> {
+        match value {
+            OptionValue::String(s) => Some(s),
+            value => Some(value.to_string()),
+        }
+    }
+    fn to_option(&self) -> OptionValue {
+        OptionValue::String(self.clone())
+    }
+}
+
+/// This struct holds a 2D vector, used for offsets and sizes
+#[derive(
+    Debug, Clone, Copy, PartialEq, Default, derive_more::Display, derive_more::Constructor,
+)]
+#[display("{x} {y}")]
+pub struct Vec2 {
+    /// The horizontal component
+    pub x: f64,
+    /// The vertical component
+    pub y: f64,
+}
+
+impl OptionType for Vec2 {
+    fn from_option(value: OptionValue) -> Option<Self> {
+        let OptionValue::String(s) = value else {
+            return None;
+        };
+        let (x, y) = s.trim().split_once(' ')?;
+        Some(Self::new(x.trim().parse().ok()?, y.trim().parse().ok()?))
+    }
+    fn to_option(&self) -> OptionValue {
+        OptionValue::String(self.to_string())
+    }
+}
+
+pub use crate::color::{Color, Gradient};
+
+impl OptionType for Color {
+    /// Hyprland returns colors as integers, or as `AARRGGBB` hex strings
+    fn from_option(value: OptionValue) -> Option<Self> {
+        match value {
+            OptionValue::Int(i) => Some(Self::from_argb(i as u32)),
+            OptionValue::String(s) => u32::from_str_radix(s.trim().trim_start_matches("0x"), 16)
+                .ok()
+                .map(Self::from_argb),
+            OptionValue::Float(_) => None,
+        }
+    }
+    fn to_option(&self) -> OptionValue {
+        OptionValue::String(self.to_string())
+    }
+}
+
+impl OptionType for Gradient {
+    /// Hyprland returns gradients as `AARRGGBB` colors followed by the angle, like `ee33ccff ee00ff99 45deg`
+    fn from_option(value: OptionValue) -> Option<Self> {
+        let OptionValue::String(s) = value else {
+            return Color::from_option(value).map(Self::from);
+        };
+        let mut gradient = Self::default();
+        for part in s.split_whitespace() {
+            match part.strip_suffix("deg") {
+                Some(angle) => gradient.angle = angle.parse().ok()?,
+                None => gradient
+                    .colors
+                    .push(Color::from_option(OptionValue::String(part.to_string()))?),
+            }
+        }
+        Some(gradient)
+    }
+    fn to_option(&self) -> OptionValue {
+        OptionValue::String(self.to_string())
+    }
+}
+
+macro_rules! option_enum {
+    ($(#[$meta:meta])* $name:ident: $repr:ident { $($(#[$vmeta:meta])* $variant:ident = $value:literal,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$vmeta])* $variant,)*
+        }
+
+        impl OptionType for $name {
+            fn from_option(value: OptionValue) -> Option<Self> {
+                match value {
+                    $(OptionValue::$repr(v) if v == $value => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+            fn to_option(&self) -> OptionValue {
+                match self {
+                    $(Self::$variant => $value.into(),)*
+                }
+            }
+        }
+    };
+}
+
+option_enum!(
+    /// How dwindle splits new windows
+    ForceSplit: Int {
+        /// Split in the direction of the cursor
+        Mouse = 0,
+        /// Always split to the left or top
+        Left = 1,
+        /// Always split to the right or bottom
+        Right = 2,
+    }
+);
+
+option_enum!(
+    /// How focus follows the cursor
+    FollowMouse: Int {
+        /// The cursor doesn't change focus
+        Disabled = 0,
+        /// The window under the cursor is always focused
+        Always = 1,
+        /// Focus is detached from the cursor, except on click
+        Detached = 2,
+        /// Keyboard and mouse focus are separate
+        Separate = 3,
+    }
+);
+
+option_enum!(
+    /// When variable refresh rate is used
+    Vrr: Int {
+        /// Never
+        Off = 0,
+        /// Always
+        On = 1,
+        /// Only for fullscreen windows
+        Fullscreen = 2,
+        /// Only for fullscreen video and game windows
+        FullscreenVideo = 3,
+    }
+);
+
+option_enum!(
+    /// Where master places new windows
+    NewStatus: String {
+        /// New windows become the master
+        Master = "master",
+        /// New windows are added to the stack
+        Slave = "slave",
+        /// New windows inherit the status of the focused window
+        Inherit = "inherit",
+    }
+);
+
+option_enum!(
+    /// Where new windows are placed relative to the focused one in master
+    NewOnActive: String {
+        /// Before the focused window
+        Before = "before",
+        /// After the focused window
+        After = "after",
+        /// Where `new_on_top` puts them
+        None = "none",
+    }
+);
+
+option_enum!(
+    /// Where master places the master area
+    Orientation: String {
+        /// On the left
+        Left = "left",
+        /// On the right
+        Right = "right",
+        /// On the top
+        Top = "top",
+        /// On the bottom
+        Bottom = "bottom",
+        /// In the center
+        Center = "center",
+    }
+);
+
+/// This struct holds a config option with the type of its value
+#[derive(Debug)]
+pub struct ConfigOption<T> {
+    name: &'static str,
+    min: Option<f64>,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for ConfigOption<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ConfigOption<T> {}
+
+impl<T: OptionType> ConfigOption<T> {
+    /// Creates an option, with the minimum numeric values must be at
+    pub const fn new(name: &'static str, min: Option<f64>) -> Self {
+        Self {
+            name,
+            min,
+            value: PhantomData,
+        }
+    }
+
+    /// Returns the full name of the option, like `general:border_size`
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the minimum numeric values must be at, for options Hyprland rejects negative values for
+    pub const fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// This method checks that the value is not below the minimum of the option
+    pub fn validate(&self, value: &T) -> crate::Result<()> {
+        match (self.min, value.as_number()) {
+            (Some(min), Some(n)) if n < min => Err(HyprError::InvalidOptionValue(format!(
+                "{n} is less than the minimum {min} of {}",
+                self.name
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// This method converts a keyword returned by Hyprland into the value of the option
+    pub fn parse(&self, keyword: Keyword) -> crate::Result<T> {
+        match T::from_option(keyword.value.clone()) {
+            Some(value) => Ok(value),
+            None => Err(invalid_value(self.name, &keyword.value)),
+        }
+    }
+
+    /// This method returns the value of the option
+    pub fn get(&self) -> crate::Result<T> {
+        self.instance_get(default_instance()?)
+    }
+
+    /// This method returns the value of the option
+    pub fn instance_get(&self, instance: &Instance) -> crate::Result<T> {
+        self.parse(Keyword::instance_get(instance, self.name)?)
+    }
+
+    /// This method returns the value of the option (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn get_async(&self) -> crate::Result<T> {
+        self.instance_get_async(default_instance()?).await
+    }
+
+    /// This method returns the value of the option (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_get_async(&self, instance: &Instance) -> crate::Result<T> {
+        self.parse(Keyword::instance_get_async(instance, self.name).await?)
+    }
+
+    /// This method sets the value of the option, after checking its minimum
+    pub fn set<V: Into<T>>(&self, value: V) -> crate::Result<()> {
+        self.instance_set(default_instance()?, value)
+    }
+
+    /// This method sets the value of the option, after checking its minimum
+    pub fn instance_set<V: Into<T>>(&self, instance: &Instance, value: V) -> crate::Result<()> {
+        let value = value.into();
+        self.validate(&value)?;
+        Keyword::instance_set(instance, self.name, value.to_option())
+    }
+
+    /// This method sets the value of the option, after checking its minimum (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn set_async<V: Into<T>>(&self, value: V) -> crate::Result<()> {
+        self.instance_set_async(default_instance()?, value).await
+    }
+
+    /// This method sets the value of the option, after checking its minimum (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_set_async<V: Into<T>>(
+        &self,
+        instance: &Instance,
+        value: V,
+    ) -> crate::Result<()> {
+        let value = value.into();
+        self.validate(&value)?;
+        Keyword::instance_set_async(instance, self.name, value.to_option()).await
+    }
+}
+
+macro_rules! options {
+    ($category:literal; $($(#[$meta:meta])* $const:ident: $ty:ty = $name:literal $(>= $min:literal)?;)*) => {
+        use super::*;
+
+        $(
+            $(#[$meta])*
+            pub const $const: ConfigOption<$ty> =
+                ConfigOption::new(concat!($category, ":", $name), options!(@min $($min)?));
+        )*
+
+        /// The names of the options in this category
+        pub const NAMES: &[&str] = &[$(concat!($category, ":", $name)),*];
+    };
+    (@min) => { None };
+    (@min $min:literal) => { Some($min as f64) };
+}
+
+/// Options in the `general` category
+pub mod general {
+    options! { "general";
+        /// Size of the border around windows
+        BORDER_SIZE: i64 = "border_size" >= 0;
+        /// Gaps between windows, like `5` or `5,10,5,10`
+        GAPS_IN: String = "gaps_in";
+        /// Gaps between windows and monitor edges, like `20` or `20,10,20,10`
+        GAPS_OUT: String = "gaps_out";
+        /// Gaps between workspaces, stacked with `gaps_out`
+        GAPS_WORKSPACES: i64 = "gaps_workspaces" >= 0;
+        /// Border color of inactive windows
+        COL_INACTIVE_BORDER: Gradient = "col.inactive_border";
+        /// Border color of the active window
+        COL_ACTIVE_BORDER: Gradient = "col.active_border";
+        /// Border color of inactive windows that can't be grouped
+        COL_NOGROUP_BORDER: Gradient = "col.nogroup_border";
+        /// Border color of the active window if it can't be grouped
+        COL_NOGROUP_BORDER_ACTIVE: Gradient = "col.nogroup_border_active";
+        /// The layout to use, like `dwindle` or `master`
+        LAYOUT: String = "layout";
+        /// Disables moving focus to another window when there is none in the direction
+        NO_FOCUS_FALLBACK: bool = "no_focus_fallback";
+        /// Enables resizing windows by dragging their borders
+        RESIZE_ON_BORDER: bool = "resize_on_border";
+        /// Extends the area around borders that can be dragged
+        EXTEND_BORDER_GRAB_AREA: i64 = "extend_border_grab_area" >= 0;
+        /// Shows a cursor icon when hovering over borders
+        HOVER_ICON_ON_BORDER: bool = "hover_icon_on_border";
+        /// Allows tearing for windows that request it
+        ALLOW_TEARING: bool = "allow_tearing";
+        /// Forces floating windows to resize from one corner, 0 to disable
+        RESIZE_CORNER: i64 = "resize_corner" >= 0;
+    }
+
+    /// Options in the `general:snap` category
+    pub mod snap {
+        options! { "general:snap";
+            /// Enables snapping floating windows
+            ENABLED: bool = "enabled";
+            /// The distance at which floating windows snap to each other
+            WINDOW_GAP: i64 = "window_gap" >= 0;
+            /// The distance at which floating windows snap to monitor edges
+            MONITOR_GAP: i64 = "monitor_gap" >= 0;
+            /// Snaps windows so their borders overlap
+            BORDER_OVERLAP: bool = "border_overlap";
+        }
+    }
+}
+
+/// Options in the `decoration` category
+pub mod decoration {
+    options! { "decoration";
+        /// Radius of rounded window corners
+        ROUNDING: i64 = "rounding" >= 0;
+        /// Curve of rounded corners, 2 is a circle
+        ROUNDING_POWER: f64 = "rounding_power" >= 0.0;
+        /// Opacity of the active window
+        ACTIVE_OPACITY: f64 = "active_opacity" >= 0.0;
+        /// Opacity of inactive windows
+        INACTIVE_OPACITY: f64 = "inactive_opacity" >= 0.0;
+        /// Opacity of fullscreen windows
+        FULLSCREEN_OPACITY: f64 = "fullscreen_opacity" >= 0.0;
+        /// Dims inactive windows
+        DIM_INACTIVE: bool = "dim_inactive";
+        /// How much inactive windows are dimmed
+        DIM_STRENGTH: f64 = "dim_strength" >= 0.0;
+        /// How much the special workspace dims what is behind it
+        DIM_SPECIAL: f64 = "dim_special" >= 0.0;
+        /// How much windows with the `dimaround` rule dim what is behind them
+        DIM_AROUND: f64 = "dim_around" >= 0.0;
+        /// Path to a fragment shader applied to the screen
+        SCREEN_SHADER: String = "screen_shader";
+        /// Counts the border as part of the window
+        BORDER_PART_OF_WINDOW: bool = "border_part_of_window";
+    }
+
+    /// Options in the `decoration:blur` category
+    pub mod blur {
+        options! { "decoration:blur";
+            /// Enables blurring transparent windows
+            ENABLED: bool = "enabled";
+            /// Blur size, the distance
+            SIZE: i64 = "size" >= 0;
+            /// The amount of passes
+            PASSES: i64 = "passes" >= 0;
+            /// Blurs behind transparent parts of windows regardless of their opacity
+            IGNORE_OPACITY: bool = "ignore_opacity";
+            /// Enables optimizations that greatly improve performance
+            NEW_OPTIMIZATIONS: bool = "new_optimizations";
+            /// Makes floating windows ignore tiled windows in their blur
+            XRAY: bool = "xray";
+            /// How much noise to apply
+            NOISE: f64 = "noise" >= 0.0;
+            /// Contrast modulation for blur
+            CONTRAST: f64 = "contrast" >= 0.0;
+            /// Brightness modulation for blur
+            BRIGHTNESS: f64 = "brightness" >= 0.0;
+            /// Increases the saturation of blurred colors
+            VIBRANCY: f64 = "vibrancy" >= 0.0;
+            /// How strong the vibrancy is on dark areas
+            VIBRANCY_DARKNESS: f64 = "vibrancy_darkness" >= 0.0;
+            /// Blurs behind the special workspace
+            SPECIAL: bool = "special";
+            /// Blurs popups
+            POPUPS: bool = "popups";
+            /// Only blurs popup pixels with an opacity above this
+            POPUPS_IGNOREALPHA: f64 = "popups_ignorealpha" >= 0.0;
+        }
+    }
+
+    /// Options in the `decoration:shadow` category
+    pub mod shadow {
+        options! { "decoration:shadow";
+            /// Enables drop shadows on windows
+            ENABLED: bool = "enabled";
+            /// Shadow range, the size
+            RANGE: i64 = "range" >= 0;
+            /// How fast the shadow falls off, higher is faster
+            RENDER_POWER: i64 = "render_power" >= 0;
+            /// Makes the shadow sharp
+            SHARP: bool = "sharp";
+            /// Hides the shadow behind the window
+            IGNORE_WINDOW: bool = "ignore_window";
+            /// Shadow color
+            COLOR: Color = "color";
+            /// Shadow color of inactive windows
+            COLOR_INACTIVE: Color = "color_inactive";
+            /// Shadow offset
+            OFFSET: Vec2 = "offset";
+            /// Shadow scale
+            SCALE: f64 = "scale" >= 0.0;
+        }
+    }
+}
+
+/// Options in the `animations` category
+pub mod animations {
+    options! { "animations";
+        /// Enables animations
+        ENABLED: bool = "enabled";
+        /// Enables the animation when Hyprland starts
+        FIRST_LAUNCH_ANIMATION: bool = "first_launch_animation";
+        /// Animates workspaces as if the first and last were adjacent
+        WORKSPACE_WRAPAROUND: bool = "workspace_wraparound";
+    }
+}
+
+/// Options in the `input` category
+pub mod input {
+    options! { "input";
+        /// XKB keymap model
+        KB_MODEL: String = "kb_model";
+        /// XKB keymap layout
+        KB_LAYOUT: String = "kb_layout";
+        /// XKB keymap variant
+        KB_VARIANT: String = "kb_variant";
+        /// XKB keymap options
+        KB_OPTIONS: String = "kb_options";
+        /// XKB keymap rules
+        KB_RULES: String = "kb_rules";
+        /// Path to a `.xkb` keymap file
+        KB_FILE: String = "kb_file";
+        /// Enables numlock by default
+        NUMLOCK_BY_DEFAULT: bool = "numlock_by_default";
+        /// Resolves binds by symbols instead of keycodes
+        RESOLVE_BINDS_BY_SYM: bool = "resolve_binds_by_sym";
+        /// Repeat rate for held keys, in repeats per second
+        REPEAT_RATE: i64 = "repeat_rate";
+        /// Delay before a held key repeats, in milliseconds
+        REPEAT_DELAY: i64 = "repeat_delay";
+        /// Cursor sensitivity
+        SENSITIVITY: f64 = "sensitivity";
+        /// Cursor acceleration profile, `adaptive`, `flat` or `custom`
+        ACCEL_PROFILE: String = "accel_profile";
+        /// Disables cursor acceleration completely
+        FORCE_NO_ACCEL: bool = "force_no_accel";
+        /// Switches the left and right mouse buttons
+        LEFT_HANDED: bool = "left_handed";
+        /// Points of the custom scroll acceleration profile
+        SCROLL_POINTS: String = "scroll_points";
+        /// Scroll method, `2fg`, `edge`, `on_button_down` or `no_scroll`
+        SCROLL_METHOD: String = "scroll_method";
+        /// Button used for `on_button_down` scrolling
+        SCROLL_BUTTON: i64 = "scroll_button";
+        /// Makes the scroll button a toggle
+        SCROLL_BUTTON_LOCK: bool = "scroll_button_lock";
+        /// Multiplier for scrolling
+        SCROLL_FACTOR: f64 = "scroll_factor";
+        /// Inverts scrolling
+        NATURAL_SCROLL: bool = "natural_scroll";
+        /// How focus follows the cursor
+        FOLLOW_MOUSE: FollowMouse = "follow_mouse";
+        /// What is focused when a window closes, 0 for the next window and 1 for the window under the cursor
+        FOCUS_ON_CLOSE: i64 = "focus_on_close" >= 0;
+        /// Changes focus when the cursor crosses window edges
+        MOUSE_REFOCUS: bool = "mouse_refocus";
+        /// Focus changes when the cursor moves between floating and tiled windows, if `follow_mouse` is 1
+        FLOAT_SWITCH_OVERRIDE_FOCUS: i64 = "float_switch_override_focus" >= 0;
+        /// Lets focus fall through the special workspace to the regular one
+        SPECIAL_FALLTHROUGH: bool = "special_fallthrough";
+        /// How scroll events outside windows are handled
+        OFF_WINDOW_AXIS_EVENTS: i64 = "off_window_axis_events" >= 0;
+        /// Emulates discrete scrolling from high resolution scrolling
+        EMULATE_DISCRETE_SCROLL: i64 = "emulate_discrete_scroll" >= 0;
+    }
+
+    /// Options in the `input:touchpad` category
+    pub mod touchpad {
+        options! { "input:touchpad";
+            /// Disables the touchpad while typing
+            DISABLE_WHILE_TYPING: bool = "disable_while_typing";
+            /// Inverts scrolling
+            NATURAL_SCROLL: bool = "natural_scroll";
+            /// Multiplier for scrolling
+            SCROLL_FACTOR: f64 = "scroll_factor";
+            /// Sends a middle click when pressing left and right at once
+            MIDDLE_BUTTON_EMULATION: bool = "middle_button_emulation";
+            /// Buttons sent by tapping with 1, 2 and 3 fingers, `lrm` or `lmr`
+            TAP_BUTTON_MAP: String = "tap_button_map";
+            /// Clicks with 1, 2 and 3 fingers send left, right and middle clicks
+            CLICKFINGER_BEHAVIOR: bool = "clickfinger_behavior";
+            /// Tapping sends a click
+            TAP_TO_CLICK: bool = "tap-to-click";
+            /// Keeps dragging after lifting the finger for a short time
+            DRAG_LOCK: i64 = "drag_lock" >= 0;
+            /// Tapping and then dragging drags
+            TAP_AND_DRAG: bool = "tap-and-drag";
+        }
+    }
+
+    /// Options in the `input:touchdevice` category
+    pub mod touchdevice {
+        options! { "input:touchdevice";
+            /// Transform of the input, -1 to follow the monitor
+            TRANSFORM: i64 = "transform";
+            /// The monitor touch input is mapped to
+            OUTPUT: String = "output";
+            /// Enables touch devices
+            ENABLED: bool = "enabled";
+        }
+    }
+
+    /// Options in the `input:tablet` category
+    pub mod tablet {
+        options! { "input:tablet";
+            /// Transform of the input, -1 to follow the monitor
+            TRANSFORM: i64 = "transform";
+            /// The monitor tablet input is mapped to
+            OUTPUT: String = "output";
+            /// Position of the mapped region, if `output` is `[[EMPTY]]`
+            REGION_POSITION: Vec2 = "region_position";
+            /// Size of the mapped region
+            REGION_SIZE: Vec2 = "region_size";
+            /// Moves the cursor relatively instead of absolutely
+            RELATIVE_INPUT: bool = "relative_input";
+            /// Rotates the tablet for left handed use
+            LEFT_HANDED: bool = "left_handed";
+            /// Size of the active area of the tablet, in millimeters
+            ACTIVE_AREA_SIZE: Vec2 = "active_area_size";
+            /// Position of the active area of the tablet, in millimeters
+            ACTIVE_AREA_POSITION: Vec2 = "active_area_position";
+        }
+    }
+}
+
+/// Options in the `gestures` category
+pub mod gestures {
+    options! { "gestures";
+        /// Enables swiping between workspaces
+        WORKSPACE_SWIPE: bool = "workspace_swipe";
+        /// The amount of fingers to swipe with
+        WORKSPACE_SWIPE_FINGERS: i64 = "workspace_swipe_fingers" >= 0;
+        /// Makes `workspace_swipe_fingers` the minimum amount of fingers
+        WORKSPACE_SWIPE_MIN_FINGERS: bool = "workspace_swipe_min_fingers";
+        /// How far a swipe goes, in pixels
+        WORKSPACE_SWIPE_DISTANCE: i64 = "workspace_swipe_distance";
+        /// Enables swiping from the edges of touchscreens
+        WORKSPACE_SWIPE_TOUCH: bool = "workspace_swipe_touch";
+        /// Inverts the direction of swiping on touchpads
+        WORKSPACE_SWIPE_INVERT: bool = "workspace_swipe_invert";
+        /// Inverts the direction of swiping on touchscreens
+        WORKSPACE_SWIPE_TOUCH_INVERT: bool = "workspace_swipe_touch_invert";
+        /// Swipe speed that always switches workspaces, 0 to disable
+        WORKSPACE_SWIPE_MIN_SPEED_TO_FORCE: i64 = "workspace_swipe_min_speed_to_force";
+        /// How far a swipe must go to switch workspaces
+        WORKSPACE_SWIPE_CANCEL_RATIO: f64 = "workspace_swipe_cancel_ratio" >= 0.0;
+        /// Creates a new workspace when swiping past the last one
+        WORKSPACE_SWIPE_CREATE_NEW: bool = "workspace_swipe_create_new";
+        /// Locks the direction of a swipe once it started
+        WORKSPACE_SWIPE_DIRECTION_LOCK: bool = "workspace_swipe_direction_lock";
+        /// How far a swipe goes before its direction is locked
+        WORKSPACE_SWIPE_DIRECTION_LOCK_THRESHOLD: i64 = "workspace_swipe_direction_lock_threshold";
+        /// Keeps swiping past neighboring workspaces
+        WORKSPACE_SWIPE_FOREVER: bool = "workspace_swipe_forever";
+        /// Uses the `r` prefix for workspaces when swiping, to skip empty ones
+        WORKSPACE_SWIPE_USE_R: bool = "workspace_swipe_use_r";
+    }
+}
+
+/// Options in the `group` category
+pub mod group {
+    options! { "group";
+        /// Adds new windows to the focused group
+        AUTO_GROUP: bool = "auto_group";
+        /// Inserts new windows after the current one in groups
+        INSERT_AFTER_CURRENT: bool = "insert_after_current";
+        /// Focuses the window that was moved out of a group
+        FOCUS_REMOVED_WINDOW: bool = "focus_removed_window";
+        /// Dragging windows into groups, 0 to disable, 1 anywhere and 2 only on the groupbar
+        DRAG_INTO_GROUP: i64 = "drag_into_group" >= 0;
+        /// Merges groups dragged into each other
+        MERGE_GROUPS_ON_DRAG: bool = "merge_groups_on_drag";
+        /// Only merges groups dropped on the groupbar
+        MERGE_GROUPS_ON_GROUPBAR: bool = "merge_groups_on_groupbar";
+        /// Merges floating windows into tiled groups when dropped on their groupbar
+        MERGE_FLOATED_INTO_TILED_ON_GROUPBAR: bool = "merge_floated_into_tiled_on_groupbar";
+        /// Groups windows moved to a workspace with a group
+        GROUP_ON_MOVETOWORKSPACE: bool = "group_on_movetoworkspace";
+        /// Border color of the active group
+        COL_BORDER_ACTIVE: Gradient = "col.border_active";
+        /// Border color of inactive groups
+        COL_BORDER_INACTIVE: Gradient = "col.border_inactive";
+        /// Border color of the active locked group
+        COL_BORDER_LOCKED_ACTIVE: Gradient = "col.border_locked_active";
+        /// Border color of inactive locked groups
+        COL_BORDER_LOCKED_INACTIVE: Gradient = "col.border_locked_inactive";
+    }
+
+    /// Options in the `group:groupbar` category
+    pub mod groupbar {
+        options! { "group:groupbar";
+            /// Enables the groupbar
+            ENABLED: bool = "enabled";
+            /// Font of the titles
+            FONT_FAMILY: String = "font_family";
+            /// Font size of the titles
+            FONT_SIZE: i64 = "font_size";
+            /// Draws gradients behind the titles
+            GRADIENTS: bool = "gradients";
+            /// Height of the groupbar
+            HEIGHT: i64 = "height";
+            /// Height of the indicator
+            INDICATOR_HEIGHT: i64 = "indicator_height";
+            /// Stacks the groupbar vertically
+            STACKED: bool = "stacked";
+            /// Decoration priority of the groupbar
+            PRIORITY: i64 = "priority";
+            /// Renders titles in the groupbar
+            RENDER_TITLES: bool = "render_titles";
+            /// Vertical offset of the titles
+            TEXT_OFFSET: i64 = "text_offset";
+            /// Scrolling in the groupbar changes the active window
+            SCROLLING: bool = "scrolling";
+            /// Rounding of the indicator
+            ROUNDING: i64 = "rounding" >= 0;
+            /// Rounding of the gradients
+            GRADIENT_ROUNDING: i64 = "gradient_rounding" >= 0;
+            /// Only rounds the edges of the whole groupbar
+            ROUND_ONLY_EDGES: bool = "round_only_edges";
+            /// Only rounds the edges of the whole gradient
+            GRADIENT_ROUND_ONLY_EDGES: bool = "gradient_round_only_edges";
+            /// Color of the titles
+            TEXT_COLOR: Color = "text_color";
+            /// Color of the active window
+            COL_ACTIVE: Gradient = "col.active";
+            /// Color of inactive windows
+            COL_INACTIVE: Gradient = "col.inactive";
+            /// Color of the active window in locked groups
+            COL_LOCKED_ACTIVE: Gradient = "col.locked_active";
+            /// Color of inactive windows in locked groups
+            COL_LOCKED_INACTIVE: Gradient = "col.locked_inactive";
+            /// Gap between the indicators
+            GAPS_IN: i64 = "gaps_in";
+            /// Gap between the groupbar and the window
+            GAPS_OUT: i64 = "gaps_out";
+            /// Keeps the gap above the groupbar
+            KEEP_UPPER_GAP: bool = "keep_upper_gap";
+        }
+    }
+}
+
+/// Options in the `misc` category
+pub mod misc {
+    options! { "misc";
+        /// Disables the random Hyprland logo and anime girl wallpaper
+        DISABLE_HYPRLAND_LOGO: bool = "disable_hyprland_logo";
+        /// Disables the splash text below the logo
+        DISABLE_SPLASH_RENDERING: bool = "disable_splash_rendering";
+        /// Color of the splash text
+        COL_SPLASH: Color = "col.splash";
+        /// Font used for text rendered by Hyprland
+        FONT_FAMILY: String = "font_family";
+        /// Font of the splash text
+        SPLASH_FONT_FAMILY: String = "splash_font_family";
+        /// Which default wallpaper is used, -1 for a random one
+        FORCE_DEFAULT_WALLPAPER: i64 = "force_default_wallpaper";
+        /// Lowers the framerate when nothing changes
+        VFR: bool = "vfr";
+        /// When variable refresh rate is used
+        VRR: Vrr = "vrr";
+        /// Moving the mouse wakes up monitors turned off with dpms
+        MOUSE_MOVE_ENABLES_DPMS: bool = "mouse_move_enables_dpms";
+        /// Pressing a key wakes up monitors turned off with dpms
+        KEY_PRESS_ENABLES_DPMS: bool = "key_press_enables_dpms";
+        /// Focus follows the cursor while dragging and dropping
+        ALWAYS_FOLLOW_ON_DND: bool = "always_follow_on_dnd";
+        /// Layers keep keyboard focus while the cursor moves over windows
+        LAYERS_HOG_KEYBOARD_FOCUS: bool = "layers_hog_keyboard_focus";
+        /// Animates resizing with the keyboard or mouse
+        ANIMATE_MANUAL_RESIZES: bool = "animate_manual_resizes";
+        /// Animates dragging windows with the mouse
+        ANIMATE_MOUSE_WINDOWDRAGGING: bool = "animate_mouse_windowdragging";
+        /// Disables reloading the config when it changes
+        DISABLE_AUTORELOAD: bool = "disable_autoreload";
+        /// Enables window swallowing
+        ENABLE_SWALLOW: bool = "enable_swallow";
+        /// Class of windows that swallow the windows they spawn
+        SWALLOW_REGEX: String = "swallow_regex";
+        /// Title of windows that don't swallow
+        SWALLOW_EXCEPTION_REGEX: String = "swallow_exception_regex";
+        /// Focuses windows that request to be activated
+        FOCUS_ON_ACTIVATE: bool = "focus_on_activate";
+        /// Moving the cursor to another monitor focuses it
+        MOUSE_MOVE_FOCUSES_MONITOR: bool = "mouse_move_focuses_monitor";
+        /// Allows restarting a crashed lockscreen
+        ALLOW_SESSION_LOCK_RESTORE: bool = "allow_session_lock_restore";
+        /// Background color behind wallpapers
+        BACKGROUND_COLOR: Color = "background_color";
+        /// Closes the special workspace when its last window closes
+        CLOSE_SPECIAL_ON_EMPTY: bool = "close_special_on_empty";
+        /// What happens to fullscreen windows when a window opens
+        NEW_WINDOW_TAKES_OVER_FULLSCREEN: i64 = "new_window_takes_over_fullscreen" >= 0;
+        /// Keeps fullscreen when the fullscreen window closes
+        EXIT_WINDOW_RETAINS_FULLSCREEN: bool = "exit_window_retains_fullscreen";
+        /// Opens windows on the workspace their process was started on
+        INITIAL_WORKSPACE_TRACKING: i64 = "initial_workspace_tracking" >= 0;
+        /// Enables pasting with the middle mouse button
+        MIDDLE_CLICK_PASTE: bool = "middle_click_paste";
+        /// Framerate of unfocused windows with the `renderunfocused` rule
+        RENDER_UNFOCUSED_FPS: i64 = "render_unfocused_fps";
+        /// Disables the warning about missing XDG environment variables
+        DISABLE_XDG_ENV_CHECKS: bool = "disable_xdg_env_checks";
+        /// Disables the warning about missing hyprland-qtutils
+        DISABLE_HYPRLAND_QTUTILS_CHECK: bool = "disable_hyprland_qtutils_check";
+        /// Delay before showing the crashed lockscreen message, in milliseconds
+        LOCKDEAD_SCREEN_DELAY: i64 = "lockdead_screen_delay";
+        /// Shows a dialog for windows that don't respond
+        ENABLE_ANR_DIALOG: bool = "enable_anr_dialog";
+        /// Missed pings before a window is considered unresponsive
+        ANR_MISSED_PINGS: i64 = "anr_missed_pings";
+    }
+}
+
+/// Options in the `binds` category
+pub mod binds {
+    options! { "binds";
+        /// Passes mouse clicks to windows even if they are bound
+        PASS_MOUSE_WHEN_BOUND: bool = "pass_mouse_when_bound";
+        /// Delay between scroll binds, in milliseconds
+        SCROLL_EVENT_DELAY: i64 = "scroll_event_delay";
+        /// Switching to the current workspace switches to the previous one
+        WORKSPACE_BACK_AND_FORTH: bool = "workspace_back_and_forth";
+        /// Hides the special workspace when changing workspaces
+        HIDE_SPECIAL_ON_WORKSPACE_CHANGE: bool = "hide_special_on_workspace_change";
+        /// Lets `workspace previous` cycle between two workspaces
+        ALLOW_WORKSPACE_CYCLES: bool = "allow_workspace_cycles";
+        /// What window is focused on a workspace switch, 0 for the last focused and 1 for the center one
+        WORKSPACE_CENTER_ON: i64 = "workspace_center_on" >= 0;
+        /// How `movefocus` picks a window, 0 by history and 1 by overlap
+        FOCUS_PREFERRED_METHOD: i64 = "focus_preferred_method" >= 0;
+        /// Lets focus move into locked groups
+        IGNORE_GROUP_LOCK: bool = "ignore_group_lock";
+        /// `movefocus` cycles fullscreen windows
+        MOVEFOCUS_CYCLES_FULLSCREEN: bool = "movefocus_cycles_fullscreen";
+        /// `movefocus` cycles through groups first
+        MOVEFOCUS_CYCLES_GROUPFIRST: bool = "movefocus_cycles_groupfirst";
+        /// Disables keybinds of apps grabbing the keyboard
+        DISABLE_KEYBIND_GRABBING: bool = "disable_keybind_grabbing";
+        /// Directional window commands fall back to other monitors
+        WINDOW_DIRECTION_MONITOR_FALLBACK: bool = "window_direction_monitor_fallback";
+        /// Allows pinning fullscreen windows
+        ALLOW_PIN_FULLSCREEN: bool = "allow_pin_fullscreen";
+        /// Distance the cursor moves before a click becomes a drag
+        DRAG_THRESHOLD: i64 = "drag_threshold";
+    }
+}
+
+/// Options in the `xwayland` category
+pub mod xwayland {
+    options! { "xwayland";
+        /// Enables XWayland
+        ENABLED: bool = "enabled";
+        /// Uses nearest neighbor filtering for scaled XWayland windows
+        USE_NEAREST_NEIGHBOR: bool = "use_nearest_neighbor";
+        /// Forces a scale of 1 on XWayland windows
+        FORCE_ZERO_SCALING: bool = "force_zero_scaling";
+        /// Creates the abstract X11 socket
+        CREATE_ABSTRACT_SOCKET: bool = "create_abstract_socket";
+    }
+}
+
+/// Options in the `opengl` category
+pub mod opengl {
+    options! { "opengl";
+        /// Reduces flickering on nvidia
+        NVIDIA_ANTI_FLICKER: bool = "nvidia_anti_flicker";
+    }
+}
+
+/// Options in the `render` category
+pub mod render {
+    options! { "render";
+        /// Enables direct scanout, 2 only for games
+        DIRECT_SCANOUT: i64 = "direct_scanout" >= 0;
+        /// Expands textures smaller than their window
+        EXPAND_UNDERSIZED_TEXTURES: bool = "expand_undersized_textures";
+        /// Disables back buffering and triple buffering
+        XP_MODE: bool = "xp_mode";
+        /// Animates changes of the color transform matrix, 2 unless on nvidia
+        CTM_ANIMATION: i64 = "ctm_animation" >= 0;
+        /// Passes through color management of fullscreen windows, 2 only for HDR
+        CM_FS_PASSTHROUGH: i64 = "cm_fs_passthrough" >= 0;
+        /// Enables color management
+        CM_ENABLED: bool = "cm_enabled";
+    }
+}
+
+/// Options in the `cursor` category
+pub mod cursor {
+    options! { "cursor";
+        /// Disables hardware cursors, 2 to decide automatically
+        NO_HARDWARE_CURSORS: i64 = "no_hardware_cursors" >= 0;
+        /// Keeps variable refresh rate while the cursor moves in fullscreen, 2 to decide automatically
+        NO_BREAK_FS_VRR: i64 = "no_break_fs_vrr" >= 0;
+        /// Minimum refresh rate while the cursor moves with variable refresh rate
+        MIN_REFRESH_RATE: i64 = "min_refresh_rate";
+        /// Padding around the hotspot of the cursor
+        HOTSPOT_PADDING: i64 = "hotspot_padding";
+        /// Seconds before the cursor hides, 0 to never hide
+        INACTIVE_TIMEOUT: f64 = "inactive_timeout";
+        /// Disables moving the cursor with focus changes
+        NO_WARPS: bool = "no_warps";
+        /// Returns the cursor to its last position in a window when it is focused again
+        PERSISTENT_WARPS: bool = "persistent_warps";
+        /// Moves the cursor to the last focused window on workspace changes, 2 to force it
+        WARP_ON_CHANGE_WORKSPACE: i64 = "warp_on_change_workspace" >= 0;
+        /// The monitor the cursor starts on
+        DEFAULT_MONITOR: String = "default_monitor";
+        /// Zoom around the cursor
+        ZOOM_FACTOR: f64 = "zoom_factor" >= 0.0;
+        /// Keeps the zoomed view still while the cursor moves
+        ZOOM_RIGID: bool = "zoom_rigid";
+        /// Enables hyprcursor themes
+        ENABLE_HYPRCURSOR: bool = "enable_hyprcursor";
+        /// Hides the cursor when typing
+        HIDE_ON_KEY_PRESS: bool = "hide_on_key_press";
+        /// Hides the cursor when using a touchscreen
+        HIDE_ON_TOUCH: bool = "hide_on_touch";
+        /// Uses a CPU buffer for hardware cursors, 2 to decide automatically
+        USE_CPU_BUFFER: i64 = "use_cpu_buffer" >= 0;
+        /// Moves the cursor back after it was hidden by keyboard input
+        WARP_BACK_AFTER_NON_MOUSE_INPUT: bool = "warp_back_after_non_mouse_input";
+    }
+}
+
+/// Options in the `dwindle` category
+pub mod dwindle {
+    options! { "dwindle";
+        /// Enables pseudotiling
+        PSEUDOTILE: bool = "pseudotile";
+        /// How new windows are split
+        FORCE_SPLIT: ForceSplit = "force_split";
+        /// Keeps the split direction regardless of container size
+        PRESERVE_SPLIT: bool = "preserve_split";
+        /// Splits in the direction of the cursor within the window
+        SMART_SPLIT: bool = "smart_split";
+        /// Resizes in the direction of the cursor
+        SMART_RESIZING: bool = "smart_resizing";
+        /// Keeps the preselected direction until it is changed
+        PERMANENT_DIRECTION_OVERRIDE: bool = "permanent_direction_override";
+        /// Scale of windows in the special workspace
+        SPECIAL_SCALE_FACTOR: f64 = "special_scale_factor" >= 0.0;
+        /// Multiplier for the width when deciding the split direction
+        SPLIT_WIDTH_MULTIPLIER: f64 = "split_width_multiplier";
+        /// Splits the active window instead of the one under the cursor
+        USE_ACTIVE_FOR_SPLITS: bool = "use_active_for_splits";
+        /// Ratio of new splits
+        DEFAULT_SPLIT_RATIO: f64 = "default_split_ratio" >= 0.0;
+        /// Which window gets the larger part of the split ratio
+        SPLIT_BIAS: i64 = "split_bias" >= 0;
+    }
+}
+
+/// Options in the `master` category
+pub mod master {
+    options! { "master";
+        /// Allows splitting the master area
+        ALLOW_SMALL_SPLIT: bool = "allow_small_split";
+        /// Scale of windows in the special workspace
+        SPECIAL_SCALE_FACTOR: f64 = "special_scale_factor" >= 0.0;
+        /// Size of the master area
+        MFACT: f64 = "mfact" >= 0.0;
+        /// Where new windows are placed
+        NEW_STATUS: NewStatus = "new_status";
+        /// Places new windows at the top of the stack
+        NEW_ON_TOP: bool = "new_on_top";
+        /// Places new windows relative to the focused one
+        NEW_ON_ACTIVE: NewOnActive = "new_on_active";
+        /// Where the master area is
+        ORIENTATION: Orientation = "orientation";
+        /// Keeps fullscreen when a window is added or removed
+        INHERIT_FULLSCREEN: bool = "inherit_fullscreen";
+        /// Stack windows needed before the master is centered, with the `center` orientation
+        SLAVE_COUNT_FOR_CENTER_MASTER: i64 = "slave_count_for_center_master";
+        /// Where the master goes when it isn't centered
+        CENTER_MASTER_FALLBACK: Orientation = "center_master_fallback";
+        /// Resizes in the direction of the cursor
+        SMART_RESIZING: bool = "smart_resizing";
+        /// Drops dragged windows at the cursor
+        DROP_AT_CURSOR: bool = "drop_at_cursor";
+    }
+}
+
+/// Options in the `debug` category
+pub mod debug {
+    options! { "debug";
+        /// Shows an overlay with rendering information
+        OVERLAY: bool = "overlay";
+        /// Flashes damaged areas
+        DAMAGE_BLINK: bool = "damage_blink";
+        /// Disables logging
+        DISABLE_LOGS: bool = "disable_logs";
+        /// Disables timestamps in logs
+        DISABLE_TIME: bool = "disable_time";
+        /// Logs to stdout
+        ENABLE_STDOUT_LOGS: bool = "enable_stdout_logs";
+        /// How much damage tracking is used, 2 for full
+        DAMAGE_TRACKING: i64 = "damage_tracking" >= 0;
+        /// Seconds before the watchdog kills a frozen Hyprland, 0 to disable
+        WATCHDOG_TIMEOUT: i64 = "watchdog_timeout";
+        /// Allows any monitor scale
+        DISABLE_SCALE_CHECKS: bool = "disable_scale_checks";
+    }
+}
+
+/// Options in the `ecosystem` category
+pub mod ecosystem {
+    options! { "ecosystem";
+        /// Disables the update news after updates
+        NO_UPDATE_NEWS: bool = "no_update_news";
+        /// Disables the donation reminder
+        NO_DONATION_NAG: bool = "no_donation_nag";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockHyprland;
+
+    fn respond(mock: &MockHyprland, option: &str, value: &str) {
+        mock.respond_with(
+            format!("getoption {option}"),
+            format!(r#"{{"option": "{option}", {value}, "set": true}}"#),
+        );
+    }
+
+    #[test]
+    fn test_get() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let instance = mock.instance();
+        respond(&mock, "general:border_size", r#""int": 2"#);
+        respond(&mock, "decoration:blur:enabled", r#""int": 1"#);
+        respond(&mock, "decoration:active_opacity", r#""float": 0.9"#);
+        respond(&mock, "decoration:shadow:offset", r#""vec2": [2, -4.5]"#);
+        respond(&mock, "decoration:shadow:color", r#""int": 4279900698"#);
+        respond(
+            &mock,
+            "general:col.active_border",
+            r#""custom": "ee33ccff ee00ff99 45deg""#,
+        );
+        respond(&mock, "dwindle:force_split", r#""int": 2"#);
+        respond(&mock, "master:orientation", r#""str": "center""#);
+
+        assert_eq!(general::BORDER_SIZE.instance_get(instance)?, 2);
+        assert!(decoration::blur::ENABLED.instance_get(instance)?);
+        assert_eq!(decoration::ACTIVE_OPACITY.instance_get(instance)?, 0.9);
+        assert_eq!(
+            decoration::shadow::OFFSET.instance_get(instance)?,
+            Vec2::new(2.0, -4.5)
+        );
+        assert_eq!(
+            decoration::shadow::COLOR.instance_get(instance)?,
+            Color::new(0x1a, 0x1a, 0x1a, 0xff)
+        );
+        assert_eq!(
+            general::COL_ACTIVE_BORDER.instance_get(instance)?,
+            Gradient {
+                colors: vec![Color::from_argb(0xee33ccff), Color::from_argb(0xee00ff99)],
+                angle: 45.0,
+            }
+        );
+        assert_eq!(
+            dwindle::FORCE_SPLIT.instance_get(instance)?,
+            ForceSplit::Right
+        );
+        assert_eq!(
+            master::ORIENTATION.instance_get(instance)?,
+            Orientation::Center
+        );
+
+        respond(&mock, "dwindle:force_split", r#""int": 7"#);
+        assert!(matches!(
+            dwindle::FORCE_SPLIT.instance_get(instance),
+            Err(HyprError::InvalidOptionValue(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_set() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let instance = mock.instance();
+        general::BORDER_SIZE.instance_set(instance, 3)?;
+        decoration::blur::ENABLED.instance_set(instance, false)?;
+        input::SENSITIVITY.instance_set(instance, -0.5)?;
+        decoration::shadow::OFFSET.instance_set(instance, Vec2::new(2.0, 2.0))?;
+        general::COL_ACTIVE_BORDER.instance_set(
+            instance,
+            Gradient {
+                colors: vec![
+                    Color::new(0x33, 0xcc, 0xff, 0xee),
+                    Color::from_argb(0xee00ff99),
+                ],
+                angle: 45.0,
+            },
+        )?;
+        master::NEW_STATUS.instance_set(instance, NewStatus::Master)?;
+        general::LAYOUT.instance_set(instance, "master")?;
+        general::BORDER_SIZE.instance_set(instance, 21)?;
+
+        assert!(matches!(
+            general::BORDER_SIZE.instance_set(instance, -1),
+            Err(HyprError::InvalidOptionValue(_))
+        ));
+        assert!(decoration::ACTIVE_OPACITY
+            .instance_set(instance, -0.5)
+            .is_err());
+        assert_eq!(general::BORDER_SIZE.min(), Some(0.0));
+        assert_eq!(input::SENSITIVITY.min(), None);
+        decoration::ROUNDING.instance_set(instance, 100)?;
+
+        assert_eq!(
+            mock.received(),
+            [
+                "keyword general:border_size 3",
+                "keyword decoration:blur:enabled 0",
+                "keyword input:sensitivity -0.5",
+                "keyword decoration:shadow:offset 2 2",
+                "keyword general:col.active_border rgba(33ccffee) rgba(00ff99ee) 45deg",
+                "keyword master:new_status master",
+                "keyword general:layout master",
+                "keyword general:border_size 21",
+                "keyword decoration:rounding 100",
+            ]
+        );
+        assert_eq!(general::snap::NAMES.len(), 4);
+        assert_eq!(general::snap::ENABLED.name(), "general:snap:enabled");
+        Ok(())
+    }
+}
@@ -0,0 +1,341 @@
+//! # Layout module
+//!
+//! This module arranges floating windows on a monitor in a grid, columns, rows, master and
+//! stack, a cascade or explicit rectangles, and applies it with a single [Batch]
+//!
+//! The rectangles are computed in the usable area of the monitor, which leaves out the space
+//! reserved by bars and is in logical pixels, so it already accounts for the scale
+//!
+//! ## Usage
+//!
+//! ```rust, no_run
+//! use hyprland::data::{Clients, Monitor};
+//! use hyprland::layout::{Arrangement, Layout};
+//! use hyprland::prelude::*;
+//!
+//! fn main() -> hyprland::Result<()> {
+//!     let monitor = Monitor::get_active()?;
+//!     let clients = Clients::get()?;
+//!     let kitties = clients.iter().filter(|client| client.class == "kitty");
+//!     Layout::new(Arrangement::Grid)
+//!         .gaps(10)
+//!         .workspace(monitor.active_workspace.id)
+//!         .apply(kitties, &monitor)?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::batch::{check_batch, Batch};
+use crate::data::{Client, Monitor};
+use crate::default_instance;
+use crate::dispatch::{DispatchType, WindowIdentifier, WorkspaceIdentifierWithSpecial};
+use crate::geometry::Rect;
+use crate::instance::Instance;
+use crate::shared::WorkspaceId;
+
+/// This enum holds the ways windows can be arranged
+#[derive(Debug, Clone, PartialEq)]
+pub enum Arrangement {
+    /// Rows of equal cells, with as many columns as rows or one more
+    Grid,
+    /// Side by side columns of the full height
+    Columns,
+    /// Stacked rows of the full width
+    Rows,
+    /// The first window on the left, taking the ratio of the width, and the others stacked on the right
+    MasterStack {
+        /// The part of the width the first window takes, from 0 to 1
+        ratio: f64,
+    },
+    /// Overlapping windows, each one offset from the previous one by the step
+    Cascade {
+        /// The offset between windows, in logical pixels, at least 1
+        step: i32,
+    },
+    /// Explicit rectangles, relative to the top left corner of the usable area.
+    /// Windows without a rectangle are left where they are
+    Explicit(Vec<Rect>),
+}
+
+/// This struct holds how to arrange windows
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    /// The arrangement
+    pub arrangement: Arrangement,
+    /// The space between windows, and around them, in logical pixels
+    pub gaps: i32,
+    /// The workspace the windows are moved to first, if set
+    pub workspace: Option<WorkspaceId>,
+}
+
+impl Layout {
+    /// This method creates a layout without gaps, that leaves windows on their workspace
+    pub fn new(arrangement: Arrangement) -> Self {
+        Self {
+            arrangement,
+            gaps: 0,
+            workspace: None,
+        }
+    }
+
+    /// This method sets the space between windows, and around them
+    pub fn gaps(mut self, gaps: i32) -> Self {
+        self.gaps = gaps.max(0);
+        self
+    }
+
+    /// This method makes the windows move to the workspace, without switching to it
+    pub fn workspace(mut self, workspace: WorkspaceId) -> Self {
+        self.workspace = Some(workspace);
+        self
+    }
+
+    /// This method computes the rectangles of the windows in the area, in order.
+    /// It returns fewer rectangles than windows only for explicit arrangements
+    pub fn rects(&self, area: Rect, count: usize) -> Vec<Rect> {
+        if count == 0 {
+            return Vec::new();
+        }
+        let gaps = self.gaps;
+        let area = area.inset(gaps, gaps, gaps, gaps);
+        match &self.arrangement {
+            Arrangement::Grid => {
+                let columns = (count as f64).sqrt().ceil() as usize;
+                let rows = count.div_ceil(columns);
+                let heights = split(area.y, area.height, rows, gaps);
+                (0..count)
+                    .map(|index| {
+                        let row = index / columns;
+                        // the last row can have fewer windows, which then get wider
+                        let in_row = columns.min(count - row * columns);
+                        let widths = split(area.x, area.width, in_row, gaps);
+                        let (x, width) = widths[index % columns];
+                        let (y, height) = heights[row];
+                        Rect::new(x, y, width, height)
+                    })
+                    .collect()
+            }
+            Arrangement::Columns => split(area.x, area.width, count, gaps)
+                .into_iter()
+                .map(|(x, width)| Rect::new(x, area.y, width, area.height))
+                .collect(),
+            Arrangement::Rows => split(area.y, area.height, count, gaps)
+                .into_iter()
+                .map(|(y, height)| Rect::new(area.x, y, area.width, height))
+                .collect(),
+            Arrangement::MasterStack { ratio } if count > 1 => {
+                let ratio = ratio.clamp(0.05, 0.95);
+                let master_width = ((area.width - gaps) as f64 * ratio).round() as i32;
+                let stack_x = area.x + master_width + gaps;
+                let stack_width = area.width - master_width - gaps;
+                std::iter::once(Rect::new(area.x, area.y, master_width, area.height))
+                    .chain(
+                        split(area.y, area.height, count - 1, gaps)
+                            .into_iter()
+                            .map(|(y, height)| Rect::new(stack_x, y, stack_width, height)),
+                    )
+                    .collect()
+            }
+            Arrangement::MasterStack { .. } => vec![area],
+            Arrangement::Cascade { step } => {
+                // a step below 1 would make the windows larger than the area
+                let step = (*step).max(1);
+                let offset = step.saturating_mul(count as i32 - 1);
+                let width = (area.width - offset).max(area.width / 2);
+                let height = (area.height - offset).max(area.height / 2);
+                (0..count as i32)
+                    .map(|index| {
+                        // start over from the corner once the windows would leave the area
+                        let x = step.saturating_mul(index) % (area.width - width + 1);
+                        let y = step.saturating_mul(index) % (area.height - height + 1);
+                        Rect::new(area.x + x, area.y + y, width, height)
+                    })
+                    .collect()
+            }
+            Arrangement::Explicit(rects) => rects
+                .iter()
+                .take(count)
+                .map(|rect| rect.offset(area.x - gaps, area.y - gaps))
+                .collect(),
+        }
+    }
+
+    /// This method returns the dispatchers that arrange the clients on the monitor.
+    /// Tiled clients are made floating first
+    pub fn batch<'a, I: IntoIterator<Item = &'a Client>>(
+        &self,
+        clients: I,
+        monitor: &Monitor,
+    ) -> Batch {
+        let clients: Vec<&Client> = clients.into_iter().collect();
+        let rects = self.rects(monitor.usable_rect(), clients.len());
+        clients
+            .into_iter()
+            .zip(rects)
+            .fold(Batch::new(), |mut batch, (client, rect)| {
+                let window = || WindowIdentifier::Address(client.address.clone());
+                if let Some(workspace) = self.workspace {
+                    batch = batch.dispatch(DispatchType::MoveToWorkspaceSilent(
+                        WorkspaceIdentifierWithSpecial::Id(workspace),
+                        Some(window()),
+                    ));
+                }
+                if !client.floating {
+                    batch = batch.dispatch(DispatchType::ToggleFloating(Some(window())));
+                }
+                batch
+                    .dispatch(DispatchType::ResizeWindowPixel(
+                        rect.resize_position(),
+                        window(),
+                    ))
+                    .dispatch(DispatchType::MoveWindowPixel(
+                        rect.move_position(),
+                        window(),
+                    ))
+            })
+    }
+
+    /// This method arranges the clients on the monitor
+    pub fn apply<'a, I: IntoIterator<Item = &'a Client>>(
+        &self,
+        clients: I,
+        monitor: &Monitor,
+    ) -> crate::Result<()> {
+        self.instance_apply(default_instance()?, clients, monitor)
+    }
+
+    /// This method arranges the clients on the monitor
+    pub fn instance_apply<'a, I: IntoIterator<Item = &'a Client>>(
+        &self,
+        instance: &Instance,
+        clients: I,
+        monitor: &Monitor,
+    ) -> crate::Result<()> {
+        check_batch(self.batch(clients, monitor).instance_call(instance)?)
+    }
+
+    /// This method arranges the clients on the monitor (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn apply_async<'a, I: IntoIterator<Item = &'a Client>>(
+        &self,
+        clients: I,
+        monitor: &Monitor,
+    ) -> crate::Result<()> {
+        self.instance_apply_async(default_instance()?, clients, monitor)
+            .await
+    }
+
+    /// This method arranges the clients on the monitor (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_apply_async<'a, I: IntoIterator<Item = &'a Client>>(
+        &self,
+        instance: &Instance,
+        clients: I,
+        monitor: &Monitor,
+    ) -> crate::Result<()> {
+        let batch = self.batch(clients, monitor);
+        check_batch(batch.instance_call_async(instance).await?)
+    }
+}
+
+/// Splits a length into parts separated by gaps, as starts and lengths.
+/// The pixels that don't divide evenly go to the first parts
+fn split(start: i32, length: i32, parts: usize, gap: i32) -> Vec<(i32, i32)> {
+    let parts = parts.max(1) as i32;
+    let available = (length - gap * (parts - 1)).max(0);
+    let (base, extra) = (available / parts, available % parts);
+    let mut position = start;
+    (0..parts)
+        .map(|index| {
+            let size = base + i32::from(index < extra);
+            let part = (position, size);
+            position += size + gap;
+            part
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::{HyprData, HyprDataVec};
+    use crate::testing::MockHyprland;
+
+    #[test]
+    fn test_rects() {
+        let area = Rect::new(0, 30, 1000, 600);
+        assert_eq!(
+            Layout::new(Arrangement::Grid).gaps(10).rects(area, 3),
+            [
+                Rect::new(10, 40, 485, 285),
+                Rect::new(505, 40, 485, 285),
+                Rect::new(10, 335, 980, 285),
+            ]
+        );
+        assert_eq!(
+            Layout::new(Arrangement::Columns).rects(area, 3),
+            [
+                Rect::new(0, 30, 334, 600),
+                Rect::new(334, 30, 333, 600),
+                Rect::new(667, 30, 333, 600),
+            ]
+        );
+        assert_eq!(
+            Layout::new(Arrangement::MasterStack { ratio: 0.6 })
+                .gaps(10)
+                .rects(area, 3),
+            [
+                Rect::new(10, 40, 582, 580),
+                Rect::new(602, 40, 388, 285),
+                Rect::new(602, 335, 388, 285),
+            ]
+        );
+        assert_eq!(
+            Layout::new(Arrangement::Cascade { step: 40 }).rects(area, 3),
+            [
+                Rect::new(0, 30, 920, 520),
+                Rect::new(40, 70, 920, 520),
+                Rect::new(80, 110, 920, 520),
+            ]
+        );
+        for step in [-1, 0, 1] {
+            assert_eq!(
+                Layout::new(Arrangement::Cascade { step }).rects(area, 2),
+                [Rect::new(0, 30, 999, 599), Rect::new(1, 31, 999, 599)],
+                "{step}"
+            );
+        }
+        assert_eq!(
+            Layout::new(Arrangement::Explicit(vec![Rect::new(0, 0, 100, 100)]))
+                .gaps(10)
+                .rects(area, 2),
+            [Rect::new(0, 30, 100, 100)]
+        );
+        assert!(Layout::new(Arrangement::Grid).rects(area, 0).is_empty());
+    }
+
+    #[test]
+    fn test_apply() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let monitor = crate::data::Monitors::instance_get(mock.instance())?
+            .to_vec()
+            .remove(0);
+        let clients = crate::data::Clients::instance_get(mock.instance())?;
+        mock.clear_received();
+
+        Layout::new(Arrangement::Columns)
+            .gaps(10)
+            .workspace(2)
+            .instance_apply(mock.instance(), clients.iter(), &monitor)?;
+        assert_eq!(
+            mock.received(),
+            [
+                "dispatch movetoworkspacesilent 2,address:0x55d1c8a0e6f0",
+                "dispatch togglefloating address:0x55d1c8a0e6f0",
+                "dispatch resizewindowpixel exact 2540 1390,address:0x55d1c8a0e6f0",
+                "dispatch movewindowpixel exact 10 40,address:0x55d1c8a0e6f0",
+            ]
+        );
+        Ok(())
+    }
+}
@@ -34,6 +34,24 @@ pub mod event_listener;
 #[cfg(feature = "dispatch")]
 pub mod dispatch;
 
+/// This module is for keeping a copy of the compositor state up to date using events
+#[cfg(feature = "listener")]
+pub mod mirror;
+
+/// This module provides points and rectangles for relating monitors, windows and the cursor
+pub mod geometry;
+
+/// This module provides colors and gradients in the syntaxes Hyprland uses
+pub mod color;
+
+/// This module is for arranging floating windows on a monitor
+#[cfg(all(feature = "data", feature = "dispatch"))]
+pub mod layout;
+
+/// This module is for sending many commands to Hyprland in a single request
+#[cfg(any(feature = "dispatch", feature = "keyword", feature = "data"))]
+pub mod batch;
+
 /// This module is for calling hyprctl **commands**, for getting data use [data]
 #[cfg(feature = "ctl")]
 pub mod ctl;
@@ -57,6 +75,10 @@ pub mod instance;
 #[cfg(feature = "hyprpaper")]
 pub mod hyprpaper;
 
+/// This module is for testing code that uses this crate without a running Hyprland
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 /// The prelude module, this is to import all traits
 pub mod prelude {
     pub use crate::shared::{HyprData, HyprDataActive, HyprDataActiveOptional, HyprDataVec};
@@ -0,0 +1,1143 @@
+//! # State Mirror module
+//!
+//! This module provides [StateMirror](crate::mirror::StateMirror), which keeps a copy of the monitors, workspaces,
+//! clients and layers of the compositor up to date by applying every [Event](crate::event_listener::Event) to it
+//!
+//! ## Usage
+//!
+//! ```rust, no_run
+//! use hyprland::mirror::StateMirror;
+//! fn main() -> hyprland::Result<()> {
+//!     let mirror = StateMirror::new()?;
+//!     let changes = mirror.subscribe();
+//!
+//!     let runner = mirror.clone();
+//!     std::thread::spawn(move || runner.run());
+//!
+//!     for change in changes {
+//!         let state = mirror.state();
+//!         println!("{change:?}, there are now {} windows", state.clients.len());
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::data::{
+    Client, Clients, FullscreenMode, LayerDisplay, Layers, Monitor, Monitors, Transforms,
+    Workspace, WorkspaceBasic, Workspaces,
+};
+use crate::default_instance;
+use crate::error::HyprError;
+use crate::event_listener::*;
+use crate::instance::Instance;
+use crate::shared::*;
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+#[cfg(feature = "ahash")]
+use ahash::HashMap;
+#[cfg(not(feature = "ahash"))]
+use std::collections::HashMap;
+
+/// The amount of changes a subscriber can fall behind before new ones are dropped for it
+pub const SUBSCRIBER_CAPACITY: usize = 1024;
+
+/// This enum describes a change applied to the [MirrorState] of a [StateMirror]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateChange {
+    /// A client was added
+    ClientAdded(Address),
+    /// A client was removed
+    ClientRemoved(Address),
+    /// A client was changed
+    ClientChanged(Address),
+    /// The active window was changed
+    ActiveWindowChanged(Option<Address>),
+    /// A workspace was added
+    WorkspaceAdded(WorkspaceId),
+    /// A workspace was removed
+    WorkspaceRemoved(WorkspaceId),
+    /// A workspace was changed
+    WorkspaceChanged(WorkspaceId),
+    /// A monitor was added
+    MonitorAdded(String),
+    /// A monitor was removed
+    MonitorRemoved(String),
+    /// A monitor was changed
+    MonitorChanged(String),
+    /// The layers were changed
+    LayersChanged,
+    /// The whole state was fetched again
+    Refreshed,
+}
+
+/// This struct holds the compositor state kept by a [StateMirror]
+#[derive(Debug, Clone, Default)]
+pub struct MirrorState {
+    /// The monitors
+    pub monitors: Vec<Monitor>,
+    /// The workspaces
+    pub workspaces: Vec<Workspace>,
+    /// The clients/windows
+    pub clients: Vec<Client>,
+    /// The layer surfaces of every monitor, by monitor name
+    pub layers: HashMap<String, LayerDisplay>,
+    /// The active window
+    pub active_window: Option<Address>,
+}
+
+/// The data fetched again after an event the state can not be updated from by itself
+enum Fetched {
+    Clients(Vec<Client>),
+    Monitors(Vec<Monitor>),
+    Layers(HashMap<String, LayerDisplay>),
+    All(MirrorState),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Refresh {
+    Clients,
+    Monitors,
+    Layers,
+    All,
+}
+
+impl Refresh {
+    fn for_event(event: &Event) -> Option<Self> {
+        match event {
+            // the event holds no geometry, pid, etc.
+            Event::WindowOpened(_) => Some(Self::Clients),
+            // the event does not say which group the window joined
+            Event::WindowMovedIntoGroup(_) => Some(Self::Clients),
+            // the event only holds the workspace state, not the fullscreen mode of the window
+            Event::FullscreenStateChanged(_) => Some(Self::Clients),
+            Event::MonitorAdded(_) => Some(Self::Monitors),
+            Event::LayerOpened(_) | Event::LayerClosed(_) => Some(Self::Layers),
+            Event::Reconnected(_) => Some(Self::All),
+            _ => None,
+        }
+    }
+
+    fn fetch(self, instance: &Instance) -> crate::Result<Fetched> {
+        Ok(match self {
+            Self::Clients => Fetched::Clients(Clients::instance_get(instance)?.to_vec()),
+            Self::Monitors => Fetched::Monitors(Monitors::instance_get(instance)?.to_vec()),
+            Self::Layers => Fetched::Layers(Layers::instance_get(instance)?.into_iter().collect()),
+            Self::All => Fetched::All(MirrorState::fetch(instance)?),
+        })
+    }
+
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    async fn fetch_async(self, instance: &Instance) -> crate::Result<Fetched> {
+        Ok(match self {
+            Self::Clients => {
+                Fetched::Clients(Clients::instance_get_async(instance).await?.to_vec())
+            }
+            Self::Monitors => {
+                Fetched::Monitors(Monitors::instance_get_async(instance).await?.to_vec())
+            }
+            Self::Layers => Fetched::Layers(
+                Layers::instance_get_async(instance)
+                    .await?
+                    .into_iter()
+                    .collect(),
+            ),
+            Self::All => Fetched::All(MirrorState::fetch_async(instance).await?),
+        })
+    }
+}
+
+fn active_window(clients: &[Client]) -> Option<Address> {
+    clients
+        .iter()
+        .find(|client| client.focus_history_id == 0)
+        .map(|client| client.address.clone())
+}
+
+impl MirrorState {
+    fn fetch(instance: &Instance) -> crate::Result<Self> {
+        let clients = Clients::instance_get(instance)?.to_vec();
+        Ok(Self {
+            monitors: Monitors::instance_get(instance)?.to_vec(),
+            workspaces: Workspaces::instance_get(instance)?.to_vec(),
+            active_window: active_window(&clients),
+            clients,
+            layers: Layers::instance_get(instance)?.into_iter().collect(),
+        })
+    }
+
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    async fn fetch_async(instance: &Instance) -> crate::Result<Self> {
+        let clients = Clients::instance_get_async(instance).await?.to_vec();
+        Ok(Self {
+            monitors: Monitors::instance_get_async(instance).await?.to_vec(),
+            workspaces: Workspaces::instance_get_async(instance).await?.to_vec(),
+            active_window: active_window(&clients),
+            clients,
+            layers: Layers::instance_get_async(instance)
+                .await?
+                .into_iter()
+                .collect(),
+        })
+    }
+
+    fn update(&mut self, fetched: Fetched) {
+        match fetched {
+            Fetched::Clients(clients) => {
+                self.active_window = active_window(&clients);
+                self.clients = clients;
+            }
+            Fetched::Monitors(monitors) => self.monitors = monitors,
+            Fetched::Layers(layers) => self.layers = layers,
+            Fetched::All(state) => *self = state,
+        }
+    }
+
+    /// This method returns the client with the address
+    pub fn client(&self, address: &Address) -> Option<&Client> {
+        self.clients
+            .iter()
+            .find(|client| &client.address == address)
+    }
+
+    /// This method returns the active window
+    pub fn active_client(&self) -> Option<&Client> {
+        self.active_window
+            .as_ref()
+            .and_then(|address| self.client(address))
+    }
+
+    /// This method returns the workspace with the id
+    pub fn workspace(&self, id: WorkspaceId) -> Option<&Workspace> {
+        self.workspaces.iter().find(|workspace| workspace.id == id)
+    }
+
+    /// This method returns the workspace with the name
+    pub fn workspace_by_name(&self, name: &str) -> Option<&Workspace> {
+        self.workspaces
+            .iter()
+            .find(|workspace| workspace.name == name)
+    }
+
+    /// This method returns the monitor with the name
+    pub fn monitor(&self, name: &str) -> Option<&Monitor> {
+        self.monitors.iter().find(|monitor| monitor.name == name)
+    }
+
+    /// This method returns the focused monitor
+    pub fn focused_monitor(&self) -> Option<&Monitor> {
+        self.monitors.iter().find(|monitor| monitor.focused)
+    }
+
+    /// This method returns the clients on the workspace
+    pub fn clients_on_workspace(&self, id: WorkspaceId) -> impl Iterator<Item = &Client> {
+        self.clients
+            .iter()
+            .filter(move |client| client.workspace.id == id)
+    }
+
+    fn client_mut(&mut self, address: &Address) -> Option<&mut Client> {
+        self.clients
+            .iter_mut()
+            .find(|client| &client.address == address)
+    }
+
+    fn workspace_mut(&mut self, id: WorkspaceId) -> Option<&mut Workspace> {
+        self.workspaces
+            .iter_mut()
+            .find(|workspace| workspace.id == id)
+    }
+
+    fn monitor_id(&self, name: &str) -> Option<MonitorId> {
+        self.monitor(name).map(|monitor| monitor.id)
+    }
+
+    fn change_window_count(&mut self, id: WorkspaceId, opened: bool) -> Option<StateChange> {
+        let workspace = self.workspace_mut(id)?;
+        workspace.windows = if opened {
+            workspace.windows.saturating_add(1)
+        } else {
+            workspace.windows.saturating_sub(1)
+        };
+        Some(StateChange::WorkspaceChanged(id))
+    }
+
+    fn update_client(
+        &mut self,
+        address: &Address,
+        f: impl FnOnce(&mut Client),
+    ) -> Option<StateChange> {
+        let client = self.client_mut(address)?;
+        f(client);
+        Some(StateChange::ClientChanged(address.clone()))
+    }
+
+    /// This method applies an event to the state, and returns what changed
+    ///
+    /// Some events do not carry everything needed to update the state,
+    /// like the size of an opened window, those parts are left empty until the next refresh.
+    /// [StateMirror] refreshes them automatically.
+    pub fn apply(&mut self, event: &Event) -> Vec<StateChange> {
+        let mut changes = vec![];
+        match event {
+            Event::WindowOpened(data) => {
+                let (workspace, monitor) = match self.workspace_by_name(&data.workspace_name) {
+                    Some(workspace) => (
+                        WorkspaceBasic {
+                            id: workspace.id,
+                            name: workspace.name.clone(),
+                        },
+                        workspace.monitor_id,
+                    ),
+                    None => (
+                        WorkspaceBasic {
+                            id: 0,
+                            name: data.workspace_name.clone(),
+                        },
+                        None,
+                    ),
+                };
+                self.clients
+                    .retain(|client| client.address != data.window_address);
+                changes.extend(self.change_window_count(workspace.id, true));
+                self.clients.push(Client {
+                    address: data.window_address.clone(),
+                    at: (0, 0),
+                    size: (0, 0),
+                    workspace,
+                    floating: false,
+                    fullscreen: FullscreenMode::None,
+                    fullscreen_client: FullscreenMode::None,
+                    monitor,
+                    initial_class: data.window_class.clone(),
+                    class: data.window_class.clone(),
+                    initial_title: data.window_title.clone(),
+                    title: data.window_title.clone(),
+                    pid: -1,
+                    xwayland: false,
+                    pinned: false,
+                    grouped: vec![],
+                    mapped: true,
+                    swallowing: None,
+                    focus_history_id: -1,
+                });
+                changes.push(StateChange::ClientAdded(data.window_address.clone()));
+            }
+            Event::WindowClosed(address) => {
+                let Some(index) = self.clients.iter().position(|c| &c.address == address) else {
+                    return changes;
+                };
+                let client = self.clients.remove(index);
+                changes.extend(self.change_window_count(client.workspace.id, false));
+                for member in client.grouped.iter().filter(|member| ***member != *address) {
+                    changes.extend(self.update_client(member, |member| {
+                        member.grouped.retain(|grouped| **grouped != *address)
+                    }));
+                }
+                if self.active_window.as_ref() == Some(address) {
+                    self.active_window = None;
+                    changes.push(StateChange::ActiveWindowChanged(None));
+                }
+                changes.push(StateChange::ClientRemoved(address.clone()));
+            }
+            Event::WindowMoved(data) => {
+                let monitor = self
+                    .workspace(data.workspace_id)
+                    .and_then(|workspace| workspace.monitor_id);
+                let Some(client) = self.client_mut(&data.window_address) else {
+                    return changes;
+                };
+                let old = client.workspace.id;
+                client.workspace = WorkspaceBasic {
+                    id: data.workspace_id,
+                    name: data.workspace_name.to_string(),
+                };
+                client.monitor = monitor;
+                changes.push(StateChange::ClientChanged(data.window_address.clone()));
+                changes.extend(self.change_window_count(old, false));
+                changes.extend(self.change_window_count(data.workspace_id, true));
+            }
+            Event::WindowTitleChanged(data) => {
+                changes.extend(
+                    self.update_client(&data.address, |client| {
+                        client.title.clone_from(&data.title)
+                    }),
+                );
+            }
+            Event::FloatStateChanged(data) => {
+                changes.extend(
+                    self.update_client(&data.address, |client| client.floating = data.floating),
+                );
+            }
+            Event::WindowPinned(data) => {
+                changes.extend(
+                    self.update_client(&data.address, |client| client.pinned = data.pinned),
+                );
+            }
+            Event::GroupToggled(data) => {
+                let group: Vec<Box<Address>> = match data.toggled {
+                    true => data
+                        .window_addresses
+                        .iter()
+                        .cloned()
+                        .map(Box::new)
+                        .collect(),
+                    false => vec![],
+                };
+                for address in &data.window_addresses {
+                    changes.extend(
+                        self.update_client(address, |client| client.grouped.clone_from(&group)),
+                    );
+                }
+            }
+            Event::WindowMovedOutOfGroup(address) => {
+                let Some(client) = self.client_mut(address) else {
+                    return changes;
+                };
+                let group = std::mem::take(&mut client.grouped);
+                changes.push(StateChange::ClientChanged(address.clone()));
+                for member in group.iter().filter(|member| ***member != *address) {
+                    changes.extend(self.update_client(member, |member| {
+                        member.grouped.retain(|grouped| **grouped != *address)
+                    }));
+                }
+            }
+            Event::WindowMovedIntoGroup(address) if self.client(address).is_some() => {
+                changes.push(StateChange::ClientChanged(address.clone()));
+            }
+            Event::ActiveWindowChanged(data) => {
+                let address = data.as_ref().map(|data| data.address.clone());
+                if let Some(address) = &address {
+                    let previous = self.client(address).map(|c| c.focus_history_id);
+                    for client in self.clients.iter_mut() {
+                        if &client.address == address {
+                            client.focus_history_id = 0;
+                        } else if client.focus_history_id >= 0
+                            && previous.map_or(true, |p| p < 0 || client.focus_history_id < p)
+                        {
+                            client.focus_history_id = client.focus_history_id.saturating_add(1);
+                        }
+                    }
+                }
+                self.active_window.clone_from(&address);
+                changes.push(StateChange::ActiveWindowChanged(address));
+            }
+            Event::FullscreenStateChanged(state) => {
+                let Some(id) = self.active_client().map(|client| client.workspace.id) else {
+                    return changes;
+                };
+                if let Some(workspace) = self.workspace_mut(id) {
+                    workspace.fullscreen = *state;
+                    changes.push(StateChange::WorkspaceChanged(id));
+                }
+            }
+            Event::WorkspaceAdded(data) => {
+                if self.workspace(data.id).is_some() {
+                    return changes;
+                }
+                let (monitor, monitor_id) = match self.focused_monitor() {
+                    Some(monitor) => (monitor.name.clone(), Some(monitor.id)),
+                    None => (String::new(), None),
+                };
+                self.workspaces.push(Workspace {
+                    id: data.id,
+                    name: data.name.to_string(),
+                    monitor,
+                    monitor_id,
+                    windows: 0,
+                    fullscreen: false,
+                    last_window: Address::new("0x0"),
+                    last_window_title: String::new(),
+                });
+                changes.push(StateChange::WorkspaceAdded(data.id));
+            }
+            Event::WorkspaceDeleted(data) => {
+                let count = self.workspaces.len();
+                self.workspaces.retain(|workspace| workspace.id != data.id);
+                if self.workspaces.len() != count {
+                    changes.push(StateChange::WorkspaceRemoved(data.id));
+                }
+            }
+            Event::WorkspaceRenamed(data) => {
+                let Some(workspace) = self.workspace_mut(data.id) else {
+                    return changes;
+                };
+                workspace.name.clone_from(&data.name);
+                changes.push(StateChange::WorkspaceChanged(data.id));
+                for client in self.clients.iter_mut() {
+                    if client.workspace.id == data.id {
+                        client.workspace.name.clone_from(&data.name);
+                        changes.push(StateChange::ClientChanged(client.address.clone()));
+                    }
+                }
+                for monitor in self.monitors.iter_mut() {
+                    if monitor.active_workspace.id == data.id {
+                        monitor.active_workspace.name.clone_from(&data.name);
+                        changes.push(StateChange::MonitorChanged(monitor.name.clone()));
+                    }
+                }
+            }
+            Event::WorkspaceMoved(data) => {
+                let monitor_id = self.monitor_id(&data.monitor);
+                let Some(workspace) = self.workspace_mut(data.id) else {
+                    return changes;
+                };
+                workspace.monitor.clone_from(&data.monitor);
+                workspace.monitor_id = monitor_id;
+                changes.push(StateChange::WorkspaceChanged(data.id));
+                for client in self.clients.iter_mut() {
+                    if client.workspace.id == data.id {
+                        client.monitor = monitor_id;
+                        changes.push(StateChange::ClientChanged(client.address.clone()));
+                    }
+                }
+            }
+            Event::WorkspaceChanged(data) => {
+                let Some(monitor) = self.monitors.iter_mut().find(|monitor| monitor.focused) else {
+                    return changes;
+                };
+                monitor.active_workspace = WorkspaceBasic {
+                    id: data.id,
+                    name: data.name.to_string(),
+                };
+                changes.push(StateChange::MonitorChanged(monitor.name.clone()));
+            }
+            Event::ChangedSpecial(data) => {
+                let id = self
+                    .workspace_by_name(&data.workspace_name)
+                    .map_or(0, |workspace| workspace.id);
+                let Some(monitor) = self
+                    .monitors
+                    .iter_mut()
+                    .find(|monitor| monitor.name == data.monitor_name)
+                else {
+                    return changes;
+                };
+                monitor.special_workspace = WorkspaceBasic {
+                    id,
+                    name: data.workspace_name.clone(),
+                };
+                changes.push(StateChange::MonitorChanged(monitor.name.clone()));
+            }
+            Event::SpecialRemoved(name) => {
+                let Some(monitor) = self.monitors.iter_mut().find(|m| &m.name == name) else {
+                    return changes;
+                };
+                monitor.special_workspace = WorkspaceBasic {
+                    id: 0,
+                    name: String::new(),
+                };
+                changes.push(StateChange::MonitorChanged(name.clone()));
+            }
+            Event::ActiveMonitorChanged(data) => {
+                for monitor in self.monitors.iter_mut() {
+                    let focused = monitor.name == data.monitor_name;
+                    if monitor.focused != focused {
+                        monitor.focused = focused;
+                        changes.push(StateChange::MonitorChanged(monitor.name.clone()));
+                    }
+                }
+            }
+            Event::MonitorAdded(data) => {
+                if self.monitor(&data.name).is_some() {
+                    return changes;
+                }
+                self.monitors.push(Monitor {
+                    id: data.id,
+                    name: data.name.clone(),
+                    description: data.description.clone(),
+                    width: 0,
+                    height: 0,
+                    refresh_rate: 0.0,
+                    x: 0,
+                    y: 0,
+                    active_workspace: WorkspaceBasic {
+                        id: 0,
+                        name: String::new(),
+                    },
+                    special_workspace: WorkspaceBasic {
+                        id: 0,
+                        name: String::new(),
+                    },
+                    reserved: (0, 0, 0, 0),
+                    scale: 1.0,
+                    transform: Transforms::Normal,
+                    focused: false,
+                    dpms_status: true,
+                    vrr: false,
+                    disabled: false,
+                    make: String::new(),
+                    model: String::new(),
+                    serial: String::new(),
+                    available_modes: Vec::new(),
+                    mirror_of: None,
+                    current_format: String::new(),
+                    solitary: None,
+                    actively_tearing: false,
+                    direct_scanout_to: None,
+                });
+                changes.push(StateChange::MonitorAdded(data.name.clone()));
+            }
+            Event::MonitorRemoved(name) => {
+                let count = self.monitors.len();
+                self.monitors.retain(|monitor| &monitor.name != name);
+                self.layers.remove(name);
+                if self.monitors.len() != count {
+                    changes.push(StateChange::MonitorRemoved(name.clone()));
+                }
+            }
+            Event::LayerOpened(_) => changes.push(StateChange::LayersChanged),
+            Event::LayerClosed(namespace) => {
+                for display in self.layers.values_mut() {
+                    for level in display.levels.values_mut() {
+                        level.retain(|layer| &layer.namespace != namespace);
+                    }
+                }
+                changes.push(StateChange::LayersChanged);
+            }
+            _ => {}
+        }
+        changes
+    }
+}
+
+/// This struct keeps a [MirrorState] up to date with the compositor, using the event socket
+///
+/// Clones share the same state and subscribers,
+/// so one clone can run the mirror while others read from it.
+#[derive(Debug, Clone)]
+pub struct StateMirror {
+    instance: Instance,
+    state: Arc<RwLock<MirrorState>>,
+    subscribers: Arc<Mutex<Vec<SyncSender<StateChange>>>>,
+    reconnect: Option<ReconnectPolicy>,
+}
+
+impl StateMirror {
+    fn from_state(instance: Instance, state: MirrorState) -> Self {
+        Self {
+            instance,
+            state: Arc::new(RwLock::new(state)),
+            subscribers: Arc::new(Mutex::new(vec![])),
+            reconnect: None,
+        }
+    }
+
+    /// This method creates a mirror from the current state (blocking)
+    pub fn new() -> crate::Result<Self> {
+        Self::instance_new(default_instance()?)
+    }
+
+    /// This method creates a mirror from the current state (blocking)
+    pub fn instance_new(instance: &Instance) -> crate::Result<Self> {
+        let state = MirrorState::fetch(instance)?;
+        Ok(Self::from_state(instance.clone(), state))
+    }
+
+    /// This method creates a mirror from the current state (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn new_async() -> crate::Result<Self> {
+        Self::instance_new_async(default_instance()?).await
+    }
+
+    /// This method creates a mirror from the current state (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn instance_new_async(instance: &Instance) -> crate::Result<Self> {
+        let state = MirrorState::fetch_async(instance).await?;
+        Ok(Self::from_state(instance.clone(), state))
+    }
+
+    /// This method makes the mirror reconnect when the event socket closes,
+    /// the whole state is fetched again after reconnecting
+    pub fn set_reconnect_policy(&mut self, policy: ReconnectPolicy) {
+        self.reconnect = Some(policy);
+    }
+
+    /// This method returns read access to the state
+    ///
+    /// The mirror can not apply events while this is held
+    pub fn state(&self) -> RwLockReadGuard<'_, MirrorState> {
+        match self.state.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn state_mut(&self) -> RwLockWriteGuard<'_, MirrorState> {
+        match self.state.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// This method returns a receiver for every change applied from now on
+    ///
+    /// The receiver holds up to [SUBSCRIBER_CAPACITY] changes,
+    /// the changes applied while it is full are dropped for it
+    pub fn subscribe(&self) -> Receiver<StateChange> {
+        let (sender, receiver) = sync_channel(SUBSCRIBER_CAPACITY);
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.push(sender);
+        }
+        receiver
+    }
+
+    fn notify(&self, changes: Vec<StateChange>) {
+        let Ok(mut subscribers) = self.subscribers.lock() else {
+            return;
+        };
+        for change in changes {
+            subscribers.retain(|subscriber| {
+                !matches!(
+                    subscriber.try_send(change.clone()),
+                    Err(TrySendError::Disconnected(_))
+                )
+            });
+        }
+    }
+
+    fn update(&self, event: Option<&Event>, fetched: Option<Fetched>) {
+        let mut state = self.state_mut();
+        let mut changes = match event {
+            Some(event) => state.apply(event),
+            None => vec![],
+        };
+        if let Some(fetched) = fetched {
+            if matches!(fetched, Fetched::All(_)) {
+                changes.push(StateChange::Refreshed);
+            }
+            state.update(fetched);
+        }
+        drop(state);
+        self.notify(changes);
+    }
+
+    /// This method applies an event, without fetching anything, and notifies subscribers
+    pub fn apply(&self, event: &Event) {
+        self.update(Some(event), None);
+    }
+
+    /// This method fetches the whole state again (blocking)
+    pub fn refresh(&self) -> crate::Result<()> {
+        self.refresh_from(&self.instance)
+    }
+
+    fn refresh_from(&self, instance: &Instance) -> crate::Result<()> {
+        self.update(None, Some(Refresh::All.fetch(instance)?));
+        Ok(())
+    }
+
+    /// This method fetches the whole state again (async)
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn refresh_async(&self) -> crate::Result<()> {
+        self.refresh_from_async(&self.instance).await
+    }
+
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    async fn refresh_from_async(&self, instance: &Instance) -> crate::Result<()> {
+        let fetched = Refresh::All.fetch_async(instance).await?;
+        self.update(None, Some(fetched));
+        Ok(())
+    }
+
+    fn handle_event(&self, instance: &Instance, event: &Event) -> crate::Result<()> {
+        let fetched = match Refresh::for_event(event) {
+            Some(refresh) => Some(refresh.fetch(instance)?),
+            None => None,
+        };
+        self.update(Some(event), fetched);
+        Ok(())
+    }
+
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    async fn handle_event_async(&self, instance: &Instance, event: &Event) -> crate::Result<()> {
+        let fetched = match Refresh::for_event(event) {
+            Some(refresh) => Some(refresh.fetch_async(instance).await?),
+            None => None,
+        };
+        self.update(Some(event), fetched);
+        Ok(())
+    }
+
+    fn handle_lines(
+        &self,
+        instance: &Instance,
+        lines: String,
+        active_windows: &mut Vec<ActiveWindowState>,
+    ) -> crate::Result<()> {
+        for event in event_parser(lines)? {
+            for event in event_primer_noexec(event, active_windows)? {
+                self.handle_event(instance, &event)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    async fn handle_lines_async(
+        &self,
+        instance: &Instance,
+        lines: String,
+        active_windows: &mut Vec<ActiveWindowState>,
+    ) -> crate::Result<()> {
+        for event in event_parser(lines)? {
+            for event in event_primer_noexec(event, active_windows)? {
+                self.handle_event_async(instance, &event).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// This method applies events to the state until the event socket closes (blocking)
+    ///
+    /// A malformed event or a failed fetch doesn't stop the mirror,
+    /// the whole state is fetched again instead, until that succeeds
+    pub fn run(&self) -> crate::Result<()> {
+        let mut instance = self.instance.clone();
+        let mut stream = instance.get_event_stream()?;
+        let mut active_windows = vec![];
+        let mut reader = EventLineReader::default();
+        let mut stale = false;
+        loop {
+            let result = match (reader.read_lines(&mut stream), &self.reconnect) {
+                (Ok(Some(lines)), _) => self.handle_lines(&instance, lines, &mut active_windows),
+                (Ok(None) | Err(HyprError::IoError(_)), Some(policy)) => {
+                    stream = policy.reconnect(&mut instance)?;
+                    active_windows.clear();
+                    reader = EventLineReader::default();
+                    self.handle_event(&instance, &Event::Reconnected(instance.clone()))
+                }
+                (Ok(None), None) => break,
+                (Err(e), _) => return Err(e),
+            };
+            if result.is_err() || stale {
+                stale = self.refresh_from(&instance).is_err();
+            }
+        }
+        Ok(())
+    }
+
+    /// This method applies events to the state until the event socket closes (async)
+    ///
+    /// A malformed event or a failed fetch doesn't stop the mirror,
+    /// the whole state is fetched again instead, until that succeeds
+    #[cfg(any(feature = "async-lite", feature = "tokio"))]
+    pub async fn run_async(&self) -> crate::Result<()> {
+        let mut instance = self.instance.clone();
+        let mut stream = instance.get_event_stream_async().await?;
+        let mut active_windows = vec![];
+        let mut reader = EventLineReader::default();
+        let mut stale = false;
+        loop {
+            let result = match (reader.read_lines_async(&mut stream).await, &self.reconnect) {
+                (Ok(Some(lines)), _) => {
+                    self.handle_lines_async(&instance, lines, &mut active_windows)
+                        .await
+                }
+                (Ok(None) | Err(HyprError::IoError(_)), Some(policy)) => {
+                    stream = policy.reconnect_async(&mut instance).await?;
+                    active_windows.clear();
+                    reader = EventLineReader::default();
+                    let reconnected = Event::Reconnected(instance.clone());
+                    self.handle_event_async(&instance, &reconnected).await
+                }
+                (Ok(None), None) => break,
+                (Err(e), _) => return Err(e),
+            };
+            if result.is_err() || stale {
+                stale = self.refresh_from_async(&instance).await.is_err();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockHyprland;
+    use std::time::Duration;
+
+    fn address(address: &str) -> Address {
+        Address::new(address)
+    }
+
+    fn mirror(mock: &MockHyprland) -> StateMirror {
+        match StateMirror::instance_new(mock.instance()) {
+            Ok(mirror) => mirror,
+            Err(e) => panic!("Error occured: {e}"),
+        }
+    }
+
+    #[test]
+    fn test_initial_state() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let mirror = mirror(&mock);
+        let state = mirror.state();
+        assert_eq!(state.monitors.len(), 1);
+        assert_eq!(state.workspaces.len(), 1);
+        assert_eq!(state.layers.len(), 1);
+        assert_eq!(
+            state.active_client().map(|client| client.class.as_str()),
+            Some("kitty")
+        );
+        assert_eq!(state.clients_on_workspace(1).count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_windows() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let mirror = mirror(&mock);
+        let changes = mirror.subscribe();
+        let kitty = address("0x55d1c8a0e6f0");
+        let foot = address("0x55d1c8a11111");
+
+        mirror.apply(&Event::WindowOpened(WindowOpenEvent {
+            window_address: foot.clone(),
+            workspace_name: "1".into(),
+            window_class: "foot".into(),
+            window_title: "foot".into(),
+        }));
+        mirror.apply(&Event::WorkspaceAdded(WorkspaceEventData {
+            name: WorkspaceType::Regular("2".into()),
+            id: 2,
+        }));
+        mirror.apply(&Event::WindowMoved(WindowMoveEvent {
+            window_address: foot.clone(),
+            workspace_id: 2,
+            workspace_name: WorkspaceType::Regular("2".into()),
+        }));
+        mirror.apply(&Event::FloatStateChanged(WindowFloatEventData {
+            address: foot.clone(),
+            floating: true,
+        }));
+        mirror.apply(&Event::GroupToggled(GroupToggledEventData {
+            toggled: true,
+            window_addresses: vec![kitty.clone(), foot.clone()],
+        }));
+        mirror.apply(&Event::WindowClosed(kitty.clone()));
+
+        {
+            let state = mirror.state();
+            let client = state.client(&foot).map(|client| {
+                (
+                    client.workspace.id,
+                    client.monitor,
+                    client.floating,
+                    client.grouped.clone(),
+                )
+            });
+            assert_eq!(
+                client,
+                Some((2, Some(0), true, vec![Box::new(foot.clone())]))
+            );
+            assert!(state.client(&kitty).is_none());
+            assert_eq!(state.active_window, None);
+            assert_eq!(state.workspace(1).map(|w| w.windows), Some(0));
+            assert_eq!(state.workspace(2).map(|w| w.windows), Some(1));
+            assert_eq!(state.workspace(2).map(|w| w.monitor.as_str()), Some("DP-1"));
+        }
+
+        let changes: Vec<StateChange> = changes.try_iter().collect();
+        assert_eq!(
+            changes,
+            vec![
+                StateChange::WorkspaceChanged(1),
+                StateChange::ClientAdded(foot.clone()),
+                StateChange::WorkspaceAdded(2),
+                StateChange::ClientChanged(foot.clone()),
+                StateChange::WorkspaceChanged(1),
+                StateChange::WorkspaceChanged(2),
+                StateChange::ClientChanged(foot.clone()),
+                StateChange::ClientChanged(kitty.clone()),
+                StateChange::ClientChanged(foot.clone()),
+                StateChange::WorkspaceChanged(1),
+                StateChange::ClientChanged(foot.clone()),
+                StateChange::ActiveWindowChanged(None),
+                StateChange::ClientRemoved(kitty),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_workspaces_and_monitors() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let mirror = mirror(&mock);
+        mirror.apply(&Event::MonitorAdded(MonitorAddedEventData {
+            id: 1,
+            name: "HDMI-A-1".into(),
+            description: "".into(),
+        }));
+        mirror.apply(&Event::ActiveMonitorChanged(MonitorEventData {
+            monitor_name: "HDMI-A-1".into(),
+            workspace_name: None,
+        }));
+        mirror.apply(&Event::WorkspaceMoved(WorkspaceMovedEventData {
+            name: WorkspaceType::Regular("1".into()),
+            id: 1,
+            monitor: "HDMI-A-1".into(),
+        }));
+        mirror.apply(&Event::WorkspaceRenamed(NonSpecialWorkspaceEventData {
+            id: 1,
+            name: "web".into(),
+        }));
+        mirror.apply(&Event::MonitorRemoved("DP-1".into()));
+
+        let state = mirror.state();
+        assert_eq!(
+            state.focused_monitor().map(|m| m.name.as_str()),
+            Some("HDMI-A-1")
+        );
+        assert_eq!(state.monitors.len(), 1);
+        assert!(state.layers.is_empty());
+        let workspace = state.workspace(1).map(|w| (w.name.as_str(), w.monitor_id));
+        assert_eq!(workspace, Some(("web", Some(1))));
+        let client = state
+            .clients
+            .first()
+            .map(|c| (c.workspace.name.as_str(), c.monitor));
+        assert_eq!(client, Some(("web", Some(1))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_run() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let mirror = mirror(&mock);
+        let changes = mirror.subscribe();
+        let runner = mirror.clone();
+        let run = std::thread::spawn(move || runner.run());
+
+        assert!(mock.wait_for_event_clients(1, Duration::from_secs(5)));
+        mock.push_event("windowtitlev2>>55d1c8a0e6f0,vim")?;
+        mock.push_event("closewindow>>55d1c8a0e6f0")?;
+        mock.disconnect_event_clients();
+        assert!(matches!(run.join(), Ok(Ok(()))));
+
+        assert!(mirror.state().clients.is_empty());
+        let changes: Vec<StateChange> = changes.try_iter().collect();
+        assert_eq!(changes.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fullscreen() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let mirror = mirror(&mock);
+        let kitty = address("0x55d1c8a0e6f0");
+        let fullscreen = crate::testing::fixtures::CLIENTS.replacen(
+            r#""fullscreen": 0, "fullscreenClient": 0"#,
+            r#""fullscreen": 2, "fullscreenClient": 2"#,
+            1,
+        );
+        mock.set_data(crate::data::DataCommands::Clients, fullscreen);
+
+        mirror.handle_event(mock.instance(), &Event::FullscreenStateChanged(true))?;
+        let state = mirror.state();
+        assert_eq!(state.workspace(1).map(|w| w.fullscreen), Some(true));
+        let client = state
+            .client(&kitty)
+            .map(|client| (client.fullscreen, client.fullscreen_client));
+        assert_eq!(
+            client,
+            Some((FullscreenMode::Fullscreen, FullscreenMode::Fullscreen))
+        );
+        Ok(())
+    }
+
+    /// Waits until the mock received the command the amount of times
+    fn wait_for_requests(mock: &MockHyprland, command: &str, count: usize) -> bool {
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while std::time::Instant::now() < deadline {
+            let received = mock.received();
+            if received
+                .iter()
+                .filter(|request| *request == command)
+                .count()
+                >= count
+            {
+                return true;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        false
+    }
+
+    #[test]
+    fn test_run_recovers() -> crate::Result<()> {
+        use crate::data::DataCommands;
+        use crate::testing::fixtures;
+
+        let mock = MockHyprland::new()?;
+        let mirror = mirror(&mock);
+        let changes = mirror.subscribe();
+        let runner = mirror.clone();
+        let run = std::thread::spawn(move || runner.run());
+        assert!(mock.wait_for_event_clients(1, Duration::from_secs(5)));
+        mock.clear_received();
+
+        // the clients can't be fetched after the window opened, and neither can the whole state
+        mock.set_data(DataCommands::Clients, "not json");
+        mock.push_event("openwindow>>55d1c8a11111,1,foot,foot")?;
+        assert!(wait_for_requests(&mock, "clients", 2));
+        mock.set_data(DataCommands::Clients, fixtures::CLIENTS);
+        // the state is fetched again after the malformed line
+        mock.push_event("workspacev2>>nope,web")?;
+        assert!(wait_for_requests(&mock, "clients", 3));
+        mock.push_event("closewindow>>55d1c8a0e6f0")?;
+        std::thread::sleep(Duration::from_millis(50));
+        mock.disconnect_event_clients();
+        assert!(matches!(run.join(), Ok(Ok(()))));
+
+        assert!(mirror.state().clients.is_empty());
+        let changes: Vec<StateChange> = changes.try_iter().collect();
+        assert!(changes.contains(&StateChange::Refreshed));
+        assert_eq!(
+            changes.last(),
+            Some(&StateChange::ClientRemoved(address("0x55d1c8a0e6f0")))
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_run_async_recovers() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let mirror = StateMirror::instance_new_async(mock.instance()).await?;
+        let changes = mirror.subscribe();
+        let runner = mirror.clone();
+        let run = tokio::spawn(async move { runner.run_async().await });
+        let server = std::thread::spawn(move || -> crate::Result<()> {
+            assert!(mock.wait_for_event_clients(1, Duration::from_secs(5)));
+            mock.push_event("workspacev2>>nope,web")?;
+            std::thread::sleep(Duration::from_millis(50));
+            mock.push_event("closewindow>>55d1c8a0e6f0")?;
+            std::thread::sleep(Duration::from_millis(50));
+            mock.disconnect_event_clients();
+            Ok(())
+        });
+        assert!(matches!(run.await, Ok(Ok(()))));
+        assert!(matches!(server.join(), Ok(Ok(()))));
+
+        assert!(mirror.state().clients.is_empty());
+        let changes: Vec<StateChange> = changes.try_iter().collect();
+        assert_eq!(changes.first(), Some(&StateChange::Refreshed));
+        Ok(())
+    }
+
+    #[test]
+    fn test_slow_subscriber() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let mirror = mirror(&mock);
+        let changes = mirror.subscribe();
+        drop(mirror.subscribe());
+        for _ in 0..SUBSCRIBER_CAPACITY + 10 {
+            mirror.apply(&Event::LayerOpened("waybar".into()));
+        }
+        assert_eq!(changes.try_iter().count(), SUBSCRIBER_CAPACITY);
+        assert_eq!(mirror.subscribers.lock().map(|s| s.len()).ok(), Some(1));
+        Ok(())
+    }
+}
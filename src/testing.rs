@@ -0,0 +1,532 @@
+//! # Testing module
+//!
+//! This module provides a fake Hyprland instance for testing code that uses hyprland-rs
+//! without a running compositor. [MockHyprland] serves the `.socket.sock`, `.socket2.sock`
+//! and `.hyprpaper.sock` sockets from a temporary directory.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use hyprland::data::Monitors;
+//! use hyprland::dispatch::{Dispatch, DispatchType};
+//! use hyprland::prelude::*;
+//! use hyprland::testing::MockHyprland;
+//! fn main() -> hyprland::Result<()> {
+//!     let mock = MockHyprland::new()?;
+//!
+//!     let monitors = Monitors::instance_get(mock.instance())?;
+//!     assert_eq!(monitors.iter().count(), 1);
+//!
+//!     Dispatch::instance_call(mock.instance(), DispatchType::Exec("kitty"))?;
+//!     assert!(mock.received().contains(&"dispatch exec kitty".to_string()));
+//!     Ok(())
+//! }
+//! ```
+
+use crate::instance::Instance;
+use std::collections::HashMap;
+use std::io::{ErrorKind, Read, Write};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "data")]
+use crate::data::DataCommands;
+
+/// Default JSON replies served by [MockHyprland] for every data command
+///
+/// They describe a single monitor `DP-1`, showing workspace `1` with one `kitty` window.
+pub mod fixtures {
+    /// Reply to `monitors all`
+    pub const MONITORS: &str = r#"[{
+        "id": 0, "name": "DP-1", "description": "Dell Inc. DELL U2720Q 6KFDN13",
+        "make": "Dell Inc.", "model": "DELL U2720Q", "serial": "6KFDN13",
+        "width": 2560, "height": 1440, "refreshRate": 144.0, "x": 0, "y": 0,
+        "activeWorkspace": {"id": 1, "name": "1"},
+        "specialWorkspace": {"id": 0, "name": ""},
+        "reserved": [0, 30, 0, 0], "scale": 1.0, "transform": 0,
+        "focused": true, "dpmsStatus": true, "vrr": false, "solitary": "0",
+        "activelyTearing": false, "directScanoutTo": "0", "disabled": false,
+        "currentFormat": "XRGB8888", "mirrorOf": "none",
+        "availableModes": ["2560x1440@144.00Hz", "2560x1440@59.95Hz", "1920x1080@60.00Hz"]
+    }]"#;
+    /// Reply to `activeworkspace`
+    pub const ACTIVE_WORKSPACE: &str = r#"{
+        "id": 1, "name": "1", "monitor": "DP-1", "monitorID": 0, "windows": 1,
+        "hasfullscreen": false, "lastwindow": "0x55d1c8a0e6f0", "lastwindowtitle": "kitty"
+    }"#;
+    /// Reply to `workspaces`
+    pub const WORKSPACES: &str = r#"[{
+        "id": 1, "name": "1", "monitor": "DP-1", "monitorID": 0, "windows": 1,
+        "hasfullscreen": false, "lastwindow": "0x55d1c8a0e6f0", "lastwindowtitle": "kitty"
+    }]"#;
+    /// Reply to `activewindow`
+    pub const ACTIVE_WINDOW: &str = r#"{
+        "address": "0x55d1c8a0e6f0", "mapped": true, "hidden": false,
+        "at": [10, 40], "size": [2540, 1390],
+        "workspace": {"id": 1, "name": "1"},
+        "floating": false, "pseudo": false, "monitor": 0,
+        "class": "kitty", "title": "kitty", "initialClass": "kitty", "initialTitle": "kitty",
+        "pid": 1234, "xwayland": false, "pinned": false,
+        "fullscreen": 0, "fullscreenClient": 0,
+        "grouped": [], "tags": [], "swallowing": "0x0", "focusHistoryID": 0
+    }"#;
+    /// Reply to `clients`
+    pub const CLIENTS: &str = r#"[{
+        "address": "0x55d1c8a0e6f0", "mapped": true, "hidden": false,
+        "at": [10, 40], "size": [2540, 1390],
+        "workspace": {"id": 1, "name": "1"},
+        "floating": false, "pseudo": false, "monitor": 0,
+        "class": "kitty", "title": "kitty", "initialClass": "kitty", "initialTitle": "kitty",
+        "pid": 1234, "xwayland": false, "pinned": false,
+        "fullscreen": 0, "fullscreenClient": 0,
+        "grouped": [], "tags": [], "swallowing": "0x0", "focusHistoryID": 0
+    }]"#;
+    /// Reply to `layers`
+    pub const LAYERS: &str = r#"{
+        "DP-1": {"levels": {
+            "0": [{"address": "0x55d1c8b1a2c0", "x": 0, "y": 0, "w": 2560, "h": 1440, "namespace": "wallpaper"}],
+            "1": [], "2": [{"address": "0x55d1c8b3f4d0", "x": 0, "y": 0, "w": 2560, "h": 30, "namespace": "waybar"}],
+            "3": []
+        }}
+    }"#;
+    /// Reply to `devices`
+    pub const DEVICES: &str =
+        r#"{"mice": [], "keyboards": [], "tablets": [], "touch": [], "switches": []}"#;
+    /// Reply to `version`
+    pub const VERSION: &str = r#"{
+        "branch": "main", "commit": "9958d297641b5c84dcff93f9039d80a5ad37ab00",
+        "version": "0.50.0", "dirty": false, "commit_message": "mock",
+        "commit_date": "Thu Jul 17 2025", "tag": "v0.50.0", "commits": "6000",
+        "buildAquamarine": "0.9.0", "buildHyprlang": "0.6.3", "buildHyprutils": "0.8.1",
+        "buildHyprcursor": "0.1.12", "buildHyprgraphics": "0.1.5", "flags": []
+    }"#;
+    /// Reply to `cursorpos`
+    pub const CURSOR_POSITION: &str = r#"{"x": 1280, "y": 720}"#;
+    /// Reply to `binds`
+    pub const BINDS: &str = r#"[{
+        "locked": false, "mouse": false, "release": false, "repeat": false,
+        "longPress": false, "non_consuming": false, "has_description": false,
+        "modmask": 64, "submap": "", "key": "Q", "keycode": 0, "catch_all": false,
+        "description": "", "dispatcher": "exec", "arg": "kitty"
+    }]"#;
+    /// Reply to `animations`
+    pub const ANIMATIONS: &str = r#"[[{
+        "name": "global", "overridden": false, "bezier": "default",
+        "enabled": true, "speed": 8.0, "style": ""
+    }], [{"name": "default"}]]"#;
+    /// Reply to `workspacerules`
+    pub const WORKSPACE_RULES: &str =
+        r#"[{"workspaceString": "1", "monitor": "DP-1", "default": true}]"#;
+}
+
+const DEFAULT_REPLY: &str = "ok";
+const BATCH_PREFIX: &str = "[[BATCH]]";
+const BATCH_DELIMITER: &str = "\n\n\n";
+const ACCEPT_POLL: Duration = Duration::from_millis(5);
+
+static MOCK_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Default)]
+struct MockState {
+    running: AtomicBool,
+    replies: Mutex<HashMap<String, String>>,
+    received: Mutex<Vec<String>>,
+    hyprpaper_reply: Mutex<Option<String>>,
+    hyprpaper_received: Mutex<Vec<String>>,
+    event_clients: Mutex<Vec<UnixStream>>,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // a panicking test thread should not hide the commands received before it
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+impl MockState {
+    fn reply_to(&self, command: &str) -> String {
+        let replies = lock(&self.replies);
+        let name = command.split_once(' ').map_or(command, |(name, _)| name);
+        replies
+            .get(command)
+            .or_else(|| replies.get(name))
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_REPLY.to_string())
+    }
+
+    fn handle_request(&self, request: &str) -> String {
+        if let Some(batch) = request.strip_prefix(BATCH_PREFIX) {
+            let mut response = String::new();
+            for command in batch.split(';') {
+                response.push_str(&self.handle_command(command));
+                response.push_str(BATCH_DELIMITER);
+            }
+            response
+        } else {
+            self.handle_command(request)
+        }
+    }
+
+    fn handle_command(&self, command: &str) -> String {
+        let command = strip_flags(command.trim());
+        lock(&self.received).push(command.to_string());
+        self.reply_to(command)
+    }
+}
+
+/// Strips the `j/` style flags the same way Hyprland does, the flags end at the first `/`
+/// unless a space comes first
+fn strip_flags(command: &str) -> &str {
+    match (command.find('/'), command.find(' ')) {
+        (Some(slash), Some(space)) if slash < space => &command[slash + 1..],
+        (Some(slash), None) => &command[slash + 1..],
+        _ => command,
+    }
+}
+
+fn read_request(stream: &mut UnixStream) -> std::io::Result<String> {
+    // clients do not shutdown their write half, so read what is available once it arrives
+    let mut request = vec![0; 8192];
+    let read = stream.read(&mut request)?;
+    request.truncate(read);
+    stream.set_nonblocking(true)?;
+    let mut buf = [0; 8192];
+    loop {
+        match stream.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => request.extend_from_slice(&buf[..n]),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+            Err(e) => return Err(e),
+        }
+    }
+    stream.set_nonblocking(false)?;
+    Ok(String::from_utf8_lossy(&request).to_string())
+}
+
+fn accept_loop(
+    listener: UnixListener,
+    state: Arc<MockState>,
+    handler: fn(&MockState, UnixStream),
+) -> std::io::Result<JoinHandle<()>> {
+    listener.set_nonblocking(true)?;
+    Ok(std::thread::spawn(move || {
+        while state.running.load(Ordering::SeqCst) {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    if stream.set_nonblocking(false).is_ok() {
+                        handler(&state, stream)
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => std::thread::sleep(ACCEPT_POLL),
+                Err(_) => break,
+            }
+        }
+    }))
+}
+
+fn handle_socket(state: &MockState, mut stream: UnixStream) {
+    if let Ok(request) = read_request(&mut stream) {
+        let _ = stream.write_all(state.handle_request(&request).as_bytes());
+    }
+    let _ = stream.shutdown(Shutdown::Both);
+}
+
+fn handle_hyprpaper_socket(state: &MockState, mut stream: UnixStream) {
+    if let Ok(request) = read_request(&mut stream) {
+        lock(&state.hyprpaper_received).push(request);
+        let reply = lock(&state.hyprpaper_reply)
+            .clone()
+            .unwrap_or_else(|| DEFAULT_REPLY.to_string());
+        let _ = stream.write_all(reply.as_bytes());
+    }
+    let _ = stream.shutdown(Shutdown::Both);
+}
+
+fn handle_event_socket(state: &MockState, stream: UnixStream) {
+    lock(&state.event_clients).push(stream);
+}
+
+/// A fake Hyprland instance serving its sockets from a temporary directory
+///
+/// Commands sent to `.socket.sock` are recorded, and answered with the reply registered
+/// for the command (see [MockHyprland::respond_with]), falling back to `ok`.
+/// Every [DataCommands] entry is answered with the matching [fixtures] by default.
+/// The sockets are closed and the directory removed when this is dropped.
+#[derive(Debug)]
+pub struct MockHyprland {
+    instance: Instance,
+    dir: PathBuf,
+    state: Arc<MockState>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl MockHyprland {
+    /// This method creates the sockets and starts serving them
+    pub fn new() -> crate::Result<Self> {
+        let dir = std::env::temp_dir().join(format!(
+            "hyprland-rs-mock-{}-{}",
+            std::process::id(),
+            MOCK_COUNT.fetch_add(1, Ordering::SeqCst)
+        ));
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir)?;
+        }
+        std::fs::create_dir_all(&dir)?;
+
+        let state = Arc::new(MockState {
+            running: AtomicBool::new(true),
+            ..Default::default()
+        });
+        let mut mock = Self {
+            instance: Instance::from_base_socket_path(dir.clone())?,
+            dir,
+            state,
+            threads: vec![],
+        };
+        #[cfg(feature = "data")]
+        mock.use_default_fixtures();
+
+        for (name, handler) in [
+            (".socket.sock", handle_socket as fn(&MockState, UnixStream)),
+            (".socket2.sock", handle_event_socket),
+            (".hyprpaper.sock", handle_hyprpaper_socket),
+        ] {
+            let listener = UnixListener::bind(mock.dir.join(name))?;
+            let thread = accept_loop(listener, mock.state.clone(), handler)?;
+            mock.threads.push(thread);
+        }
+        Ok(mock)
+    }
+
+    /// This method returns the [Instance] connected to this mock
+    pub fn instance(&self) -> &Instance {
+        &self.instance
+    }
+
+    /// This method returns the directory the sockets are in
+    pub fn path(&self) -> &Path {
+        &self.dir
+    }
+
+    #[cfg(feature = "data")]
+    fn use_default_fixtures(&mut self) {
+        use DataCommands::*;
+        for (command, reply) in [
+            (Monitors, fixtures::MONITORS),
+            (Workspaces, fixtures::WORKSPACES),
+            (ActiveWorkspace, fixtures::ACTIVE_WORKSPACE),
+            (Clients, fixtures::CLIENTS),
+            (ActiveWindow, fixtures::ACTIVE_WINDOW),
+            (Layers, fixtures::LAYERS),
+            (Devices, fixtures::DEVICES),
+            (Version, fixtures::VERSION),
+            (CursorPosition, fixtures::CURSOR_POSITION),
+            (Binds, fixtures::BINDS),
+            (Animations, fixtures::ANIMATIONS),
+            (WorkspaceRules, fixtures::WORKSPACE_RULES),
+        ] {
+            self.set_data(command, reply);
+        }
+    }
+
+    /// This method sets the JSON served for a data command
+    #[cfg(feature = "data")]
+    pub fn set_data<Str: ToString>(&self, command: DataCommands, json: Str) {
+        self.respond_with(command, json);
+    }
+
+    /// This method sets the reply for a command, without its flags
+    ///
+    /// The command is either matched as a whole (`dispatch exec kitty`),
+    /// or by its name (`dispatch`) if there is no reply for the whole command.
+    pub fn respond_with<Cmd: ToString, Str: ToString>(&self, command: Cmd, reply: Str) {
+        lock(&self.state.replies).insert(command.to_string(), reply.to_string());
+    }
+
+    /// This method sets the reply for every `.hyprpaper.sock` request
+    pub fn set_hyprpaper_reply<Str: ToString>(&self, reply: Str) {
+        *lock(&self.state.hyprpaper_reply) = Some(reply.to_string());
+    }
+
+    /// This method returns every command received on `.socket.sock`, without their flags
+    ///
+    /// Commands sent in a batch are recorded one by one.
+    pub fn received(&self) -> Vec<String> {
+        lock(&self.state.received).clone()
+    }
+
+    /// This method returns every request received on `.hyprpaper.sock`
+    pub fn hyprpaper_received(&self) -> Vec<String> {
+        lock(&self.state.hyprpaper_received).clone()
+    }
+
+    /// This method clears the received commands
+    pub fn clear_received(&self) {
+        lock(&self.state.received).clear();
+        lock(&self.state.hyprpaper_received).clear();
+    }
+
+    /// This method returns the amount of clients connected to `.socket2.sock`
+    pub fn event_clients(&self) -> usize {
+        lock(&self.state.event_clients).len()
+    }
+
+    /// This method blocks until at least `count` clients are connected to `.socket2.sock`,
+    /// returns false if the timeout elapsed first
+    pub fn wait_for_event_clients(&self, count: usize, timeout: Duration) -> bool {
+        let start = Instant::now();
+        while self.event_clients() < count {
+            if start.elapsed() > timeout {
+                return false;
+            }
+            std::thread::sleep(ACCEPT_POLL);
+        }
+        true
+    }
+
+    /// This method sends an event line (like `workspacev2>>1,1`) to every `.socket2.sock` client
+    pub fn push_event<Str: AsRef<str>>(&self, line: Str) -> crate::Result<()> {
+        self.push_event_bytes(format!("{}\n", line.as_ref()).as_bytes())
+    }
+
+    /// This method sends raw bytes to every `.socket2.sock` client,
+    /// which allows splitting events and characters across reads
+    pub fn push_event_bytes(&self, bytes: &[u8]) -> crate::Result<()> {
+        let mut clients = lock(&self.state.event_clients);
+        clients.retain_mut(|client| client.write_all(bytes).and_then(|_| client.flush()).is_ok());
+        Ok(())
+    }
+
+    /// This method closes every `.socket2.sock` connection, like Hyprland exiting would
+    pub fn disconnect_event_clients(&self) {
+        for client in lock(&self.state.event_clients).drain(..) {
+            let _ = client.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+impl Drop for MockHyprland {
+    fn drop(&mut self) {
+        self.state.running.store(false, Ordering::SeqCst);
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+        self.disconnect_event_clients();
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::*;
+
+    #[test]
+    fn test_strip_flags() {
+        assert_eq!(strip_flags("j/monitors all"), "monitors all");
+        assert_eq!(
+            strip_flags("/keyword general:gaps_in 5"),
+            "keyword general:gaps_in 5"
+        );
+        assert_eq!(
+            strip_flags("dispatch exec /usr/bin/kitty"),
+            "dispatch exec /usr/bin/kitty"
+        );
+        assert_eq!(strip_flags("version"), "version");
+    }
+
+    #[test]
+    fn test_replies() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        mock.respond_with("dispatch", "no such dispatcher");
+        mock.respond_with("dispatch exec kitty", "ok");
+        let reply = mock
+            .instance()
+            .write_to_socket(command!(JSON, "dispatch exec kitty"))?;
+        assert_eq!(reply, "ok");
+        let reply = mock
+            .instance()
+            .write_to_socket(command!(JSON, "dispatch foo"))?;
+        assert_eq!(reply, "no such dispatcher");
+        let reply = mock.instance().write_to_socket(command!(Empty, "reload"))?;
+        assert_eq!(reply, "ok");
+        assert_eq!(
+            mock.received(),
+            vec!["dispatch exec kitty", "dispatch foo", "reload"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_replies() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        mock.respond_with("cursorpos", r#"{"x": 1, "y": 2}"#);
+        let reply = mock
+            .instance()
+            .write_raw_to_socket(b"[[BATCH]]j/dispatch exec kitty;j/cursorpos")?;
+        assert_eq!(reply, "ok\n\n\n{\"x\": 1, \"y\": 2}\n\n\n");
+        assert_eq!(mock.received(), vec!["dispatch exec kitty", "cursorpos"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_events() -> crate::Result<()> {
+        let mock = MockHyprland::new()?;
+        let mut stream = mock.instance().get_event_stream()?;
+        assert!(mock.wait_for_event_clients(1, Duration::from_secs(5)));
+        mock.push_event("workspacev2>>2,2")?;
+        mock.disconnect_event_clients();
+        let mut received = String::new();
+        stream.read_to_string(&mut received)?;
+        assert_eq!(received, "workspacev2>>2,2\n");
+        Ok(())
+    }
+
+    #[cfg(feature = "hyprpaper")]
+    #[test]
+    fn test_hyprpaper() -> crate::Result<()> {
+        use crate::hyprpaper::{instance_hyprpaper, Keyword, Preload, Response};
+        let mock = MockHyprland::new()?;
+        let keyword = Keyword::Preload(Preload {
+            path: "/foo/bar".into(),
+        });
+        let response = instance_hyprpaper(mock.instance(), keyword)?;
+        assert!(matches!(response, Response::Ok));
+        assert_eq!(mock.hyprpaper_received(), vec!["preload /foo/bar"]);
+        Ok(())
+    }
+
+    #[cfg(feature = "data")]
+    #[test]
+    fn test_fixtures() -> crate::Result<()> {
+        use crate::data::*;
+        use crate::prelude::*;
+        let mock = MockHyprland::new()?;
+        let instance = mock.instance();
+        assert_eq!(Monitors::instance_get(instance)?.iter().count(), 1);
+        assert_eq!(Workspaces::instance_get(instance)?.iter().count(), 1);
+        assert_eq!(Workspace::instance_get_active(instance)?.id, 1);
+        assert_eq!(Clients::instance_get(instance)?.iter().count(), 1);
+        assert!(Client::instance_get_active(instance)?.is_some());
+        assert_eq!(Layers::instance_get(instance)?.iter().count(), 1);
+        Devices::instance_get(instance)?;
+        Version::instance_get(instance)?;
+        assert_eq!(
+            CursorPosition::instance_get(instance)?,
+            CursorPosition { x: 1280, y: 720 }
+        );
+        assert_eq!(Binds::instance_get(instance)?.iter().count(), 1);
+        Animations::instance_get(instance)?;
+        assert_eq!(WorkspaceRules::instance_get(instance)?.iter().count(), 1);
+
+        mock.set_data(DataCommands::ActiveWindow, "{}");
+        assert!(Client::instance_get_active(instance)?.is_none());
+        Ok(())
+    }
+}